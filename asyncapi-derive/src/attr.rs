@@ -0,0 +1,178 @@
+use syn::{meta::ParseNestedMeta, Attribute, Expr, ExprLit, Lit, LitStr, Meta, Token};
+
+/// The `#[serde(...)]` attributes of a container, variant or field which
+/// change its serialization. Other attributes are ignored.
+#[derive(Default)]
+pub struct SerdeAttrs {
+    pub rename: Option<String>,
+    pub rename_all: Option<RenameRule>,
+    pub tag: Option<String>,
+    pub content: Option<String>,
+    pub untagged: bool,
+    pub transparent: bool,
+    pub deny_unknown_fields: bool,
+    /// `skip` or `skip_serializing`.
+    pub skip: bool,
+    pub default: bool,
+    pub skip_serializing_if: bool,
+    pub flatten: bool,
+}
+
+impl SerdeAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut serde = SerdeAttrs::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+            attr.parse_nested_meta(|meta| {
+                let path = &meta.path;
+                if path.is_ident("rename") {
+                    if let Some(rename) = serialize_name(&meta)? {
+                        serde.rename = Some(rename);
+                    }
+                } else if path.is_ident("rename_all") {
+                    if let Some(rule) = serialize_name(&meta)? {
+                        serde.rename_all = Some(RenameRule::from_name(&rule).ok_or_else(|| meta.error(format!("unknown rename rule `{rule}`")))?);
+                    }
+                } else if path.is_ident("tag") {
+                    serde.tag = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if path.is_ident("content") {
+                    serde.content = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if path.is_ident("untagged") {
+                    serde.untagged = true;
+                } else if path.is_ident("transparent") {
+                    serde.transparent = true;
+                } else if path.is_ident("deny_unknown_fields") {
+                    serde.deny_unknown_fields = true;
+                } else if path.is_ident("skip") || path.is_ident("skip_serializing") {
+                    serde.skip = true;
+                } else if path.is_ident("flatten") {
+                    serde.flatten = true;
+                } else if path.is_ident("default") {
+                    serde.default = true;
+                    skip_value(&meta)?;
+                } else if path.is_ident("skip_serializing_if") {
+                    serde.skip_serializing_if = true;
+                    skip_value(&meta)?;
+                } else {
+                    skip_value(&meta)?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(serde)
+    }
+}
+
+/// Reads `name = "..."` or the `serialize` half of `name(serialize = "...", deserialize = "...")`.
+fn serialize_name(meta: &ParseNestedMeta) -> syn::Result<Option<String>> {
+    if meta.input.peek(Token![=]) {
+        return Ok(Some(meta.value()?.parse::<LitStr>()?.value()));
+    }
+    let mut name = None;
+    meta.parse_nested_meta(|nested| {
+        if nested.path.is_ident("serialize") {
+            name = Some(nested.value()?.parse::<LitStr>()?.value());
+        } else {
+            skip_value(&nested)?;
+        }
+        Ok(())
+    })?;
+    Ok(name)
+}
+
+fn skip_value(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|nested| skip_value(&nested))?;
+    }
+    Ok(())
+}
+
+/// The doc comment of an item, without the leading space of each line.
+pub fn doc(attrs: &[Attribute]) -> String {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(name_value) => match &name_value.value {
+                Expr::Lit(ExprLit { lit: Lit::Str(line), .. }) => Some(line.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    lines.iter().map(|line| line.strip_prefix(' ').unwrap_or(line)).collect::<Vec<_>>().join("\n").trim().to_owned()
+}
+
+/// The case conversions of `#[serde(rename_all = "...")]`.
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "lowercase" => RenameRule::Lower,
+            "UPPERCASE" => RenameRule::Upper,
+            "PascalCase" => RenameRule::Pascal,
+            "camelCase" => RenameRule::Camel,
+            "snake_case" => RenameRule::Snake,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnake,
+            "kebab-case" => RenameRule::Kebab,
+            "SCREAMING-KEBAB-CASE" => RenameRule::ScreamingKebab,
+            _ => return None,
+        })
+    }
+
+    /// Renames a `snake_case` field.
+    pub fn apply_to_field(self, field: &str) -> String {
+        match self {
+            RenameRule::Lower | RenameRule::Snake => field.to_owned(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field.to_ascii_uppercase(),
+            RenameRule::Pascal => field
+                .split('_')
+                .map(|word| {
+                    let mut chars = word.chars();
+                    chars.next().map(|first| first.to_ascii_uppercase().to_string() + chars.as_str()).unwrap_or_default()
+                })
+                .collect(),
+            RenameRule::Camel => {
+                let pascal = RenameRule::Pascal.apply_to_field(field);
+                pascal[..1.min(pascal.len())].to_ascii_lowercase() + &pascal[1.min(pascal.len())..]
+            }
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+
+    /// Renames a `PascalCase` variant.
+    pub fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            RenameRule::Pascal => variant.to_owned(),
+            RenameRule::Lower => variant.to_ascii_lowercase(),
+            RenameRule::Upper => variant.to_ascii_uppercase(),
+            RenameRule::Camel => variant[..1.min(variant.len())].to_ascii_lowercase() + &variant[1.min(variant.len())..],
+            RenameRule::Snake => {
+                let mut snake = String::new();
+                for (index, char) in variant.char_indices() {
+                    if index > 0 && char.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(char.to_ascii_lowercase());
+                }
+                snake
+            }
+            RenameRule::ScreamingSnake => RenameRule::Snake.apply_to_variant(variant).to_ascii_uppercase(),
+            RenameRule::Kebab => RenameRule::Snake.apply_to_variant(variant).replace('_', "-"),
+            RenameRule::ScreamingKebab => RenameRule::ScreamingSnake.apply_to_variant(variant).replace('_', "-"),
+        }
+    }
+}
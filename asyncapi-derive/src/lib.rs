@@ -0,0 +1,166 @@
+//! `#[derive(AsyncApiSchema)]`, which implements `asyncapi::ToSchema` for
+//! structs and enums.
+//!
+//! This crate is re-exported by the `asyncapi` crate with its `derive`
+//! feature and should not be used directly.
+
+mod attr;
+
+use attr::{doc, SerdeAttrs};
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{ext::IdentExt, parse_macro_input, parse_quote, Data, DataEnum, DeriveInput, Fields, Type, TypePath};
+
+/// Generates the schema of the JSON serialization of a struct or enum.
+///
+/// - Structs with named fields become objects. Fields are `required` unless
+///   they are an `Option` or have `#[serde(default)]` or
+///   `#[serde(skip_serializing_if = "...")]`.
+/// - Newtype structs have the schema of their field and tuple structs
+///   become arrays.
+/// - Enums of unit variants become string enumerations. Other enums become
+///   a `oneOf` of their variants, following the serde representation of the
+///   enum. Internally and adjacently tagged enums use their `tag` as the
+///   `discriminator`.
+/// - Doc comments become descriptions and the serde `rename`, `rename_all`,
+///   `skip`, `flatten`, `transparent` and `deny_unknown_fields` attributes
+///   are honoured.
+///
+/// The types of the fields must implement `asyncapi::ToSchema`.
+#[proc_macro_derive(AsyncApiSchema, attributes(serde))]
+pub fn derive_async_api_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let serde = SerdeAttrs::parse(&input.attrs)?;
+    let ident = &input.ident;
+    let title = serde.rename.clone().unwrap_or_else(|| ident.unraw().to_string());
+    let description = doc(&input.attrs);
+    let schema = match &input.data {
+        Data::Struct(data) => struct_schema(&data.fields, &serde, &description)?,
+        Data::Enum(data) => enum_schema(data, &serde, &description)?,
+        Data::Union(_) => return Err(syn::Error::new_spanned(ident, "AsyncApiSchema cannot be derived for unions")),
+    };
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::asyncapi::ToSchema));
+    }
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::asyncapi::ToSchema for #ident #type_generics #where_clause {
+            fn schema() -> ::asyncapi::Schema {
+                ::asyncapi::derive_support::titled(#schema, #title)
+            }
+        }
+    })
+}
+
+/// An expression building the schema of the fields of a struct or variant.
+fn struct_schema(fields: &Fields, serde: &SerdeAttrs, description: &str) -> syn::Result<TokenStream2> {
+    let fields: Vec<_> = fields.iter().map(|field| Ok((field, SerdeAttrs::parse(&field.attrs)?))).collect::<syn::Result<_>>()?;
+    let fields: Vec<_> = fields.into_iter().filter(|(_, field_serde)| !field_serde.skip).collect();
+    let named = fields.iter().any(|(field, _)| field.ident.is_some());
+
+    if serde.transparent || (!named && fields.len() == 1) {
+        let Some((field, _)) = fields.first() else {
+            return Ok(quote!(::asyncapi::derive_support::null(#description)));
+        };
+        let ty = &field.ty;
+        return Ok(quote!(::asyncapi::derive_support::inline(::asyncapi::derive_support::property::<#ty>(#description))));
+    }
+    if fields.is_empty() && !named {
+        return Ok(quote!(::asyncapi::derive_support::null(#description)));
+    }
+    if !named {
+        let items = fields.iter().map(|(field, _)| {
+            let ty = &field.ty;
+            quote!(::asyncapi::derive_support::property::<#ty>(""))
+        });
+        return Ok(quote!(::asyncapi::derive_support::tuple(#description, ::std::vec![#(#items),*])));
+    }
+
+    let deny_unknown_fields = serde.deny_unknown_fields;
+    let properties = fields.iter().map(|(field, field_serde)| {
+        let ty = &field.ty;
+        if field_serde.flatten {
+            return quote!(object.flatten(<#ty as ::asyncapi::ToSchema>::schema()););
+        }
+        let ident = field.ident.as_ref().expect("named field").unraw().to_string();
+        let name = match (&field_serde.rename, serde.rename_all) {
+            (Some(rename), _) => rename.clone(),
+            (None, Some(rule)) => rule.apply_to_field(&ident),
+            (None, None) => ident,
+        };
+        let field_description = doc(&field.attrs);
+        let required = !is_option(ty) && !serde.default && !field_serde.default && !field_serde.skip_serializing_if;
+        quote!(object.property::<#ty>(#name, #field_description, #required);)
+    });
+    Ok(quote!({
+        let mut object = ::asyncapi::derive_support::Object::new(#description, #deny_unknown_fields);
+        #(#properties)*
+        object.into_schema()
+    }))
+}
+
+/// An expression building the schema of an enum, following its serde representation.
+fn enum_schema(data: &DataEnum, serde: &SerdeAttrs, description: &str) -> syn::Result<TokenStream2> {
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        let variant_serde = SerdeAttrs::parse(&variant.attrs)?;
+        if variant_serde.skip {
+            continue;
+        }
+        let ident = variant.ident.unraw().to_string();
+        let name = match (&variant_serde.rename, serde.rename_all) {
+            (Some(rename), _) => rename.clone(),
+            (None, Some(rule)) => rule.apply_to_variant(&ident),
+            (None, None) => ident,
+        };
+        variants.push((variant, variant_serde, name));
+    }
+
+    let unit = variants.iter().all(|(variant, _, _)| matches!(variant.fields, Fields::Unit));
+    if unit && serde.tag.is_none() && !serde.untagged {
+        let names = variants.iter().map(|(_, _, name)| name);
+        return Ok(quote!(::asyncapi::derive_support::string_enum(#description, &[#(#names),*])));
+    }
+
+    let mut one_of = Vec::new();
+    for (variant, variant_serde, name) in &variants {
+        let content = match variant.fields {
+            Fields::Unit => None,
+            _ => Some(struct_schema(&variant.fields, variant_serde, "")?),
+        };
+        let content_tokens = match &content {
+            Some(content) => quote!(::std::option::Option::Some(#content)),
+            None => quote!(::std::option::Option::None),
+        };
+        let schema = match (&serde.tag, &serde.content) {
+            _ if serde.untagged => content.unwrap_or_else(|| quote!(::asyncapi::derive_support::null(""))),
+            (Some(tag), Some(content_name)) => quote!(::asyncapi::derive_support::adjacently_tagged(#tag, #name, #content_name, #content_tokens)),
+            (Some(tag), None) => quote!(::asyncapi::derive_support::internally_tagged(#tag, #name, #content_tokens)),
+            (None, _) => match content {
+                Some(content) => quote!(::asyncapi::derive_support::externally_tagged(#name, #content)),
+                None => quote!(::asyncapi::derive_support::string_enum("", &[#name])),
+            },
+        };
+        let variant_description = doc(&variant.attrs);
+        one_of.push(quote!((#name, #variant_description, #schema)));
+    }
+    let discriminator = match &serde.tag {
+        Some(tag) if !serde.untagged => quote!(::std::option::Option::Some(#tag)),
+        _ => quote!(::std::option::Option::None),
+    };
+    Ok(quote!(::asyncapi::derive_support::one_of(#description, ::std::vec![#(#one_of),*], #discriminator)))
+}
+
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => path.segments.last().is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
@@ -0,0 +1,92 @@
+#![allow(dead_code)]
+
+use asyncapi::{AsyncApiSchema, ToSchema};
+use serde::Serialize;
+use serde_json::json;
+
+/// A user signed up.
+#[derive(Serialize, AsyncApiSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct UserSignedUp {
+    /// Name of the user.
+    display_name: String,
+    #[serde(rename = "years")]
+    age: Option<i32>,
+    #[serde(skip)]
+    session: String,
+    #[serde(flatten)]
+    origin: Origin,
+}
+
+#[derive(Serialize, AsyncApiSchema)]
+struct Origin {
+    region: String,
+}
+
+#[derive(Serialize, AsyncApiSchema)]
+#[serde(rename_all = "snake_case")]
+enum Status {
+    Active,
+    SignedOut,
+}
+
+/// An event of the user.
+#[derive(Serialize, AsyncApiSchema)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+enum UserEvent {
+    /// The user signed up.
+    SignedUp(UserSignedUp),
+    Renamed {
+        name: String,
+    },
+    Deleted,
+}
+
+#[derive(Serialize, AsyncApiSchema)]
+enum Shape {
+    Circle(f64),
+    Point,
+}
+
+#[test]
+fn test_derive_struct() {
+    assert_eq!(
+        serde_json::to_value(UserSignedUp::schema()).unwrap(),
+        json!({
+            "title": "UserSignedUp",
+            "description": "A user signed up.",
+            "type": "object",
+            "properties": {
+                "displayName": { "type": "string", "description": "Name of the user." },
+                "years": { "type": "integer", "format": "int32", "nullable": true },
+                "region": { "type": "string" },
+            },
+            "required": ["displayName", "region"],
+            "additionalProperties": false,
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(Status::schema()).unwrap(),
+        json!({ "title": "Status", "type": "string", "enum": ["active", "signed_out"] })
+    );
+}
+
+#[test]
+fn test_derive_enum() {
+    let schema = serde_json::to_value(UserEvent::schema()).unwrap();
+    assert_eq!(schema["discriminator"], "kind");
+    assert_eq!(schema["description"], "An event of the user.");
+    let variants = schema["oneOf"].as_array().unwrap();
+    assert_eq!(variants.len(), 3);
+    assert_eq!(variants[0]["title"], "signedUp");
+    assert_eq!(variants[0]["description"], "The user signed up.");
+    assert_eq!(variants[0]["properties"]["kind"]["enum"], json!(["signedUp"]));
+    assert_eq!(variants[0]["required"], json!(["kind", "displayName", "region"]));
+    assert_eq!(variants[1]["required"], json!(["kind", "name"]));
+    assert_eq!(variants[2]["required"], json!(["kind"]));
+
+    let schema = serde_json::to_value(Shape::schema()).unwrap();
+    assert!(schema.get("discriminator").is_none());
+    assert_eq!(schema["oneOf"][0]["properties"]["Circle"]["format"], "double");
+    assert_eq!(schema["oneOf"][1]["enum"], json!(["Point"]));
+}
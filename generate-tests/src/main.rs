@@ -30,7 +30,7 @@ fn main() -> io::Result<()> {
         test_file,
         "{}",
         quote! {
-            use asyncapi::{AsyncAPI};
+            use asyncapi::{AsyncAPI, Severity};
         }
         .to_string()
     )
@@ -57,7 +57,7 @@ fn main() -> io::Result<()> {
         examples_file,
         "{}",
         quote! {
-            use asyncapi::{AsyncAPI};
+            use asyncapi::{v3, AsyncAPI};
         }
         .to_string()
     )
@@ -87,6 +87,8 @@ fn find_test(dir: &Path, file: &mut File, ident_regex: &Regex) -> io::Result<()>
 
                 if valid {
                     write_deserialize_test(&path_string, file, ident_regex)?;
+                } else {
+                    write_invalid_test(&path, file, ident_regex)?;
                 }
             }
         }
@@ -98,9 +100,8 @@ fn find_spec_example(examples_dir: &Path, file: &mut File, ident_regex: &Regex)
     for entry in examples_dir.read_dir()? {
         let entry = entry?;
         let path = entry.path();
-        let path_string = path.to_string_lossy();
         if entry.file_name().to_string_lossy().ends_with(".yml") {
-            write_deserialize_test(&path_string, file, ident_regex)?
+            write_example_test(&path, file, ident_regex)?
         }
     }
     let social_media_dir = examples_dir.join("social-media");
@@ -112,8 +113,7 @@ fn find_spec_example(examples_dir: &Path, file: &mut File, ident_regex: &Regex)
                 let entry = entry?;
                 let path = entry.path();
                 if path.is_file() && path.file_name() == Some(OsStr::new("asyncapi.yaml")) {
-                    let path_string = path.to_string_lossy();
-                    write_deserialize_test(&path_string, file, ident_regex)?;
+                    write_example_test(&path, file, ident_regex)?;
                 }
             }
         }
@@ -121,6 +121,42 @@ fn find_spec_example(examples_dir: &Path, file: &mut File, ident_regex: &Regex)
     Ok(())
 }
 
+/// Examples of the 3.x specification are deserialized with the `v3` module
+/// and must round-trip, older examples are deserialized with the root module.
+fn write_example_test(path: &Path, file: &mut File, ident_regex: &Regex) -> io::Result<()> {
+    let path_string = path.to_string_lossy();
+    let content = std::fs::read_to_string(path)?;
+    if content.lines().any(|line| line.starts_with("asyncapi: 3") || line.starts_with("asyncapi: '3") || line.starts_with("asyncapi: \"3")) {
+        write_v3_round_trip_test(&path_string, file, ident_regex)
+    } else {
+        write_deserialize_test(&path_string, file, ident_regex)
+    }
+}
+
+fn write_v3_round_trip_test(path_string: &str, file: &mut File, ident_regex: &Regex) -> io::Result<()> {
+    let test = quote! {
+        let value: serde_json::Value = serde_yaml::from_str(include_str!(#path_string))
+        .expect(&format!("Could not parse {}", #path_string));
+        let asyncapi: v3::AsyncAPI = serde_json::from_value(value.clone())
+        .expect(&format!("Could not deserialize {}", #path_string));
+        assert_eq!(serde_json::to_value(&asyncapi).unwrap(), value, "{} does not round-trip", #path_string);
+    };
+    let test_name = format_ident!(
+        "test_{}",
+        ident_regex
+            .replace_all(&path_string, "_")
+            .to_case(Case::Snake)
+    );
+    let test = quote! {
+        #[test]
+        fn #test_name() {
+            #test
+        }
+    };
+    writeln!(file, "{}", test.to_string())?;
+    Ok(())
+}
+
 fn write_deserialize_test(
     path_string: &str,
     file: &mut File,
@@ -145,3 +181,65 @@ fn write_deserialize_test(
     writeln!(file, "{}", test.to_string())?;
     Ok(())
 }
+
+/// The reason an invalid TCK fixture must be rejected for, as text found in
+/// the pointer or message of the deserialization error or of an error
+/// diagnostic, for the fixtures whose file name does not tell it. Keyed by
+/// the path of the fixture relative to `fixtures/tck/tests`.
+const INVALID_REASONS: &[(&str, &str)] = &[];
+
+/// The reason an invalid fixture must be rejected for: the one listed in
+/// [INVALID_REASONS], or else the field named by a file name of the
+/// `invalid-missing-<field>.yaml` or `invalid-<field>-type.yaml` form, e.g.
+/// `authorizationUrl` for `invalid-missing-authorization-url.yaml`.
+fn invalid_reason(path: &Path) -> Option<String> {
+    let relative = path.to_string_lossy().split("/tck/tests/").last()?.to_owned();
+    if let Some((_, reason)) = INVALID_REASONS.iter().find(|(fixture, _)| *fixture == relative) {
+        return Some(reason.to_string());
+    }
+    let stem = path.file_stem()?.to_str()?.strip_prefix("invalid-")?;
+    let field = match (stem.strip_prefix("missing-"), stem.strip_suffix("-type")) {
+        (Some(field), _) | (None, Some(field)) => field,
+        (None, None) => return None,
+    };
+    Some(field.to_case(Case::Camel))
+}
+
+/// Invalid documents must either fail to deserialize or be reported by the
+/// semantic validation, and for the reason given by [invalid_reason].
+fn write_invalid_test(path: &Path, file: &mut File, ident_regex: &Regex) -> io::Result<()> {
+    let path_string = path.to_string_lossy();
+    let reason = invalid_reason(path).unwrap_or_else(|| panic!("add the reason {} is invalid for to INVALID_REASONS", path_string));
+    let test = quote! {
+        let reasons: Vec<String> = match asyncapi::load_with_source_map(include_str!(#path_string)) {
+            Err(error) => vec![format!("{} {}", error.pointer.unwrap_or_default(), error.message)],
+            Ok((asyncapi, _)) => asyncapi
+                .validate()
+                .into_iter()
+                .filter(|diagnostic| diagnostic.severity == Severity::Error)
+                .map(|diagnostic| format!("{} {}", diagnostic.path, diagnostic.message))
+                .collect(),
+        };
+        assert!(
+            reasons.iter().any(|found| found.contains(#reason)),
+            "{} should be invalid because of `{}`, found {:?}",
+            #path_string,
+            #reason,
+            reasons
+        );
+    };
+    let test_name = format_ident!(
+        "test_{}",
+        ident_regex
+            .replace_all(&path_string, "_")
+            .to_case(Case::Snake)
+    );
+    let test = quote! {
+        #[test]
+        fn #test_name() {
+            #test
+        }
+    };
+    writeln!(file, "{}", test.to_string())?;
+    Ok(())
+}
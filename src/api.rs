@@ -1,12 +1,12 @@
 use indexmap::IndexMap;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{Channel, Components, ExternalDocumentation, Info, ReferenceOr, Server, Tag};
+use crate::{de, Channel, Components, ExternalDocumentation, Info, ReferenceOr, Server, Tag};
 
 /// This is the root document object for the API specification.
 /// It combines resource listing and API declaration together into one document.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-#[serde(rename_all = "camelCase")]
+#[serde(remote = "Self", rename_all = "camelCase")]
 pub struct AsyncAPI {
     /// **REQUIRED.** Specifies the AsyncAPI Specification version being used.
     /// It can be used by tooling Specifications and clients to interpret the
@@ -166,3 +166,26 @@ pub struct AsyncAPI {
     #[serde(flatten)]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
+
+impl Serialize for AsyncAPI {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        AsyncAPI::serialize(self, serializer)
+    }
+}
+
+/// The document is buffered, so that its errors are reported with their
+/// JSON Pointer whatever the deserializer.
+impl<'de> Deserialize<'de> for AsyncAPI {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        de::variant(de::buffer(deserializer)?).map(|Fields(asyncapi)| asyncapi)
+    }
+}
+
+/// The fields of an [AsyncAPI], deserialized by its derived implementation.
+struct Fields(AsyncAPI);
+
+impl<'de> Deserialize<'de> for Fields {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        AsyncAPI::deserialize(deserializer).map(Fields)
+    }
+}
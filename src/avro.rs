@@ -0,0 +1,470 @@
+//! [Apache Avro](https://avro.apache.org/docs/1.9.0/spec.html) schemas, the
+//! payloads of messages whose `schemaFormat` is
+//! `application/vnd.apache.avro`.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::schema::{present, AdditionalProperties, ArrayType, Items, ObjectType, StringType, Type};
+use crate::{de, ReferenceOr, Schema, SchemaData, SchemaKind, ToSchema, VariantOrUnknownOrEmpty};
+
+/// An Avro schema.
+///
+/// Deserialized from a type name as a [Primitive] or the name of a type
+/// defined before, from a list as a union, and from an object by its `type`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AvroSchema {
+    Primitive(Primitive),
+    /// A primitive type written as an object, e.g. to give it a logical type.
+    Annotated(Annotated),
+    Record(Record),
+    Enum(Enum),
+    Array(Array),
+    Map(Map),
+    Fixed(Fixed),
+    Union(Vec<AvroSchema>),
+    /// The name of a record, enum or fixed type defined before.
+    Named(String),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Primitive {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+}
+
+impl Primitive {
+    fn parse(name: &str) -> Option<Primitive> {
+        Some(match name {
+            "null" => Primitive::Null,
+            "boolean" => Primitive::Boolean,
+            "int" => Primitive::Int,
+            "long" => Primitive::Long,
+            "float" => Primitive::Float,
+            "double" => Primitive::Double,
+            "bytes" => Primitive::Bytes,
+            "string" => Primitive::String,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Annotated {
+    #[serde(rename = "type")]
+    pub primitive: Primitive,
+    /// The logical type, e.g. `uuid`, `date` or `timestamp-millis`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logical_type: Option<String>,
+    /// The other attributes, e.g. the `precision` and `scale` of a decimal.
+    #[serde(flatten)]
+    pub attributes: IndexMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Record {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    pub fields: Vec<Field>,
+    #[serde(flatten)]
+    pub attributes: IndexMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Field {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(rename = "type")]
+    pub schema: AvroSchema,
+    /// The default value, which is kept when it is `null`.
+    #[serde(default, deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// The other attributes, e.g. the `order` of the field.
+    #[serde(flatten)]
+    pub attributes: IndexMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Enum {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    pub symbols: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(flatten)]
+    pub attributes: IndexMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Array {
+    pub items: Box<AvroSchema>,
+    #[serde(flatten)]
+    pub attributes: IndexMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Map {
+    pub values: Box<AvroSchema>,
+    #[serde(flatten)]
+    pub attributes: IndexMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Fixed {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// The number of bytes of a value.
+    pub size: usize,
+    #[serde(flatten)]
+    pub attributes: IndexMap<String, Value>,
+}
+
+/// A complex type, serialized with its `type`.
+#[derive(Serialize)]
+struct Tagged<'a, T> {
+    #[serde(rename = "type")]
+    typ: &'static str,
+    #[serde(flatten)]
+    schema: &'a T,
+}
+
+impl Serialize for AvroSchema {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            AvroSchema::Primitive(primitive) => primitive.serialize(serializer),
+            AvroSchema::Annotated(annotated) => annotated.serialize(serializer),
+            AvroSchema::Record(record) => Tagged { typ: "record", schema: record }.serialize(serializer),
+            AvroSchema::Enum(enumeration) => Tagged { typ: "enum", schema: enumeration }.serialize(serializer),
+            AvroSchema::Array(array) => Tagged { typ: "array", schema: array }.serialize(serializer),
+            AvroSchema::Map(map) => Tagged { typ: "map", schema: map }.serialize(serializer),
+            AvroSchema::Fixed(fixed) => Tagged { typ: "fixed", schema: fixed }.serialize(serializer),
+            AvroSchema::Union(variants) => variants.serialize(serializer),
+            AvroSchema::Named(name) => serializer.serialize_str(name),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AvroSchema {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = de::buffer(deserializer)?;
+        let typ = match &value {
+            Value::String(name) => return Ok(Primitive::parse(name).map_or_else(|| AvroSchema::Named(name.clone()), AvroSchema::Primitive)),
+            Value::Array(_) => return de::variant(value).map(AvroSchema::Union),
+            value => value.get("type").and_then(Value::as_str).map(str::to_owned),
+        };
+        let Some(typ) = typ else {
+            // A schema given in the `type` of an object, or no type at all.
+            return match de::has_key(&value, "type") {
+                true => de::field(&mut value, "type"),
+                false => de::variant(value).map(AvroSchema::Annotated),
+            };
+        };
+        if Primitive::parse(&typ).is_some() {
+            return de::variant(value).map(AvroSchema::Annotated);
+        }
+        if let Some(object) = value.as_object_mut() {
+            object.remove("type");
+        }
+        match typ.as_str() {
+            "record" | "error" => de::variant(value).map(AvroSchema::Record),
+            "enum" => de::variant(value).map(AvroSchema::Enum),
+            "array" => de::variant(value).map(AvroSchema::Array),
+            "map" => de::variant(value).map(AvroSchema::Map),
+            "fixed" => de::variant(value).map(AvroSchema::Fixed),
+            _ => Ok(AvroSchema::Named(typ)),
+        }
+    }
+}
+
+/// The error returned when an [AvroSchema] can not be converted into a
+/// [Schema].
+#[derive(Debug, Clone, PartialEq)]
+pub enum AvroError {
+    /// The schema names a type which is neither primitive nor defined before.
+    UnknownType(String),
+}
+
+impl fmt::Display for AvroError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AvroError::UnknownType(name) => write!(f, "Avro type `{name}` is not defined"),
+        }
+    }
+}
+
+impl std::error::Error for AvroError {}
+
+impl AvroSchema {
+    /// Converts the schema into a [Schema] of the values it describes, as
+    /// they are written in JSON by most Avro libraries: records are objects
+    /// without other properties, enums are strings, `bytes` and `fixed` are
+    /// strings, and the value of a union is not wrapped in an object naming
+    /// its branch. A union is an `anyOf`, as its values may match several
+    /// branches, and is nullable when it has a `null` branch. The logical
+    /// type of a primitive is its `format`.
+    ///
+    /// Named types are inlined where they are used. A record used within
+    /// itself allows any value there.
+    ///
+    /// ```
+    /// # use asyncapi::AvroSchema;
+    /// let avro: AvroSchema = serde_json::from_str(r#"{
+    ///     "type": "record",
+    ///     "name": "UserSignedUp",
+    ///     "fields": [
+    ///         { "name": "id", "type": { "type": "string", "logicalType": "uuid" } },
+    ///         { "name": "email", "type": ["null", "string"] }
+    ///     ]
+    /// }"#).unwrap();
+    /// let schema = avro.to_schema().unwrap();
+    /// let user = serde_json::json!({ "id": "1b4e28ba-2fa1-11d2-883f-0016d3cca427", "email": null });
+    /// assert_eq!(schema.validate(&user), Ok(()));
+    /// ```
+    pub fn to_schema(&self) -> Result<Schema, AvroError> {
+        Converter::default().schema(self, None)
+    }
+}
+
+#[derive(Default)]
+struct Converter<'a> {
+    /// The named types by full name.
+    named: HashMap<String, &'a AvroSchema>,
+    /// The full names of the named types being converted.
+    converting: HashSet<String>,
+}
+
+impl<'a> Converter<'a> {
+    fn schema(&mut self, avro: &'a AvroSchema, namespace: Option<&str>) -> Result<Schema, AvroError> {
+        Ok(match avro {
+            AvroSchema::Primitive(primitive) => primitive_schema(*primitive),
+            AvroSchema::Annotated(annotated) => {
+                let mut schema = primitive_schema(annotated.primitive);
+                if let (Some(logical_type), SchemaKind::Type(typ)) = (&annotated.logical_type, &mut schema.schema_kind) {
+                    match typ {
+                        Type::String(string) => string.format = VariantOrUnknownOrEmpty::Unknown(logical_type.clone()),
+                        Type::Integer(integer) => integer.format = VariantOrUnknownOrEmpty::Unknown(logical_type.clone()),
+                        _ => {}
+                    }
+                }
+                schema
+            }
+            AvroSchema::Record(record) => {
+                let name = self.define(&record.name, record.namespace.as_deref().or(namespace), avro);
+                let namespace = namespace_of(&name);
+                let mut object = ObjectType {
+                    additional_properties: Some(AdditionalProperties::Any(false)),
+                    ..Default::default()
+                };
+                for field in &record.fields {
+                    let mut schema = self.schema(&field.schema, namespace)?;
+                    schema.schema_data.description = field.doc.clone();
+                    schema.schema_data.default = field.default.clone();
+                    if field.default.is_none() && schema.schema_data.nullable != Some(true) {
+                        object.required.push(field.name.clone());
+                    }
+                    object.properties.insert(field.name.clone(), ReferenceOr::Item(Box::new(schema)));
+                }
+                self.converting.remove(&name);
+                named(&record.name, record.doc.as_deref(), Type::Object(object))
+            }
+            AvroSchema::Enum(enumeration) => {
+                let name = self.define(&enumeration.name, enumeration.namespace.as_deref().or(namespace), avro);
+                self.converting.remove(&name);
+                let string = StringType {
+                    enumeration: enumeration.symbols.iter().cloned().map(Some).collect(),
+                    ..Default::default()
+                };
+                named(&enumeration.name, enumeration.doc.as_deref(), Type::String(string))
+            }
+            AvroSchema::Fixed(fixed) => {
+                let name = self.define(&fixed.name, fixed.namespace.as_deref().or(namespace), avro);
+                self.converting.remove(&name);
+                let string = StringType {
+                    min_length: Some(fixed.size),
+                    max_length: Some(fixed.size),
+                    ..Default::default()
+                };
+                named(&fixed.name, None, Type::String(string))
+            }
+            AvroSchema::Array(array) => {
+                let items = self.schema(&array.items, namespace)?;
+                type_schema(Type::Array(ArrayType {
+                    items: Some(Items::Schema(ReferenceOr::Item(Box::new(items)))),
+                    ..Default::default()
+                }))
+            }
+            AvroSchema::Map(map) => {
+                let values = self.schema(&map.values, namespace)?;
+                type_schema(Type::Object(ObjectType {
+                    additional_properties: Some(AdditionalProperties::Schema(Box::new(ReferenceOr::Item(values)))),
+                    ..Default::default()
+                }))
+            }
+            AvroSchema::Union(variants) => {
+                let nullable = variants.contains(&AvroSchema::Primitive(Primitive::Null));
+                let mut schemas = Vec::new();
+                for variant in variants.iter().filter(|variant| **variant != AvroSchema::Primitive(Primitive::Null)) {
+                    schemas.push(self.schema(variant, namespace)?);
+                }
+                let mut schema = match schemas.len() {
+                    0 => return Ok(primitive_schema(Primitive::Null)),
+                    1 => schemas.remove(0),
+                    _ => Schema {
+                        schema_data: SchemaData::default(),
+                        schema_kind: SchemaKind::AnyOf {
+                            any_of: schemas.into_iter().map(ReferenceOr::Item).collect(),
+                        },
+                    },
+                };
+                if nullable {
+                    schema.schema_data.nullable = Some(true);
+                }
+                schema
+            }
+            AvroSchema::Named(name) => {
+                let full_name = [namespace.map(|namespace| format!("{namespace}.{name}")), Some(name.clone())]
+                    .into_iter()
+                    .flatten()
+                    .find(|full_name| self.named.contains_key(full_name))
+                    .ok_or_else(|| AvroError::UnknownType(name.clone()))?;
+                if self.converting.contains(&full_name) {
+                    return Ok(Schema {
+                        schema_data: SchemaData {
+                            title: Some(name.clone()),
+                            ..Default::default()
+                        },
+                        schema_kind: SchemaKind::Any(Default::default()),
+                    });
+                }
+                let avro = self.named[&full_name];
+                self.schema(avro, namespace_of(&full_name))?
+            }
+        })
+    }
+
+    /// Records a named type and marks it as being converted, returning its
+    /// full name.
+    fn define(&mut self, name: &str, namespace: Option<&str>, avro: &'a AvroSchema) -> String {
+        let full_name = match namespace.filter(|namespace| !name.contains('.') && !namespace.is_empty()) {
+            Some(namespace) => format!("{namespace}.{name}"),
+            None => name.to_owned(),
+        };
+        self.named.insert(full_name.clone(), avro);
+        self.converting.insert(full_name.clone());
+        full_name
+    }
+}
+
+fn namespace_of(full_name: &str) -> Option<&str> {
+    full_name.rsplit_once('.').map(|(namespace, _)| namespace)
+}
+
+fn primitive_schema(primitive: Primitive) -> Schema {
+    match primitive {
+        Primitive::Null => <()>::schema(),
+        Primitive::Boolean => bool::schema(),
+        Primitive::Int => i32::schema(),
+        Primitive::Long => i64::schema(),
+        Primitive::Float => f32::schema(),
+        Primitive::Double => f64::schema(),
+        Primitive::Bytes | Primitive::String => String::schema(),
+    }
+}
+
+fn type_schema(typ: Type) -> Schema {
+    Schema {
+        schema_data: SchemaData::default(),
+        schema_kind: SchemaKind::Type(typ),
+    }
+}
+
+/// The schema of a named type, titled with its name.
+fn named(name: &str, doc: Option<&str>, typ: Type) -> Schema {
+    let mut schema = type_schema(typ);
+    schema.schema_data.title = Some(name.rsplit('.').next().unwrap_or(name).to_owned());
+    schema.schema_data.description = doc.map(str::to_owned);
+    schema
+}
+
+#[test]
+fn test_avro() {
+    let value = serde_json::json!({
+        "type": "record",
+        "name": "Order",
+        "namespace": "com.example",
+        "doc": "An order.",
+        "fields": [
+            { "name": "id", "type": { "type": "string", "logicalType": "uuid" } },
+            { "name": "status", "type": { "type": "enum", "name": "Status", "symbols": ["OPEN", "CLOSED"] }, "default": "OPEN" },
+            { "name": "lines", "type": { "type": "array", "items": { "type": "record", "name": "Line", "fields": [
+                { "name": "quantity", "type": "int" },
+                { "name": "price", "type": { "type": "bytes", "logicalType": "decimal", "precision": 9, "scale": 2 } }
+            ] } } },
+            { "name": "previous", "type": ["null", "Order"], "default": null },
+            { "name": "note", "type": ["null", "string", "Status"] },
+            { "name": "tags", "type": { "type": "map", "values": "com.example.Status" }, "order": "ignore" }
+        ]
+    });
+    let avro: AvroSchema = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&avro).unwrap(), value);
+    let AvroSchema::Record(record) = &avro else {
+        panic!("expected a record, found {avro:?}");
+    };
+    assert_eq!(record.fields[3].default, Some(Value::Null));
+    assert_eq!(record.fields[5].attributes["order"], "ignore");
+
+    let schema = avro.to_schema().unwrap();
+    assert_eq!(schema.schema_data.title.as_deref(), Some("Order"));
+    let SchemaKind::Type(Type::Object(object)) = &schema.schema_kind else {
+        panic!("expected an object, found {:?}", schema.schema_kind);
+    };
+    assert_eq!(object.required, vec!["id", "lines", "tags"]);
+    let order = serde_json::json!({
+        "id": "1b4e28ba-2fa1-11d2-883f-0016d3cca427",
+        "lines": [{ "quantity": 2, "price": "\u{0}\u{10}" }],
+        "previous": { "any": "order" },
+        "note": "CLOSED",
+        "tags": { "urgent": "OPEN" }
+    });
+    assert_eq!(schema.validate(&order), Ok(()));
+    let errors: Vec<String> = schema
+        .validate(&serde_json::json!({ "id": 1, "lines": [{ "quantity": 1.5 }], "note": null, "tags": { "a": "DONE" }, "x": 1 }))
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.instance_path)
+        .collect();
+    assert_eq!(errors, vec!["/id", "/lines/0", "/lines/0/quantity", "/tags/a", ""]);
+
+    let unknown: AvroSchema = serde_json::from_value(serde_json::json!(["null", "Missing"])).unwrap();
+    assert_eq!(unknown.to_schema(), Err(AvroError::UnknownType("Missing".to_owned())));
+    let error = serde_json::from_value::<AvroSchema>(serde_json::json!({ "type": "enum", "name": "E" })).unwrap_err();
+    assert!(error.to_string().contains("missing field `symbols`"), "{error}");
+}
@@ -0,0 +1,412 @@
+use std::fmt;
+
+use indexmap::IndexMap;
+
+use crate::{
+    resolver::escape_pointer_token,
+    schema::{AdditionalProperties, ObjectType, Type},
+    AsyncAPI, Channel, Components, Diagnostic, Info, Message, Operation, OperationMessageType, Parameter, ReferenceOr, Schema, SchemaData, SchemaKind, SecurityRequirement, Server, ServerVariable,
+    Severity, Tag,
+};
+
+/// The error returned by the `build` methods of the builders when the built
+/// object is not valid.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuildError {
+    /// A required field is empty. `path` is the JSON Pointer of the object
+    /// holding the field.
+    MissingField { path: String, field: &'static str },
+    /// A property is `required` by an object schema but not declared in its
+    /// `properties`.
+    UndeclaredRequired(String),
+    /// The document violates the rules checked by [`AsyncAPI::validate`].
+    /// Holds the diagnostics of severity [`Severity::Error`].
+    Invalid(Vec<Diagnostic>),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::MissingField { path, field } => write!(f, "required field `{field}` of `{path}` is empty"),
+            BuildError::UndeclaredRequired(property) => write!(f, "required property `{property}` is not declared in the properties of the schema"),
+            BuildError::Invalid(diagnostics) => {
+                write!(f, "invalid document")?;
+                for diagnostic in diagnostics {
+                    write!(f, "\n{diagnostic}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+fn pointer(parent: &str, token: &str) -> String {
+    format!("{parent}/{}", escape_pointer_token(token))
+}
+
+fn require(path: &str, field: &'static str, value: &str) -> Result<(), BuildError> {
+    match value.is_empty() {
+        true => Err(BuildError::MissingField { path: path.to_owned(), field }),
+        false => Ok(()),
+    }
+}
+
+impl AsyncAPI {
+    /// Starts building a document of the given AsyncAPI version.
+    ///
+    /// ```
+    /// use asyncapi::{AsyncAPI, Info, Message, Payload, Schema, Server, ToSchema};
+    ///
+    /// let user_signed_up = Message {
+    ///     name: Some("UserSignedUp".to_owned()),
+    ///     payload: Some(Payload::Schema(Schema::object().property("displayName", String::schema()).required("displayName").build()?.into())),
+    ///     ..Default::default()
+    /// };
+    /// let asyncapi = AsyncAPI::builder("2.6.0", Info { title: "Account Service".to_owned(), version: "1.0.0".to_owned(), ..Default::default() })
+    ///     .server("production", Server::builder("broker.example.com", "mqtt"))
+    ///     .channel("user/signedup", |channel| channel.subscribe(|operation| operation.operation_id("userSignedUp").message(user_signed_up)))
+    ///     .build()?;
+    /// assert_eq!(asyncapi.channels["user/signedup"].subscribe.as_ref().unwrap().operation_id.as_deref(), Some("userSignedUp"));
+    /// # Ok::<(), asyncapi::BuildError>(())
+    /// ```
+    pub fn builder(asyncapi: impl Into<String>, info: Info) -> AsyncAPIBuilder {
+        AsyncAPIBuilder {
+            asyncapi: AsyncAPI {
+                asyncapi: asyncapi.into(),
+                info,
+                ..Default::default()
+            },
+            servers: IndexMap::new(),
+        }
+    }
+}
+
+/// Builds an [AsyncAPI] document, see [`AsyncAPI::builder`].
+#[derive(Debug, Clone)]
+pub struct AsyncAPIBuilder {
+    asyncapi: AsyncAPI,
+    servers: IndexMap<String, ServerBuilder>,
+}
+
+impl AsyncAPIBuilder {
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.asyncapi.id = Some(id.into());
+        self
+    }
+
+    pub fn default_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.asyncapi.default_content_type = Some(content_type.into());
+        self
+    }
+
+    pub fn server(mut self, name: impl Into<String>, server: ServerBuilder) -> Self {
+        self.servers.insert(name.into(), server);
+        self
+    }
+
+    /// Adds a channel, configured by `build` from an empty channel.
+    pub fn channel(mut self, name: impl Into<String>, build: impl FnOnce(ChannelBuilder) -> ChannelBuilder) -> Self {
+        let channel = build(ChannelBuilder { channel: Channel::default() }).channel;
+        self.asyncapi.channels.insert(name.into(), channel);
+        self
+    }
+
+    pub fn tag(mut self, tag: Tag) -> Self {
+        self.asyncapi.tags.push(tag);
+        self
+    }
+
+    /// Adds a schema to `components.schemas`.
+    pub fn schema(mut self, name: impl Into<String>, schema: impl Into<ReferenceOr<Schema>>) -> Self {
+        self.components().schemas.insert(name.into(), schema.into());
+        self
+    }
+
+    /// Adds a message to `components.messages`.
+    pub fn message(mut self, name: impl Into<String>, message: impl Into<ReferenceOr<Message>>) -> Self {
+        self.components().messages.insert(name.into(), message.into());
+        self
+    }
+
+    fn components(&mut self) -> &mut Components {
+        self.asyncapi.components.get_or_insert_with(Components::default)
+    }
+
+    /// Builds the document, checking that its required fields are set and
+    /// that it passes [`AsyncAPI::validate`] without errors.
+    pub fn build(self) -> Result<AsyncAPI, BuildError> {
+        let mut asyncapi = self.asyncapi;
+        require("", "asyncapi", &asyncapi.asyncapi)?;
+        require("/info", "title", &asyncapi.info.title)?;
+        require("/info", "version", &asyncapi.info.version)?;
+        for (name, server) in self.servers {
+            let server = server.build_at(&pointer("/servers", &name))?;
+            asyncapi.servers.insert(name, ReferenceOr::Item(server));
+        }
+        let errors: Vec<_> = asyncapi.validate().into_iter().filter(|diagnostic| diagnostic.severity == Severity::Error).collect();
+        match errors.is_empty() {
+            true => Ok(asyncapi),
+            false => Err(BuildError::Invalid(errors)),
+        }
+    }
+}
+
+impl Server {
+    /// Starts building a server of the given URL and protocol.
+    pub fn builder(url: impl Into<String>, protocol: impl Into<String>) -> ServerBuilder {
+        ServerBuilder {
+            server: Server {
+                url: url.into(),
+                protocol: protocol.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builds a [Server], see [`Server::builder`].
+#[derive(Debug, Clone)]
+pub struct ServerBuilder {
+    server: Server,
+}
+
+impl ServerBuilder {
+    pub fn protocol_version(mut self, protocol_version: impl Into<String>) -> Self {
+        self.server.protocol_version = Some(protocol_version.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.server.description = Some(description.into());
+        self
+    }
+
+    /// Adds a variable used in the `{variable}` expressions of the URL.
+    pub fn variable(mut self, name: impl Into<String>, variable: ServerVariable) -> Self {
+        self.server.variables.insert(name.into(), variable);
+        self
+    }
+
+    pub fn security(mut self, requirement: SecurityRequirement) -> Self {
+        self.server.security.push(requirement);
+        self
+    }
+
+    /// Builds the server, checking that its URL and protocol are set.
+    pub fn build(self) -> Result<Server, BuildError> {
+        self.build_at("")
+    }
+
+    fn build_at(self, path: &str) -> Result<Server, BuildError> {
+        require(path, "url", &self.server.url)?;
+        require(path, "protocol", &self.server.protocol)?;
+        Ok(self.server)
+    }
+}
+
+/// Configures a [Channel] added by [`AsyncAPIBuilder::channel`].
+#[derive(Debug, Clone)]
+pub struct ChannelBuilder {
+    channel: Channel,
+}
+
+impl ChannelBuilder {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.channel.description = Some(description.into());
+        self
+    }
+
+    /// Restricts the channel to a server declared in the document.
+    pub fn server(mut self, name: impl Into<String>) -> Self {
+        self.channel.servers.push(name.into());
+        self
+    }
+
+    /// Adds a parameter used in the `{parameter}` expressions of the channel name.
+    pub fn parameter(mut self, name: impl Into<String>, parameter: impl Into<ReferenceOr<Parameter>>) -> Self {
+        self.channel.parameters.insert(name.into(), parameter.into());
+        self
+    }
+
+    /// Sets the operation sending messages to the application.
+    pub fn subscribe(mut self, build: impl FnOnce(OperationBuilder) -> OperationBuilder) -> Self {
+        self.channel.subscribe = Some(build(OperationBuilder::default()).into_operation());
+        self
+    }
+
+    /// Sets the operation receiving messages from the application.
+    pub fn publish(mut self, build: impl FnOnce(OperationBuilder) -> OperationBuilder) -> Self {
+        self.channel.publish = Some(build(OperationBuilder::default()).into_operation());
+        self
+    }
+}
+
+/// Configures an [Operation] set by [`ChannelBuilder::subscribe`] or
+/// [`ChannelBuilder::publish`].
+#[derive(Debug, Clone, Default)]
+pub struct OperationBuilder {
+    operation: Operation,
+    messages: Vec<ReferenceOr<Message>>,
+}
+
+impl OperationBuilder {
+    pub fn operation_id(mut self, operation_id: impl Into<String>) -> Self {
+        self.operation.operation_id = Some(operation_id.into());
+        self
+    }
+
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.operation.summary = Some(summary.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.operation.description = Some(description.into());
+        self
+    }
+
+    pub fn tag(mut self, tag: Tag) -> Self {
+        self.operation.tags.push(tag);
+        self
+    }
+
+    /// Adds a message of the operation. An operation with several messages
+    /// accepts any of them, as a `oneOf`.
+    pub fn message(mut self, message: impl Into<ReferenceOr<Message>>) -> Self {
+        self.messages.push(message.into());
+        self
+    }
+
+    fn into_operation(mut self) -> Operation {
+        self.operation.message = match self.messages.len() {
+            0 => None,
+            1 => self.messages.pop().map(OperationMessageType::Single),
+            _ => Some(OperationMessageType::OneOf(self.messages)),
+        };
+        self.operation
+    }
+}
+
+impl Schema {
+    /// Starts building an object schema.
+    ///
+    /// ```
+    /// use asyncapi::{Schema, ToSchema};
+    ///
+    /// let schema = Schema::object().property("id", i64::schema()).property("displayName", String::schema()).required("id").build()?;
+    /// assert_eq!(serde_json::to_value(&schema).unwrap()["required"], serde_json::json!(["id"]));
+    /// # Ok::<(), asyncapi::BuildError>(())
+    /// ```
+    pub fn object() -> ObjectBuilder {
+        ObjectBuilder {
+            schema_data: SchemaData::default(),
+            object: ObjectType::default(),
+        }
+    }
+}
+
+/// Builds an object [Schema], see [`Schema::object`].
+#[derive(Debug, Clone)]
+pub struct ObjectBuilder {
+    schema_data: SchemaData,
+    object: ObjectType,
+}
+
+impl ObjectBuilder {
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.schema_data.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.schema_data.description = Some(description.into());
+        self
+    }
+
+    pub fn property(mut self, name: impl Into<String>, schema: impl Into<ReferenceOr<Schema>>) -> Self {
+        let schema = match schema.into() {
+            ReferenceOr::Reference { reference } => ReferenceOr::Reference { reference },
+            ReferenceOr::Item(schema) => ReferenceOr::Item(Box::new(schema)),
+        };
+        self.object.properties.insert(name.into(), schema);
+        self
+    }
+
+    /// Marks a property declared by [`Self::property`] as required.
+    pub fn required(mut self, name: impl Into<String>) -> Self {
+        self.object.required.push(name.into());
+        self
+    }
+
+    /// Sets whether properties other than the declared ones are allowed.
+    pub fn additional_properties(mut self, allowed: bool) -> Self {
+        self.object.additional_properties = Some(AdditionalProperties::Any(allowed));
+        self
+    }
+
+    /// Builds the schema, checking that its required properties are declared.
+    pub fn build(self) -> Result<Schema, BuildError> {
+        if let Some(property) = self.object.required.iter().find(|property| !self.object.properties.contains_key(*property)) {
+            return Err(BuildError::UndeclaredRequired(property.clone()));
+        }
+        Ok(Schema {
+            schema_data: self.schema_data,
+            schema_kind: SchemaKind::Type(Type::Object(self.object)),
+        })
+    }
+}
+
+#[test]
+fn test_builder() {
+    use crate::ToSchema;
+
+    let info = Info {
+        title: "Game Server".to_owned(),
+        version: "1.0.0".to_owned(),
+        ..Default::default()
+    };
+    let moved = Message {
+        name: Some("PlayerMoved".to_owned()),
+        ..Default::default()
+    };
+    let asyncapi = AsyncAPI::builder("2.6.0", info.clone())
+        .server("production", Server::builder("game.example.com", "ws"))
+        .message("PlayerLeft", Message::default())
+        .channel("player/{playerId}", |channel| {
+            channel
+                .server("production")
+                .parameter("playerId", Parameter::default())
+                .publish(|operation| operation.operation_id("playerMoved").message(moved.clone()))
+                .subscribe(|operation| operation.message(moved.clone()).message(ReferenceOr::ref_("#/components/messages/PlayerLeft")))
+        })
+        .build()
+        .expect("Could not build the document");
+    let channel = &asyncapi.channels["player/{playerId}"];
+    assert_eq!(
+        channel.publish.as_ref().and_then(|operation| operation.message.clone()),
+        Some(OperationMessageType::Single(ReferenceOr::Item(moved)))
+    );
+    assert!(matches!(
+        channel.subscribe.as_ref().and_then(|operation| operation.message.as_ref()),
+        Some(OperationMessageType::OneOf(messages)) if messages.len() == 2
+    ));
+    let value = serde_json::to_value(&asyncapi).expect("Could not serialize the document");
+    assert_eq!(value["servers"]["production"]["protocol"], "ws");
+    assert_eq!(value["channels"]["player/{playerId}"]["subscribe"]["message"]["oneOf"][1]["$ref"], "#/components/messages/PlayerLeft");
+
+    let missing = AsyncAPI::builder("2.6.0", info.clone()).server("production", Server::builder("", "ws")).build();
+    assert_eq!(
+        missing,
+        Err(BuildError::MissingField {
+            path: "/servers/production".to_owned(),
+            field: "url"
+        })
+    );
+    let invalid = AsyncAPI::builder("2.6.0", info).channel("player", |channel| channel.server("staging")).build();
+    assert!(matches!(invalid, Err(BuildError::Invalid(diagnostics)) if diagnostics[0].path == "/channels/player/servers/0"));
+
+    let schema = Schema::object().property("id", i64::schema()).required("name").build();
+    assert_eq!(schema, Err(BuildError::UndeclaredRequired("name".to_owned())));
+}
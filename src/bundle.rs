@@ -0,0 +1,423 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use indexmap::IndexMap;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    resolver::Component,
+    schema::{AdditionalProperties, Dependency, Items, Type},
+    AsyncAPI, Channel, Components, LoadError, Loaded, Loader, Message, MessageTrait, Operation, OperationMessageType, OperationTrait, Parameter, Payload, ReferenceOr, Schema, SchemaFormat,
+    SchemaKind, Server,
+};
+
+impl AsyncAPI {
+    /// Loads a specification split across several files and collapses it
+    /// into a single self-contained document.
+    ///
+    /// Every object referenced from another file, or from inside such a file,
+    /// is copied into the matching map of the
+    /// [Components Object][crate::Components] and the reference is rewritten
+    /// to a local `#/components/{kind}/{name}` pointer. Names are derived from
+    /// the last token of the referenced pointer, or from the file name, and
+    /// suffixed with `_1`, `_2`, ... when already taken.
+    ///
+    /// Payloads of another `schemaFormat` than a schema one, such as an Avro
+    /// schema in a `.avsc` file, have no place in the components and are
+    /// inlined into their message instead.
+    pub fn bundle(root: impl AsRef<Path>) -> Result<AsyncAPI, LoadError> {
+        let mut loader = Loader::new();
+        let Loaded { path, value: mut asyncapi, .. } = loader.load_root(root)?;
+        let mut bundler = Bundler {
+            loader,
+            root: path.clone(),
+            base: path,
+            existing: asyncapi.components.clone().unwrap_or_default(),
+            bundled: Components::default(),
+            names: HashMap::new(),
+        };
+        bundler.map(&mut asyncapi.servers, Bundler::server)?;
+        bundler.channels(&mut asyncapi.channels)?;
+        if let Some(components) = asyncapi.components.as_mut() {
+            bundler.components(components)?;
+        }
+        merge_components(asyncapi.components.get_or_insert_with(Default::default), bundler.bundled);
+        Ok(asyncapi)
+    }
+}
+
+fn merge_components(components: &mut Components, bundled: Components) {
+    components.schemas.extend(bundled.schemas);
+    components.messages.extend(bundled.messages);
+    components.security_schemes.extend(bundled.security_schemes);
+    components.parameters.extend(bundled.parameters);
+    components.correlation_ids.extend(bundled.correlation_ids);
+    components.operation_traits.extend(bundled.operation_traits);
+    components.message_traits.extend(bundled.message_traits);
+    components.servers.extend(bundled.servers);
+    components.server_variables.extend(bundled.server_variables);
+    components.server_bindings.extend(bundled.server_bindings);
+    components.channel_bindings.extend(bundled.channel_bindings);
+    components.operation_bindings.extend(bundled.operation_bindings);
+    components.message_bindings.extend(bundled.message_bindings);
+}
+
+type Descend<T> = fn(&mut Bundler, &mut T) -> Result<(), LoadError>;
+
+struct Bundler {
+    loader: Loader,
+    /// The canonical path of the root document.
+    root: PathBuf,
+    /// The canonical path of the file the objects currently visited come from.
+    base: PathBuf,
+    /// The components of the root document, as loaded.
+    existing: Components,
+    /// The components copied from other files so far.
+    bundled: Components,
+    /// The local reference assigned to each `(file, pointer)` already bundled.
+    names: HashMap<(PathBuf, String), String>,
+}
+
+impl Bundler {
+    fn map<T>(&mut self, map: &mut IndexMap<String, T>, f: Descend<T>) -> Result<(), LoadError> {
+        map.values_mut().try_for_each(|item| f(self, item))
+    }
+
+    fn reference<T: Component + DeserializeOwned>(&mut self, item: &mut ReferenceOr<T>, descend: Descend<T>) -> Result<(), LoadError> {
+        match item {
+            ReferenceOr::Item(item) => descend(self, item),
+            ReferenceOr::Reference { reference } => {
+                if let Some(local) = self.bundle_reference(reference, descend)? {
+                    *reference = local;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn option<T: Component + DeserializeOwned>(&mut self, item: &mut Option<ReferenceOr<T>>, descend: Descend<T>) -> Result<(), LoadError> {
+        match item {
+            Some(item) => self.reference(item, descend),
+            None => Ok(()),
+        }
+    }
+
+    /// Copies the object a reference points to into the bundled components
+    /// and returns the local reference to use instead. Returns `None` for
+    /// references which are already local to the root document.
+    fn bundle_reference<T: Component + DeserializeOwned>(&mut self, reference: &str, descend: Descend<T>) -> Result<Option<String>, LoadError> {
+        if reference.starts_with('#') && self.base == self.root {
+            return Ok(None);
+        }
+        let Loaded { path, pointer, mut value } = self.loader.resolve::<T>(&self.base, reference)?;
+        if path == self.root {
+            return Ok(Some(format!("#{pointer}")));
+        }
+        let key = (path, pointer);
+        if let Some(local) = self.names.get(&key) {
+            return Ok(Some(local.clone()));
+        }
+        let name = self.unique_name::<T>(&key.0, &key.1);
+        let local = format!("#/components/{}/{name}", T::KIND);
+        self.names.insert(key.clone(), local.clone());
+        // Reserve the name before descending so that recursive references
+        // find it, the placeholder is replaced once the object is bundled.
+        T::component_map_mut(&mut self.bundled).insert(name.clone(), ReferenceOr::ref_(&local));
+        let previous = std::mem::replace(&mut self.base, key.0);
+        let result = descend(self, &mut value);
+        self.base = previous;
+        result?;
+        T::component_map_mut(&mut self.bundled).insert(name, ReferenceOr::Item(value));
+        Ok(Some(local))
+    }
+
+    fn unique_name<T: Component>(&self, path: &Path, pointer: &str) -> String {
+        let name = match pointer.rsplit('/').find(|token| !token.is_empty()) {
+            Some(token) => token.replace("~1", "/").replace("~0", "~"),
+            None => path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default(),
+        };
+        let name: String = name.chars().map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') { c } else { '_' }).collect();
+        let name = if name.is_empty() { "component".to_owned() } else { name };
+        let taken = |name: &str| T::component_map(&self.existing).contains_key(name) || T::component_map(&self.bundled).contains_key(name);
+        if !taken(&name) {
+            return name;
+        }
+        (1..).map(|index| format!("{name}_{index}")).find(|candidate| !taken(candidate)).expect("an unused name")
+    }
+
+    fn components(&mut self, components: &mut Components) -> Result<(), LoadError> {
+        self.map(&mut components.schemas, |b, schema| b.reference(schema, Bundler::schema))?;
+        self.map(&mut components.messages, |b, message| b.reference(message, Bundler::message))?;
+        self.map(&mut components.security_schemes, |b, scheme| b.reference(scheme, leaf))?;
+        self.map(&mut components.parameters, |b, parameter| b.reference(parameter, Bundler::parameter))?;
+        self.map(&mut components.correlation_ids, |b, id| b.reference(id, leaf))?;
+        self.map(&mut components.operation_traits, |b, operation_trait| b.reference(operation_trait, Bundler::operation_trait))?;
+        self.map(&mut components.message_traits, |b, message_trait| b.reference(message_trait, Bundler::message_trait))?;
+        self.map(&mut components.servers, Bundler::server)?;
+        self.map(&mut components.server_variables, |b, variable| b.reference(variable, leaf))?;
+        self.map(&mut components.server_bindings, |b, binding| b.reference(binding, leaf))?;
+        self.channels(&mut components.channels)?;
+        self.map(&mut components.channel_bindings, |b, binding| b.reference(binding, leaf))?;
+        self.map(&mut components.operation_bindings, |b, binding| b.reference(binding, leaf))?;
+        self.map(&mut components.message_bindings, |b, binding| b.reference(binding, leaf))
+    }
+
+    fn server(&mut self, server: &mut ReferenceOr<Server>) -> Result<(), LoadError> {
+        self.reference(server, |b, server| b.option(&mut server.bindings, leaf))
+    }
+
+    fn channels(&mut self, channels: &mut IndexMap<String, Channel>) -> Result<(), LoadError> {
+        self.map(channels, |b, channel| {
+            b.map(&mut channel.parameters, |b, parameter| b.reference(parameter, Bundler::parameter))?;
+            b.option(&mut channel.bindings, leaf)?;
+            if let Some(subscribe) = channel.subscribe.as_mut() {
+                b.operation(subscribe)?;
+            }
+            if let Some(publish) = channel.publish.as_mut() {
+                b.operation(publish)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn parameter(&mut self, parameter: &mut Parameter) -> Result<(), LoadError> {
+        self.option(&mut parameter.schema, Bundler::schema)
+    }
+
+    fn operation(&mut self, operation: &mut Operation) -> Result<(), LoadError> {
+        self.option(&mut operation.bindings, leaf)?;
+        for operation_trait in operation.traits.iter_mut() {
+            self.reference(operation_trait, Bundler::operation_trait)?;
+        }
+        match operation.message.as_mut() {
+            Some(OperationMessageType::Single(message)) => self.reference(message, Bundler::message),
+            Some(OperationMessageType::Map(messages)) => self.map(messages, |b, message| b.reference(message, Bundler::message)),
+            Some(OperationMessageType::OneOf(messages)) => messages.iter_mut().try_for_each(|message| self.reference(message, Bundler::message)),
+            None => Ok(()),
+        }
+    }
+
+    fn operation_trait(&mut self, operation_trait: &mut OperationTrait) -> Result<(), LoadError> {
+        self.option(&mut operation_trait.bindings, leaf)
+    }
+
+    fn message(&mut self, message: &mut Message) -> Result<(), LoadError> {
+        self.option(&mut message.headers, Bundler::schema)?;
+        let format = SchemaFormat::of(message.schema_format.as_deref());
+        match message.payload.as_mut() {
+            Some(Payload::Schema(ReferenceOr::Reference { reference })) if !format.is_schema() => {
+                if let Some(payload) = self.inline_payload(&format, &reference.clone())? {
+                    message.payload = Some(payload);
+                }
+            }
+            Some(Payload::Schema(payload)) => self.reference(payload, Bundler::schema)?,
+            Some(Payload::OneOf { one_of, .. }) => one_of.iter_mut().try_for_each(|kind| self.schema(&mut kind.payload))?,
+            _ => {}
+        }
+        self.option(&mut message.correlation_id, leaf)?;
+        self.option(&mut message.bindings, leaf)?;
+        for message_trait in message.traits.iter_mut() {
+            self.reference(message_trait, Bundler::message_trait)?;
+        }
+        Ok(())
+    }
+
+    /// Reads the payload a reference points to according to the
+    /// `schemaFormat` of its message. Payloads which are not schemas can not
+    /// be kept in the components, so they are inlined instead. Returns `None`
+    /// for references which are already local to the root document.
+    fn inline_payload(&mut self, format: &SchemaFormat, reference: &str) -> Result<Option<Payload>, LoadError> {
+        if reference.starts_with('#') && self.base == self.root {
+            return Ok(None);
+        }
+        let Loaded { path, pointer, value } = self.loader.resolve_value(&self.base, reference)?;
+        match Payload::from_format(format, value) {
+            Ok(payload) => Ok(Some(payload)),
+            Err(error) => Err(LoadError::Deserialize {
+                path,
+                pointer: format!("{pointer}{}", error.pointer),
+                message: error.message,
+            }),
+        }
+    }
+
+    fn message_trait(&mut self, message_trait: &mut MessageTrait) -> Result<(), LoadError> {
+        self.option(&mut message_trait.headers, Bundler::schema)?;
+        self.option(&mut message_trait.correlation_id, leaf)?;
+        self.option(&mut message_trait.bindings, leaf)
+    }
+
+    fn boxed_schema(&mut self, schema: &mut ReferenceOr<Box<Schema>>) -> Result<(), LoadError> {
+        match schema {
+            ReferenceOr::Item(schema) => self.schema(schema),
+            ReferenceOr::Reference { reference } => {
+                if let Some(local) = self.bundle_reference(reference, Bundler::schema)? {
+                    *reference = local;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn boxed_option(&mut self, schema: &mut Option<ReferenceOr<Box<Schema>>>) -> Result<(), LoadError> {
+        schema.as_mut().map_or(Ok(()), |schema| self.boxed_schema(schema))
+    }
+
+    fn additional(&mut self, additional: &mut Option<AdditionalProperties>) -> Result<(), LoadError> {
+        match additional {
+            Some(AdditionalProperties::Schema(schema)) => self.reference(schema, Bundler::schema),
+            _ => Ok(()),
+        }
+    }
+
+    fn object_like(
+        &mut self,
+        properties: &mut IndexMap<String, ReferenceOr<Box<Schema>>>,
+        pattern_properties: &mut IndexMap<String, ReferenceOr<Box<Schema>>>,
+        additional_properties: &mut Option<AdditionalProperties>,
+        property_names: &mut Option<ReferenceOr<Box<Schema>>>,
+        dependencies: &mut IndexMap<String, Dependency>,
+    ) -> Result<(), LoadError> {
+        self.map(properties, Bundler::boxed_schema)?;
+        self.map(pattern_properties, Bundler::boxed_schema)?;
+        self.additional(additional_properties)?;
+        self.boxed_option(property_names)?;
+        self.map(dependencies, |bundler, dependency| match dependency {
+            Dependency::Schema(schema) => bundler.boxed_schema(schema),
+            Dependency::Properties(_) => Ok(()),
+        })
+    }
+
+    fn array_like(&mut self, items: &mut Option<Items>, additional_items: &mut Option<AdditionalProperties>, contains: &mut Option<ReferenceOr<Box<Schema>>>) -> Result<(), LoadError> {
+        match items {
+            Some(Items::Schema(schema)) => self.boxed_schema(schema)?,
+            Some(Items::Tuple(schemas)) => schemas.iter_mut().try_for_each(|schema| self.boxed_schema(schema))?,
+            None => {}
+        }
+        self.additional(additional_items)?;
+        self.boxed_option(contains)
+    }
+
+    fn schema(&mut self, schema: &mut Schema) -> Result<(), LoadError> {
+        let data = &mut schema.schema_data;
+        self.map(&mut data.definitions, Bundler::boxed_schema)?;
+        for subschema in [&mut data.not, &mut data.if_schema, &mut data.then_schema, &mut data.else_schema] {
+            self.boxed_option(subschema)?;
+        }
+        for schemas in [&mut data.all_of, &mut data.any_of, &mut data.one_of] {
+            schemas.iter_mut().try_for_each(|schema| self.reference(schema, Bundler::schema))?;
+        }
+        match &mut schema.schema_kind {
+            SchemaKind::Type(Type::Object(object)) => self.object_like(
+                &mut object.properties,
+                &mut object.pattern_properties,
+                &mut object.additional_properties,
+                &mut object.property_names,
+                &mut object.dependencies,
+            ),
+            SchemaKind::Type(Type::Array(array)) => self.array_like(&mut array.items, &mut array.additional_items, &mut array.contains),
+            SchemaKind::Type(_) | SchemaKind::Bool(_) => Ok(()),
+            SchemaKind::OneOf { one_of: schemas } | SchemaKind::AllOf { all_of: schemas } | SchemaKind::AnyOf { any_of: schemas } => {
+                schemas.iter_mut().try_for_each(|schema| self.reference(schema, Bundler::schema))
+            }
+            SchemaKind::Any(any) => {
+                self.object_like(
+                    &mut any.properties,
+                    &mut any.pattern_properties,
+                    &mut any.additional_properties,
+                    &mut any.property_names,
+                    &mut any.dependencies,
+                )?;
+                self.array_like(&mut any.items, &mut any.additional_items, &mut any.contains)
+            }
+        }
+    }
+}
+
+/// Descend function for objects which can not contain further references.
+fn leaf<T>(_: &mut Bundler, _: &mut T) -> Result<(), LoadError> {
+    Ok(())
+}
+
+#[test]
+fn test_bundle() {
+    use std::fs;
+
+    let dir = std::env::temp_dir().join(format!("asyncapi-bundle-{}", std::process::id()));
+    fs::create_dir_all(dir.join("schemas")).unwrap();
+    fs::write(
+        dir.join("asyncapi.yaml"),
+        r#"
+asyncapi: 2.6.0
+info:
+  title: Bundle
+  version: 1.0.0
+channels:
+  user/signedup:
+    subscribe:
+      message:
+        $ref: "messages.yaml#/UserSignedUp"
+    publish:
+      message:
+        oneOf:
+          - $ref: "messages.yaml#/UserSignedUp"
+components:
+  messages:
+    UserDeleted:
+      schemaFormat: application/vnd.apache.avro;version=1.9.0
+      payload:
+        $ref: "schemas/user.avsc"
+  schemas:
+    User:
+      type: string
+"#,
+    )
+    .unwrap();
+    fs::write(dir.join("messages.yaml"), "UserSignedUp:\n  payload:\n    $ref: 'schemas/user.yaml#/User'\n").unwrap();
+    fs::write(
+        dir.join("schemas").join("user.yaml"),
+        "User:\n  type: object\n  properties:\n    friend:\n      $ref: '#/User'\n    name:\n      $ref: '../asyncapi.yaml#/components/schemas/User'\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("schemas").join("user.avsc"),
+        r#"{ "type": "record", "name": "User", "fields": [{ "name": "id", "type": "string" }] }"#,
+    )
+    .unwrap();
+
+    let asyncapi = AsyncAPI::bundle(dir.join("asyncapi.yaml")).unwrap();
+    fs::remove_dir_all(dir).unwrap();
+
+    let components = asyncapi.components.unwrap();
+    assert_eq!(
+        asyncapi.channels["user/signedup"].subscribe.as_ref().unwrap().message,
+        Some(OperationMessageType::Single(ReferenceOr::ref_("#/components/messages/UserSignedUp")))
+    );
+    assert_eq!(
+        asyncapi.channels["user/signedup"].publish.as_ref().unwrap().message,
+        Some(OperationMessageType::OneOf(vec![ReferenceOr::ref_("#/components/messages/UserSignedUp")]))
+    );
+    let message = match &components.messages["UserSignedUp"] {
+        ReferenceOr::Item(message) => message,
+        other => panic!("message was not bundled: {other:?}"),
+    };
+    assert_eq!(message.payload, Some(Payload::Schema(ReferenceOr::ref_("#/components/schemas/User_1"))));
+    let user = match &components.schemas["User_1"] {
+        ReferenceOr::Item(Schema {
+            schema_kind: SchemaKind::Type(Type::Object(user)),
+            ..
+        }) => user,
+        other => panic!("schema was not bundled: {other:?}"),
+    };
+    assert_eq!(user.properties["friend"], ReferenceOr::ref_("#/components/schemas/User_1"));
+    assert_eq!(user.properties["name"], ReferenceOr::ref_("#/components/schemas/User"));
+    assert!(matches!(
+        &components.messages["UserDeleted"],
+        ReferenceOr::Item(Message {
+            payload: Some(Payload::Avro(_)),
+            ..
+        })
+    ));
+}
@@ -1,7 +1,7 @@
 use indexmap::IndexMap;
-use serde::{Deserialize, Serialize};
+use serde::{ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{ChannelBinding, ExternalDocumentation, Message, OperationBinding, OperationTrait, Parameter, ReferenceOr, SecurityRequirement, Tag};
+use crate::{de, ChannelBinding, ExternalDocumentation, Message, OperationBinding, OperationTrait, Parameter, ReferenceOr, SecurityRequirement, Tag};
 
 /// Describes the operations available on a single channel.
 ///
@@ -303,9 +303,59 @@ pub struct Operation {
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Deserialized as a `oneOf` of messages when it has a `oneOf`, as a single
+/// message when it is a reference or has a field of [Message] or an
+/// extension, and as a map of messages otherwise.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 #[serde(untagged)]
 pub enum OperationMessageType {
     Map(IndexMap<String, ReferenceOr<Message>>),
     Single(ReferenceOr<Message>),
+    /// Any one of several messages.
+    #[serde(serialize_with = "serialize_one_of")]
+    OneOf(Vec<ReferenceOr<Message>>),
+}
+
+fn serialize_one_of<S: Serializer>(messages: &[ReferenceOr<Message>], serializer: S) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry("oneOf", messages)?;
+    map.end()
+}
+
+/// The fields of [Message] which mark a single message.
+const MESSAGE_FIELDS: &[&str] = &[
+    "$ref",
+    "messageId",
+    "headers",
+    "payload",
+    "correlationId",
+    "schemaFormat",
+    "contentType",
+    "name",
+    "title",
+    "summary",
+    "description",
+    "tags",
+    "externalDocs",
+    "bindings",
+    "examples",
+    "traits",
+];
+
+impl<'de> Deserialize<'de> for OperationMessageType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = de::buffer(deserializer)?;
+        if de::has_key(&value, "oneOf") {
+            return de::field(&mut value, "oneOf").map(OperationMessageType::OneOf);
+        }
+        let single = match value.as_object() {
+            Some(object) => object.keys().any(|key| key.starts_with("x-") || MESSAGE_FIELDS.contains(&key.as_str())),
+            None => true,
+        };
+        match single {
+            true => de::variant(value).map(OperationMessageType::Single),
+            false => de::variant(value).map(OperationMessageType::Map),
+        }
+    }
 }
@@ -528,7 +528,7 @@ pub struct GoogleSchemaSettings {
 ///       payload: true
 /// # ...
 /// ```
-
+///
 /// ```yaml
 /// channels:
 ///   user-signedup:
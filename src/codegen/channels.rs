@@ -0,0 +1,323 @@
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+
+use super::{field_ident, TypeGenerator};
+use crate::schema::Type;
+use crate::{AsyncAPI, Channel, Component, Components, Operation, ReferenceOr, SchemaKind};
+
+/// A parameter of a channel name, e.g. `userId` in `user/{userId}/signedup`.
+struct ChannelParameter {
+    ident: Ident,
+    typ: TokenStream,
+    is_string: bool,
+}
+
+/// Generates the payload types of a document, as [`generate_types`][super::generate_types],
+/// and a trait for every operation of its channels.
+///
+/// The `publish` operation of a channel, whose messages are received by the
+/// application, becomes a `<Channel>Handler` trait with a method for the
+/// operation and a `dispatch_<channel>` method deserializing the payload of
+/// a message received on a matching address. The `subscribe` operation,
+/// whose messages are sent by the application, becomes a `<Channel>Sender`
+/// trait implemented for every `Transport`. The methods are named after the
+/// `operationId` of the operations and take the parameters of the channel
+/// as arguments, before the payload.
+///
+/// ```
+/// let asyncapi: asyncapi::AsyncAPI = serde_yaml::from_str(r#"
+/// asyncapi: 2.6.0
+/// info:
+///   title: Account Service
+///   version: 1.0.0
+/// channels:
+///   user/{userId}/signedup:
+///     parameters:
+///       userId:
+///         schema:
+///           type: string
+///     subscribe:
+///       operationId: userSignedUp
+///       message:
+///         name: UserSignedUp
+///         payload:
+///           type: object
+/// "#).unwrap();
+/// let source = asyncapi::codegen::format_source(asyncapi::codegen::generate_channels(&asyncapi)).unwrap();
+/// assert!(source.contains("pub trait UserUserIdSignedupSender: Transport {"));
+/// assert!(source.contains("fn user_signed_up("));
+/// assert!(source.contains("payload: &UserSignedUpPayload,"));
+/// ```
+pub fn generate_channels(asyncapi: &AsyncAPI) -> TokenStream {
+    let components = asyncapi.components.as_ref();
+    let mut generator = TypeGenerator::new(components);
+    generator.components();
+    generator.component_messages();
+
+    let mut traits = Vec::new();
+    let mut handlers = false;
+    for (name, channel) in &asyncapi.channels {
+        let parameters = channel_parameters(components, name, channel);
+        if let Some(operation) = &channel.publish {
+            let payload = payload(&mut generator, name, "publish", operation);
+            traits.push(handler_trait(&mut generator, name, channel, operation, &parameters, payload));
+            handlers = true;
+        }
+        if let Some(operation) = &channel.subscribe {
+            let payload = payload(&mut generator, name, "subscribe", operation);
+            traits.push(sender_trait(&mut generator, name, channel, operation, &parameters, payload));
+        }
+    }
+
+    let types = generator.into_tokens();
+    let transport = (!asyncapi.channels.is_empty()).then(|| {
+        quote! {
+            /// Sends the payloads of messages to the address of a channel.
+            pub trait Transport {
+                type Error;
+
+                fn send<P: serde::Serialize + ?Sized>(&self, address: &str, payload: &P) -> Result<(), Self::Error>;
+            }
+        }
+    });
+    let match_address = handlers.then(|| {
+        quote! {
+            /// Matches an address against the parts of a channel name around its
+            /// parameters, and returns the values of the parameters.
+            fn match_address<'a>(address: &'a str, parts: &[&str]) -> Option<Vec<&'a str>> {
+                let mut rest = address.strip_prefix(parts[0])?;
+                let mut values = Vec::new();
+                for part in &parts[1..] {
+                    let (value, next) = if part.is_empty() { (rest, "") } else { rest.split_once(part)? };
+                    values.push(value);
+                    rest = next;
+                }
+                rest.is_empty().then_some(values)
+            }
+        }
+    });
+    quote! {
+        #types
+        #transport
+        #match_address
+        #(#traits)*
+    }
+}
+
+fn payload(generator: &mut TypeGenerator, channel_name: &str, field: &str, operation: &Operation) -> TokenStream {
+    let operation_name = operation.operation_id.clone().unwrap_or_else(|| format!("{channel_name} {field}"));
+    match &operation.message {
+        Some(message) => generator.operation_payload(&operation_name, message),
+        None => quote!(()),
+    }
+}
+
+fn handler_trait(generator: &mut TypeGenerator, channel_name: &str, channel: &Channel, operation: &Operation, parameters: &[ChannelParameter], payload: TokenStream) -> TokenStream {
+    let ident = generator.unique_ident(&format!("{channel_name} handler"));
+    let method = generator.unique_method(method_ident(operation, "on", channel_name));
+    let dispatch = generator.unique_method(field_ident(&format!("dispatch {channel_name}")));
+    let (parts, _) = split_channel_name(channel_name);
+    let arguments = parameters.iter().map(|parameter| {
+        let (ident, typ) = (&parameter.ident, &parameter.typ);
+        quote!(#ident: #typ)
+    });
+    let idents: Vec<_> = parameters.iter().map(|parameter| &parameter.ident).collect();
+    let indexes = (0..idents.len()).map(Literal::usize_unsuffixed);
+    let values = match idents.is_empty() {
+        true => quote!(_),
+        false => quote!(values),
+    };
+    let trait_doc = channel_doc(channel, &format!(" Handles the messages published to `{channel_name}`."));
+    let method_doc = operation_doc(operation);
+    let dispatch_doc = format!(" Calls [`Self::{method}`] when `address` matches `{channel_name}`, with the payload deserialized from JSON.");
+    quote! {
+        #trait_doc
+        pub trait #ident {
+            #method_doc
+            fn #method(&mut self, #(#arguments,)* payload: #payload);
+
+            #[doc = #dispatch_doc]
+            /// Returns `None` when the address does not match the channel.
+            fn #dispatch(&mut self, address: &str, payload: &[u8]) -> Option<Result<(), serde_json::Error>> {
+                let #values = match_address(address, &[#(#parts),*])?;
+                #(let #idents = values[#indexes].parse().ok()?;)*
+                let payload = match serde_json::from_slice(payload) {
+                    Ok(payload) => payload,
+                    Err(error) => return Some(Err(error)),
+                };
+                self.#method(#(#idents,)* payload);
+                Some(Ok(()))
+            }
+        }
+    }
+}
+
+fn sender_trait(generator: &mut TypeGenerator, channel_name: &str, channel: &Channel, operation: &Operation, parameters: &[ChannelParameter], payload: TokenStream) -> TokenStream {
+    let ident = generator.unique_ident(&format!("{channel_name} sender"));
+    let method = generator.unique_method(method_ident(operation, "send", channel_name));
+    let (_, template) = split_channel_name(channel_name);
+    let arguments = parameters.iter().map(|parameter| {
+        let ident = &parameter.ident;
+        match parameter.is_string {
+            true => quote!(#ident: &str),
+            false => {
+                let typ = &parameter.typ;
+                quote!(#ident: #typ)
+            }
+        }
+    });
+    let idents = parameters.iter().map(|parameter| &parameter.ident);
+    let trait_doc = channel_doc(channel, &format!(" Sends the messages of `{channel_name}`."));
+    let method_doc = operation_doc(operation);
+    quote! {
+        #trait_doc
+        pub trait #ident: Transport {
+            #method_doc
+            fn #method(&self, #(#arguments,)* payload: &#payload) -> Result<(), Self::Error> {
+                self.send(&format!(#template #(, #idents)*), payload)
+            }
+        }
+
+        impl<T: Transport + ?Sized> #ident for T {}
+    }
+}
+
+/// The method of an operation, named after its `operationId` or after the channel.
+fn method_ident(operation: &Operation, prefix: &str, channel_name: &str) -> Ident {
+    match &operation.operation_id {
+        Some(operation_id) => field_ident(operation_id),
+        None => field_ident(&format!("{prefix} {channel_name}")),
+    }
+}
+
+/// Splits a channel name into the literal parts around its parameters, and
+/// builds the `format!` template of the name.
+fn split_channel_name(channel_name: &str) -> (Vec<String>, String) {
+    let mut parts = vec![String::new()];
+    let mut template = String::new();
+    let mut rest = channel_name;
+    while let Some((literal, parameter)) = rest.split_once('{').and_then(|(literal, rest)| Some((literal, rest.split_once('}')?))) {
+        parts.last_mut().expect("a part").push_str(literal);
+        template.push_str(&literal.replace('{', "{{").replace('}', "}}"));
+        template.push_str("{}");
+        parts.push(String::new());
+        rest = parameter.1;
+    }
+    parts.last_mut().expect("a part").push_str(rest);
+    template.push_str(&rest.replace('{', "{{").replace('}', "}}"));
+    (parts, template)
+}
+
+/// The parameters of a channel, in the order of the channel name. They are
+/// strings unless their schema is a number, an integer or a boolean.
+fn channel_parameters(components: Option<&Components>, channel_name: &str, channel: &Channel) -> Vec<ChannelParameter> {
+    let mut rest = channel_name;
+    let mut parameters = Vec::new();
+    while let Some((name, next)) = rest.split_once('{').and_then(|(_, rest)| rest.split_once('}')) {
+        let parameter = channel.parameters.get(name).and_then(|parameter| resolve(components, parameter));
+        let schema = parameter.and_then(|parameter| parameter.schema.as_ref()).and_then(|schema| resolve(components, schema));
+        let typ = match schema.map(|schema| &schema.schema_kind) {
+            Some(SchemaKind::Type(Type::Integer(_))) => quote!(i64),
+            Some(SchemaKind::Type(Type::Number(_))) => quote!(f64),
+            Some(SchemaKind::Type(Type::Boolean(_))) => quote!(bool),
+            _ => quote!(String),
+        };
+        parameters.push(ChannelParameter {
+            ident: field_ident(name),
+            is_string: typ.to_string() == "String",
+            typ,
+        });
+        rest = next;
+    }
+    parameters
+}
+
+fn resolve<'a, T: Component>(components: Option<&'a Components>, item: &'a ReferenceOr<T>) -> Option<&'a T> {
+    match item {
+        ReferenceOr::Item(item) => Some(item),
+        ReferenceOr::Reference { reference } => components?.resolve_reference(reference).ok(),
+    }
+}
+
+fn channel_doc(channel: &Channel, fallback: &str) -> TokenStream {
+    let mut lines = vec![fallback.to_owned()];
+    if let Some(description) = &channel.description {
+        lines.push(String::new());
+        lines.extend(description.trim().lines().map(|line| format!(" {line}")));
+    }
+    quote!(#(#[doc = #lines])*)
+}
+
+fn operation_doc(operation: &Operation) -> TokenStream {
+    let text = [&operation.summary, &operation.description]
+        .into_iter()
+        .flatten()
+        .map(|text| text.trim())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let lines = text.lines().map(|line| format!(" {line}"));
+    quote!(#(#[doc = #lines])*)
+}
+
+#[test]
+fn test_generate_channels() {
+    let asyncapi: AsyncAPI = serde_yaml::from_str(
+        r##"
+        asyncapi: 2.6.0
+        info:
+          title: Game Server
+          version: 1.0.0
+        channels:
+          room/{roomId}/player/{playerId}:
+            description: Players of a room.
+            parameters:
+              roomId:
+                $ref: "#/components/parameters/roomId"
+              playerId:
+                schema:
+                  type: string
+            publish:
+              operationId: playerMoved
+              summary: A player moved.
+              message:
+                $ref: "#/components/messages/PlayerMoved"
+            subscribe:
+              operationId: sendPlayerState
+              message:
+                oneOf:
+                  - $ref: "#/components/messages/PlayerMoved"
+                  - name: PlayerLeft
+                    payload:
+                      type: string
+        components:
+          parameters:
+            roomId:
+              schema:
+                type: integer
+          messages:
+            PlayerMoved:
+              payload:
+                type: object
+                required: [x]
+                properties:
+                  x:
+                    type: number
+        "##,
+    )
+    .expect("Could not deserialize the document");
+    let source = super::format_source(generate_channels(&asyncapi)).unwrap();
+    let expected = [
+        "/// Handles the messages published to `room/{roomId}/player/{playerId}`.\n///\n/// Players of a room.\npub trait RoomRoomIdPlayerPlayerIdHandler {",
+        "    /// A player moved.\n    fn player_moved(\n        &mut self,\n        room_id: i64,\n        player_id: String,\n        payload: PlayerMovedPayload,\n    );",
+        "    fn dispatch_room_room_id_player_player_id(\n        &mut self,\n        address: &str,\n        payload: &[u8],\n    ) -> Option<Result<(), serde_json::Error>> {\n        let values = match_address(address, &[\"room/\", \"/player/\", \"\"])?;\n        let room_id = values[0].parse().ok()?;",
+        "pub trait RoomRoomIdPlayerPlayerIdSender: Transport {",
+        "        self.send(&format!(\"room/{}/player/{}\", room_id, player_id), payload)",
+        "impl<T: Transport + ?Sized> RoomRoomIdPlayerPlayerIdSender for T {}",
+        "#[serde(untagged)]\npub enum SendPlayerStateMessage {\n    PlayerMoved(PlayerMovedPayload),\n    PlayerLeft(PlayerLeftPayload),\n}",
+        "payload: &SendPlayerStateMessage,",
+    ];
+    for expected in expected {
+        assert!(source.contains(expected), "missing `{expected}` in:\n{source}");
+    }
+    assert_eq!(split_channel_name("a/{b}").0, vec!["a/", ""]);
+}
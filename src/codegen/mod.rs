@@ -0,0 +1,111 @@
+//! Generation of Rust source code from AsyncAPI documents, with the
+//! `codegen` feature.
+//!
+//! [`generate_types`] emits a Rust type for every schema of
+//! `components.schemas` and for every message payload, the reverse of
+//! [`ToSchema`][crate::ToSchema]. [`generate_channels`] also emits a trait
+//! for every operation, to handle or send its messages. The tokens can be
+//! written to a file with [`format_source`], e.g. from a build script.
+//!
+//! ```
+//! let asyncapi: asyncapi::AsyncAPI = serde_yaml::from_str(r#"
+//! asyncapi: 2.6.0
+//! info:
+//!   title: Account Service
+//!   version: 1.0.0
+//! channels: {}
+//! components:
+//!   schemas:
+//!     User:
+//!       type: object
+//!       required: [id]
+//!       properties:
+//!         id:
+//!           type: integer
+//!           format: int64
+//!         displayName:
+//!           type: string
+//! "#).unwrap();
+//! let source = asyncapi::codegen::format_source(asyncapi::codegen::generate_types(&asyncapi)).unwrap();
+//! assert!(source.contains("pub struct User {"));
+//! assert!(source.contains("pub display_name: Option<String>,"));
+//! ```
+
+mod channels;
+mod types;
+
+use convert_case::{Case, Casing};
+use proc_macro2::{Ident, Span, TokenStream};
+
+use crate::AsyncAPI;
+
+pub use channels::generate_channels;
+pub(crate) use types::TypeGenerator;
+
+/// Generates the Rust types of the schemas of `components.schemas` and of
+/// the payloads of the messages of a document.
+///
+/// - Objects become structs. Properties which are not `required` are
+///   `Option`s, and properties whose name is not a valid Rust identifier in
+///   snake case are renamed with serde.
+/// - `oneOf` and `anyOf` become enums, tagged by the `discriminator` of
+///   the schema when it has one and untagged otherwise. `allOf` becomes a
+///   struct flattening each of the schemas.
+/// - Strings with an `enum` become enums and strings with a `format`
+///   become newtypes, shared by all the strings of that format.
+/// - References to `components.schemas` use the type of the schema.
+///
+/// The generated code depends on the `serde` and `serde_json` crates.
+pub fn generate_types(asyncapi: &AsyncAPI) -> TokenStream {
+    let mut generator = TypeGenerator::new(asyncapi.components.as_ref());
+    generator.components();
+    generator.messages(asyncapi);
+    generator.into_tokens()
+}
+
+/// Formats generated tokens as Rust source code, or returns the error of
+/// parsing tokens which are not a valid Rust file.
+pub fn format_source(tokens: TokenStream) -> syn::Result<String> {
+    let file = syn::parse2(tokens)?;
+    Ok(prettyplease::unparse(&file))
+}
+
+/// The identifier of a type, in pascal case.
+pub(crate) fn type_ident(name: &str) -> Ident {
+    ident(words(name).to_case(Case::Pascal))
+}
+
+/// The identifier of a field or function, in snake case.
+pub(crate) fn field_ident(name: &str) -> Ident {
+    ident(words(name).to_case(Case::Snake))
+}
+
+/// Splits a name on the characters which cannot appear in an identifier.
+fn words(name: &str) -> String {
+    name.chars().map(|char| if char.is_ascii_alphanumeric() { char } else { ' ' }).collect()
+}
+
+fn ident(name: String) -> Ident {
+    let name = match name.chars().next() {
+        None => "_".to_owned(),
+        Some(first) if first.is_ascii_digit() => format!("_{name}"),
+        Some(_) => name,
+    };
+    if syn::parse_str::<Ident>(&name).is_ok() {
+        return Ident::new(&name, Span::call_site());
+    }
+    match name.as_str() {
+        "self" | "Self" | "super" | "crate" | "_" => Ident::new(&format!("{name}_"), Span::call_site()),
+        _ => Ident::new_raw(&name, Span::call_site()),
+    }
+}
+
+#[test]
+fn test_idents() {
+    assert_eq!(type_ident("user/signedup").to_string(), "UserSignedup");
+    assert_eq!(type_ident("lightMeasured").to_string(), "LightMeasured");
+    assert_eq!(field_ident("displayName").to_string(), "display_name");
+    assert_eq!(field_ident("type").to_string(), "r#type");
+    assert_eq!(field_ident("2fa").to_string(), "_2_fa");
+    assert_eq!(type_ident("self").to_string(), "Self_");
+}
@@ -0,0 +1,704 @@
+use std::collections::{HashMap, HashSet};
+
+use indexmap::IndexMap;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+use super::{field_ident, type_ident};
+use crate::resolver::parse_component_pointer;
+use crate::schema::{AdditionalProperties, AnySchema, IntegerFormat, Items, NumberFormat, StringFormat, Type};
+use crate::{AsyncAPI, Channel, Components, Message, OperationMessageType, Payload, ReferenceOr, Schema, SchemaData, SchemaFormat, SchemaKind, VariantOrUnknownOrEmpty};
+
+/// Generates the Rust types of schemas. Every generated type is an item of
+/// the output with a unique name, and references to `components.schemas`
+/// use the name reserved for the component.
+pub(crate) struct TypeGenerator<'a> {
+    components: Option<&'a Components>,
+    items: Vec<TokenStream>,
+    names: HashSet<String>,
+    methods: HashSet<String>,
+    schema_types: IndexMap<String, Ident>,
+    format_types: HashMap<String, Ident>,
+    message_types: IndexMap<String, TokenStream>,
+}
+
+/// A variant of a generated enum.
+struct Variant<'s> {
+    name: String,
+    description: Option<&'s str>,
+    schema: VariantSchema<'s>,
+}
+
+enum VariantSchema<'s> {
+    Reference(&'s str),
+    Item(&'s Schema),
+}
+
+impl<'a> TypeGenerator<'a> {
+    pub(crate) fn new(components: Option<&'a Components>) -> Self {
+        let mut generator = TypeGenerator {
+            components,
+            items: Vec::new(),
+            names: HashSet::new(),
+            methods: HashSet::new(),
+            schema_types: IndexMap::new(),
+            format_types: HashMap::new(),
+            message_types: IndexMap::new(),
+        };
+        for name in components.into_iter().flat_map(|components| components.schemas.keys()) {
+            let ident = generator.unique_ident(name);
+            generator.schema_types.insert(name.clone(), ident);
+        }
+        generator
+    }
+
+    pub(crate) fn into_tokens(self) -> TokenStream {
+        let items = self.items;
+        quote!(#(#items)*)
+    }
+
+    /// Generates the types of `components.schemas`.
+    pub(crate) fn components(&mut self) {
+        let Some(components) = self.components else {
+            return;
+        };
+        for (name, schema) in &components.schemas {
+            let ident = self.schema_types[name].clone();
+            match schema {
+                ReferenceOr::Item(schema) => self.named_type(&ident, schema),
+                ReferenceOr::Reference { reference } => {
+                    let target = self.reference_type(reference);
+                    self.items.push(quote!(pub type #ident = #target;));
+                }
+            }
+        }
+    }
+
+    /// Generates the payload types of the messages of `components.messages`
+    /// and of the operations of the channels.
+    pub(crate) fn messages(&mut self, asyncapi: &AsyncAPI) {
+        self.component_messages();
+        self.channel_messages(&asyncapi.channels);
+        if let Some(components) = self.components {
+            self.channel_messages(&components.channels);
+        }
+    }
+
+    /// Generates the payload types of the messages of `components.messages`,
+    /// used by the references to these messages.
+    pub(crate) fn component_messages(&mut self) {
+        let Some(components) = self.components else {
+            return;
+        };
+        for (name, message) in &components.messages {
+            if let ReferenceOr::Item(message) = message {
+                let typ = self.payload_type(name, message);
+                self.message_types.insert(name.clone(), typ);
+            }
+        }
+    }
+
+    fn channel_messages(&mut self, channels: &IndexMap<String, Channel>) {
+        for (channel_name, channel) in channels {
+            for (field, operation) in [("publish", &channel.publish), ("subscribe", &channel.subscribe)] {
+                if let Some(message) = operation.as_ref().and_then(|operation| operation.message.as_ref()) {
+                    let operation_name = operation
+                        .as_ref()
+                        .and_then(|operation| operation.operation_id.clone())
+                        .unwrap_or_else(|| format!("{channel_name} {field}"));
+                    self.operation_payload(&operation_name, message);
+                }
+            }
+        }
+    }
+
+    /// The type of the payload of the messages of an operation. The payloads
+    /// of several messages are an untagged enum of their types, named after
+    /// the operation.
+    pub(crate) fn operation_payload(&mut self, operation_name: &str, message: &OperationMessageType) -> TokenStream {
+        match message {
+            OperationMessageType::Single(message) => self.operation_message(&format!("{operation_name} message"), message),
+            OperationMessageType::Map(messages) => self.messages_enum(operation_name, messages),
+            OperationMessageType::OneOf(one_of) => {
+                let mut messages = IndexMap::new();
+                for (index, message) in one_of.iter().enumerate() {
+                    let key = match message {
+                        ReferenceOr::Item(message) => message_name(message),
+                        ReferenceOr::Reference { reference } => reference.rsplit('/').next().map(str::to_owned),
+                    };
+                    messages.insert(key.unwrap_or_else(|| format!("message {index}")), message.clone());
+                }
+                self.messages_enum(operation_name, &messages)
+            }
+        }
+    }
+
+    fn messages_enum(&mut self, operation_name: &str, messages: &IndexMap<String, ReferenceOr<Message>>) -> TokenStream {
+        if let (1, Some((key, message))) = (messages.len(), messages.first()) {
+            return self.operation_message(key, message);
+        }
+        let ident = self.unique_ident(&format!("{operation_name} message"));
+        let mut names = HashSet::new();
+        let mut variants = Vec::new();
+        for (key, message) in messages {
+            let typ = self.operation_message(key, message);
+            let mut name = type_ident(key);
+            if !names.insert(name.to_string()) {
+                name = format_ident!("{}{}", name, names.len());
+            }
+            variants.push(quote!(#name(#typ),));
+        }
+        self.items.push(quote! {
+            #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+            #[serde(untagged)]
+            pub enum #ident {
+                #(#variants)*
+            }
+        });
+        quote!(#ident)
+    }
+
+    /// The payload type of an inline message, named after the message or
+    /// `fallback`, or of a message of `components.messages`.
+    fn operation_message(&mut self, fallback: &str, message: &ReferenceOr<Message>) -> TokenStream {
+        match message {
+            ReferenceOr::Item(message) => {
+                let name = message_name(message).unwrap_or_else(|| fallback.to_owned());
+                self.payload_type(&name, message)
+            }
+            ReferenceOr::Reference { reference } => match parse_component_pointer(reference) {
+                Ok((kind, name)) if kind == "messages" => self.message_types.get(&name).cloned().unwrap_or_else(|| quote!(serde_json::Value)),
+                _ => quote!(serde_json::Value),
+            },
+        }
+    }
+
+    /// The type of the payload of a message, generating it when the payload
+    /// is an inline schema or an Avro schema. Payloads of other schema
+    /// formats are JSON values.
+    fn payload_type(&mut self, name: &str, message: &Message) -> TokenStream {
+        let json_schema = SchemaFormat::of(message.schema_format.as_deref()).is_schema();
+        match &message.payload {
+            Some(Payload::Schema(ReferenceOr::Reference { reference })) if json_schema => self.reference_type(reference),
+            Some(Payload::Schema(ReferenceOr::Item(schema))) if json_schema => {
+                let ident = self.unique_ident(&format!("{name} payload"));
+                self.named_type(&ident, schema);
+                quote!(#ident)
+            }
+            Some(payload @ (Payload::OneOf { .. } | Payload::Avro(_))) => match payload.to_schema() {
+                Some(ReferenceOr::Item(schema)) => {
+                    let ident = self.unique_ident(&format!("{name} payload"));
+                    self.named_type(&ident, &schema);
+                    quote!(#ident)
+                }
+                _ => quote!(serde_json::Value),
+            },
+            Some(_) => quote!(serde_json::Value),
+            None => quote!(()),
+        }
+    }
+
+    /// Generates an item named `ident` for a schema: a struct, an enum, or a
+    /// type alias for the other schemas.
+    fn named_type(&mut self, ident: &Ident, schema: &Schema) {
+        let data = &schema.schema_data;
+        match &schema.schema_kind {
+            SchemaKind::Type(Type::Object(object)) if !object.properties.is_empty() || !matches!(object.additional_properties, Some(AdditionalProperties::Schema(_))) => {
+                self.object_struct(ident, data, &object.properties, &object.required, object.additional_properties.as_ref())
+            }
+            SchemaKind::Any(any) if !any.properties.is_empty() => self.object_struct(ident, data, &any.properties, &any.required, any.additional_properties.as_ref()),
+            SchemaKind::Type(Type::String(string)) if string.enumeration.iter().any(Option::is_some) => {
+                let values: Vec<&str> = string.enumeration.iter().flatten().map(String::as_str).collect();
+                self.string_enum(ident, data, &values)
+            }
+            SchemaKind::OneOf { one_of: variants } | SchemaKind::AnyOf { any_of: variants } => {
+                let variants = variants
+                    .iter()
+                    .enumerate()
+                    .map(|(index, variant)| match variant {
+                        ReferenceOr::Reference { reference } => Variant::reference(reference, index),
+                        ReferenceOr::Item(schema) => Variant {
+                            name: schema.schema_data.title.clone().unwrap_or_else(|| format!("variant {index}")),
+                            description: schema.schema_data.description.as_deref(),
+                            schema: VariantSchema::Item(schema),
+                        },
+                    })
+                    .collect();
+                self.variants_enum(ident, data, variants)
+            }
+            SchemaKind::AllOf { all_of } => self.all_of_struct(ident, data, all_of),
+            _ => {
+                let doc = doc(data);
+                let typ = self.field_type(schema, &ident.to_string());
+                self.items.push(quote!(#doc pub type #ident = #typ;));
+            }
+        }
+    }
+
+    fn object_struct(&mut self, ident: &Ident, data: &SchemaData, properties: &IndexMap<String, ReferenceOr<Box<Schema>>>, required: &[String], additional_properties: Option<&AdditionalProperties>) {
+        let mut names = HashSet::new();
+        let mut fields = Vec::new();
+        for (index, (name, property)) in properties.iter().enumerate() {
+            let mut field = field_ident(name);
+            if !names.insert(field.to_string()) {
+                field = format_ident!("{}_{}", field, index);
+            }
+            let rename = (field.to_string().trim_start_matches("r#") != name).then(|| quote!(#[serde(rename = #name)]));
+            let (mut typ, nullable) = match property {
+                ReferenceOr::Item(schema) => (self.field_type(schema, &format!("{ident} {name}")), schema.schema_data.nullable == Some(true)),
+                ReferenceOr::Reference { reference } => (self.reference_type(reference), false),
+            };
+            if *ident == typ.to_string() {
+                typ = quote!(Box<#typ>);
+            }
+            let doc = match property {
+                ReferenceOr::Item(schema) => doc(&schema.schema_data),
+                ReferenceOr::Reference { .. } => quote!(),
+            };
+            fields.push(if !required.contains(name) {
+                quote!(#doc #rename #[serde(default, skip_serializing_if = "Option::is_none")] pub #field: Option<#typ>,)
+            } else if nullable {
+                quote!(#doc #rename pub #field: Option<#typ>,)
+            } else {
+                quote!(#doc #rename pub #field: #typ,)
+            });
+        }
+        let mut attributes = quote!();
+        match additional_properties {
+            Some(AdditionalProperties::Any(false)) => attributes = quote!(#[serde(deny_unknown_fields)]),
+            Some(AdditionalProperties::Schema(schema)) => {
+                let typ = match schema.as_ref() {
+                    ReferenceOr::Item(schema) => self.field_type(schema, &format!("{ident} value")),
+                    ReferenceOr::Reference { reference } => self.reference_type(reference),
+                };
+                let mut field = format_ident!("additional_properties");
+                if !names.insert(field.to_string()) {
+                    field = format_ident!("{}_{}", field, properties.len());
+                }
+                fields.push(quote!(#[serde(flatten)] pub #field: std::collections::HashMap<String, #typ>,));
+            }
+            _ => {}
+        }
+        let doc = doc(data);
+        self.items.push(quote! {
+            #doc
+            #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+            #attributes
+            pub struct #ident {
+                #(#fields)*
+            }
+        });
+    }
+
+    /// An `allOf` is a struct flattening each of its schemas.
+    fn all_of_struct(&mut self, ident: &Ident, data: &SchemaData, all_of: &[ReferenceOr<Schema>]) {
+        let mut names = HashSet::new();
+        let mut fields = Vec::new();
+        for (index, part) in all_of.iter().enumerate() {
+            let (name, typ) = match part {
+                ReferenceOr::Reference { reference } => (reference.rsplit('/').next().unwrap_or_default().to_owned(), self.reference_type(reference)),
+                ReferenceOr::Item(schema) => {
+                    let name = schema.schema_data.title.clone().unwrap_or_else(|| format!("part {index}"));
+                    let typ = self.field_type(schema, &format!("{ident} {name}"));
+                    (name, typ)
+                }
+            };
+            let mut field = field_ident(&name);
+            if !names.insert(field.to_string()) {
+                field = format_ident!("{}_{}", field, index);
+            }
+            fields.push(quote!(#[serde(flatten)] pub #field: #typ,));
+        }
+        let doc = doc(data);
+        self.items.push(quote! {
+            #doc
+            #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+            pub struct #ident {
+                #(#fields)*
+            }
+        });
+    }
+
+    fn string_enum(&mut self, ident: &Ident, data: &SchemaData, values: &[&str]) {
+        let mut names = HashSet::new();
+        let variants = values.iter().map(|value| {
+            let mut variant = type_ident(value);
+            if !names.insert(variant.to_string()) {
+                variant = format_ident!("{}{}", variant, names.len());
+            }
+            quote!(#[serde(rename = #value)] #variant,)
+        });
+        let variants: Vec<_> = variants.collect();
+        let doc = doc(data);
+        self.items.push(quote! {
+            #doc
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+            pub enum #ident {
+                #(#variants)*
+            }
+        });
+    }
+
+    /// An enum of the variants of a `oneOf` or `anyOf`. With a
+    /// `discriminator`, the enum is internally tagged: the discriminator
+    /// property is removed from the inline variants, and the tag of a variant
+    /// is the value mapped to it, the single `enum` value of its
+    /// discriminator property, or its name.
+    fn variants_enum(&mut self, ident: &Ident, data: &SchemaData, variants: Vec<Variant>) {
+        let mapping = data.discriminator.as_deref();
+        let discriminator = mapping.map(|discriminator| discriminator.property_name.as_str());
+        let mut names = HashSet::new();
+        let mut tokens = Vec::new();
+        for variant in variants {
+            let mut name = type_ident(&variant.name);
+            if !names.insert(name.to_string()) {
+                name = format_ident!("{}{}", name, names.len());
+            }
+            let doc = variant.description.map(|description| quote!(#[doc = #description])).unwrap_or_default();
+            let (typ, tag) = match variant.schema {
+                VariantSchema::Reference(reference) => {
+                    let mapped = mapping.and_then(|mapping| mapping.mapping.keys().find(|value| mapping.mapped_reference(value).as_deref() == Some(reference)).cloned());
+                    let tag = mapped.or_else(|| discriminator.and_then(|discriminator| self.resolve(reference).and_then(|schema| tag_value(schema, discriminator))));
+                    (Some(self.reference_type(reference)), tag)
+                }
+                VariantSchema::Item(schema) => match discriminator {
+                    Some(discriminator) => {
+                        let tag = tag_value(schema, discriminator);
+                        let mut schema = schema.clone();
+                        let empty = remove_property(&mut schema, discriminator);
+                        let typ = (!empty).then(|| self.field_type(&schema, &format!("{ident} {}", variant.name)));
+                        (typ, tag)
+                    }
+                    None => (Some(self.field_type(schema, &format!("{ident} {}", variant.name))), None),
+                },
+            };
+            let rename = discriminator.map(|_| {
+                let tag = tag.unwrap_or(variant.name);
+                quote!(#[serde(rename = #tag)])
+            });
+            tokens.push(match typ {
+                Some(typ) => quote!(#doc #rename #name(#typ),),
+                None => quote!(#doc #rename #name,),
+            });
+        }
+        let attributes = match discriminator {
+            Some(discriminator) => quote!(#[serde(tag = #discriminator)]),
+            None => quote!(#[serde(untagged)]),
+        };
+        let doc = doc(data);
+        self.items.push(quote! {
+            #doc
+            #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+            #attributes
+            pub enum #ident {
+                #(#tokens)*
+            }
+        });
+    }
+
+    /// The type of a schema used by a field, an item or a variant. Schemas
+    /// needing their own item are generated with a name derived from
+    /// `suggestion`. Nullable schemas are not wrapped in an `Option`.
+    fn field_type(&mut self, schema: &Schema, suggestion: &str) -> TokenStream {
+        match &schema.schema_kind {
+            SchemaKind::Type(Type::String(string)) if string.enumeration.iter().all(Option::is_none) => match &string.format {
+                VariantOrUnknownOrEmpty::Item(format) => self.format_type(string_format_name(format)),
+                VariantOrUnknownOrEmpty::Unknown(format) => self.format_type(format),
+                VariantOrUnknownOrEmpty::Empty => quote!(String),
+            },
+            SchemaKind::Type(Type::Integer(integer)) => match integer.format {
+                VariantOrUnknownOrEmpty::Item(IntegerFormat::Int32) => quote!(i32),
+                VariantOrUnknownOrEmpty::Item(IntegerFormat::UInt32) => quote!(u32),
+                VariantOrUnknownOrEmpty::Item(IntegerFormat::UInt64) => quote!(u64),
+                _ => quote!(i64),
+            },
+            SchemaKind::Type(Type::Number(number)) => match number.format {
+                VariantOrUnknownOrEmpty::Item(NumberFormat::Float) => quote!(f32),
+                _ => quote!(f64),
+            },
+            SchemaKind::Type(Type::Boolean(_)) => quote!(bool),
+            SchemaKind::Type(Type::Array(array)) => self.array_type(array.items.as_ref(), suggestion),
+            SchemaKind::Type(Type::Object(object)) if object.properties.is_empty() => self.map_type(object.additional_properties.as_ref(), suggestion),
+            SchemaKind::Any(AnySchema {
+                properties,
+                items,
+                additional_properties,
+                ..
+            }) if properties.is_empty() => match (items, additional_properties) {
+                (Some(items), _) => self.array_type(Some(items), suggestion),
+                (None, Some(additional_properties)) => self.map_type(Some(additional_properties), suggestion),
+                (None, None) => quote!(serde_json::Value),
+            },
+            SchemaKind::Bool(_) => quote!(serde_json::Value),
+            _ => {
+                let ident = self.unique_ident(suggestion);
+                self.named_type(&ident, schema);
+                quote!(#ident)
+            }
+        }
+    }
+
+    /// Arrays with a tuple of `items` become Rust tuples.
+    fn array_type(&mut self, items: Option<&Items>, suggestion: &str) -> TokenStream {
+        match items {
+            Some(Items::Schema(schema)) => {
+                let item = self.item_type(schema, &format!("{suggestion} item"));
+                quote!(Vec<#item>)
+            }
+            Some(Items::Tuple(schemas)) => {
+                let items = schemas
+                    .iter()
+                    .enumerate()
+                    .map(|(index, schema)| self.item_type(schema, &format!("{suggestion} {index}")))
+                    .collect::<Vec<_>>();
+                quote!((#(#items,)*))
+            }
+            None => quote!(Vec<serde_json::Value>),
+        }
+    }
+
+    fn item_type(&mut self, item: &ReferenceOr<Box<Schema>>, suggestion: &str) -> TokenStream {
+        match item {
+            ReferenceOr::Item(schema) => {
+                let typ = self.field_type(schema, suggestion);
+                nullable(schema, typ)
+            }
+            ReferenceOr::Reference { reference } => self.reference_type(reference),
+        }
+    }
+
+    fn map_type(&mut self, additional_properties: Option<&AdditionalProperties>, suggestion: &str) -> TokenStream {
+        let value = match additional_properties {
+            Some(AdditionalProperties::Schema(schema)) => match schema.as_ref() {
+                ReferenceOr::Item(schema) => {
+                    let typ = self.field_type(schema, &format!("{suggestion} value"));
+                    nullable(schema, typ)
+                }
+                ReferenceOr::Reference { reference } => self.reference_type(reference),
+            },
+            _ => quote!(serde_json::Value),
+        };
+        quote!(std::collections::HashMap<String, #value>)
+    }
+
+    /// The newtype of the strings of a format, generated once per format.
+    fn format_type(&mut self, format: &str) -> TokenStream {
+        if let Some(ident) = self.format_types.get(format) {
+            return quote!(#ident);
+        }
+        let ident = self.unique_ident(format);
+        let doc = format!(" A string in the `{format}` format.");
+        self.items.push(quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+            #[serde(transparent)]
+            pub struct #ident(pub String);
+        });
+        self.format_types.insert(format.to_owned(), ident.clone());
+        quote!(#ident)
+    }
+
+    /// The type of a referenced schema. Only references to `components.schemas`
+    /// have a generated type, other schemas are JSON values.
+    fn reference_type(&mut self, reference: &str) -> TokenStream {
+        match parse_component_pointer(reference) {
+            Ok((kind, name)) if kind == "schemas" && self.schema_types.contains_key(&name) => {
+                let ident = &self.schema_types[&name];
+                quote!(#ident)
+            }
+            _ => quote!(serde_json::Value),
+        }
+    }
+
+    fn resolve(&self, reference: &str) -> Option<&'a Schema> {
+        let (kind, name) = parse_component_pointer(reference).ok()?;
+        match self.components?.schemas.get(&name)? {
+            ReferenceOr::Item(schema) if kind == "schemas" => Some(schema),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn unique_ident(&mut self, name: &str) -> Ident {
+        let base = type_ident(name);
+        let mut ident = base.clone();
+        let mut index = 1usize;
+        while !self.names.insert(ident.to_string()) {
+            index += 1;
+            ident = format_ident!("{}{}", base, index);
+        }
+        ident
+    }
+
+    /// A method name which is not used by another generated trait, as the
+    /// traits can be implemented by the same type.
+    pub(crate) fn unique_method(&mut self, base: Ident) -> Ident {
+        let mut ident = base.clone();
+        let mut index = 1usize;
+        while !self.methods.insert(ident.to_string()) {
+            index += 1;
+            ident = format_ident!("{}_{}", base, index);
+        }
+        ident
+    }
+}
+
+impl<'s> Variant<'s> {
+    fn reference(reference: &'s str, index: usize) -> Self {
+        Variant {
+            name: reference.rsplit('/').next().filter(|name| !name.is_empty()).map_or_else(|| format!("variant {index}"), str::to_owned),
+            description: None,
+            schema: VariantSchema::Reference(reference),
+        }
+    }
+}
+
+fn message_name(message: &Message) -> Option<String> {
+    message.message_id.clone().or_else(|| message.name.clone())
+}
+
+fn string_format_name(format: &StringFormat) -> &'static str {
+    match format {
+        StringFormat::Date => "date",
+        StringFormat::DateTime => "date-time",
+        StringFormat::Password => "password",
+        StringFormat::Byte => "byte",
+        StringFormat::Binary => "binary",
+    }
+}
+
+fn nullable(schema: &Schema, typ: TokenStream) -> TokenStream {
+    match schema.schema_data.nullable {
+        Some(true) => quote!(Option<#typ>),
+        _ => typ,
+    }
+}
+
+fn doc(data: &SchemaData) -> TokenStream {
+    match data.description.as_deref().or(data.title.as_deref()) {
+        Some(description) => {
+            let lines = description.trim().lines().map(|line| format!(" {line}"));
+            quote!(#(#[doc = #lines])*)
+        }
+        None => quote!(),
+    }
+}
+
+/// The single `enum` value of the discriminator property of a schema.
+fn tag_value(schema: &Schema, discriminator: &str) -> Option<String> {
+    let properties = match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => &object.properties,
+        SchemaKind::Any(any) => &any.properties,
+        _ => return None,
+    };
+    match properties.get(discriminator)? {
+        ReferenceOr::Item(property) => match &property.schema_kind {
+            SchemaKind::Type(Type::String(string)) if string.enumeration.len() == 1 => string.enumeration[0].clone(),
+            _ => None,
+        },
+        ReferenceOr::Reference { .. } => None,
+    }
+}
+
+/// Removes the discriminator property of an inline variant, which is read by
+/// serde as the tag. Returns whether the variant has no other property.
+fn remove_property(schema: &mut Schema, name: &str) -> bool {
+    let (properties, required) = match &mut schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => (&mut object.properties, &mut object.required),
+        SchemaKind::Any(any) => (&mut any.properties, &mut any.required),
+        _ => return false,
+    };
+    properties.shift_remove(name);
+    required.retain(|required| required != name);
+    properties.is_empty()
+}
+
+#[test]
+fn test_generate_types() {
+    let asyncapi: AsyncAPI = serde_yaml::from_str(
+        r##"
+        asyncapi: 2.6.0
+        info:
+          title: Game Server
+          version: 1.0.0
+        channels:
+          player/moved:
+            subscribe:
+              operationId: onPlayerMoved
+              message:
+                $ref: "#/components/messages/PlayerMoved"
+        components:
+          messages:
+            PlayerMoved:
+              payload:
+                type: object
+                required: [position]
+                properties:
+                  position:
+                    type: array
+                    items:
+                      type: number
+                      format: float
+            PlayerJoined:
+              payload:
+                $ref: "#/components/schemas/Player"
+          schemas:
+            Player:
+              type: object
+              description: A connected player.
+              required: [id, name, joinedAt]
+              properties:
+                id:
+                  type: integer
+                  format: uint32
+                name:
+                  type: string
+                joinedAt:
+                  type: string
+                  format: date-time
+                team:
+                  $ref: "#/components/schemas/Team"
+                type:
+                  type: string
+                  nullable: true
+            Team:
+              type: string
+              enum: [red, blue]
+            Event:
+              discriminator: kind
+              oneOf:
+                - $ref: "#/components/schemas/Joined"
+                - title: Left
+                  type: object
+                  required: [kind, reason]
+                  properties:
+                    kind:
+                      type: string
+                      enum: [left]
+                    reason:
+                      type: string
+            Joined:
+              type: object
+              properties:
+                kind:
+                  type: string
+                  enum: [joined]
+        "##,
+    )
+    .expect("Could not deserialize the document");
+    let source = super::format_source(super::generate_types(&asyncapi)).unwrap();
+    let expected = [
+        "/// A connected player.\n#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]\npub struct Player {",
+        "    pub id: u32,\n    pub name: String,\n    #[serde(rename = \"joinedAt\")]\n    pub joined_at: DateTime,",
+        "    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n    pub team: Option<Team>,",
+        "    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n    pub r#type: Option<String>,",
+        "pub struct DateTime(pub String);",
+        "pub enum Team {\n    #[serde(rename = \"red\")]\n    Red,",
+        "#[serde(tag = \"kind\")]\npub enum Event {\n    #[serde(rename = \"joined\")]\n    Joined(Joined),\n    #[serde(rename = \"left\")]\n    Left(EventLeft),\n}",
+        "pub struct EventLeft {\n    pub reason: String,\n}",
+        "pub struct PlayerMovedPayload {\n    pub position: Vec<f32>,\n}",
+    ];
+    for expected in expected {
+        assert!(source.contains(expected), "missing `{expected}` in:\n{source}");
+    }
+    assert!(!source.contains("PlayerJoinedPayload"));
+}
@@ -0,0 +1,246 @@
+//! Deserialization of the enums which used to be `#[serde(untagged)]`.
+//!
+//! Their `Deserialize` impls buffer the value, pick the variant from its
+//! marker keys (`$ref`, `type`, `oneOf`, ...) and deserialize only that
+//! variant, so that the error of the variant is reported instead of "data
+//! did not match any variant". The variant is deserialized with
+//! [`from_value`], which records the JSON Pointer of the node where the
+//! error happened: the deserializers of maps and sequences prefix the errors
+//! of their entries with their keys, and the error of a variant is handed
+//! to the deserializer of the enclosing value as is by [`nested`]. The
+//! [AsyncAPI][crate::AsyncAPI] document is buffered as well, so that its
+//! errors are reported with their pointer whatever the deserializer.
+//!
+//! Serde buffers the fields of structs with a flattened field and the
+//! variants of internally tagged enums without going through these
+//! deserializers, so the keys under such a buffer are not recorded. Schemas,
+//! which are nested the deepest, are therefore deserialized without them.
+
+use std::{cell::RefCell, fmt};
+
+use serde::{
+    de::{self, value::StrDeserializer, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor},
+    forward_to_deserialize_any, Deserialize, Deserializer,
+};
+use serde_json::Value;
+
+use crate::resolver::escape_pointer_token;
+
+/// A deserialization error and the JSON Pointer of the node where it
+/// happened, relative to the value passed to [`from_value`].
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PathError {
+    pub(crate) pointer: String,
+    pub(crate) message: String,
+}
+
+impl PathError {
+    pub(crate) fn prefixed(mut self, token: &str) -> Self {
+        self.pointer = format!("/{}{}", escape_pointer_token(token), self.pointer);
+        self
+    }
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.pointer.is_empty() {
+            true => f.write_str(&self.message),
+            false => write!(f, "at `{}`: {}", self.pointer, self.message),
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+thread_local! {
+    /// The error being handed by [`nested`] to the deserializer of the
+    /// enclosing value, whose error type can only be built from a message.
+    static NESTED: RefCell<Option<PathError>> = const { RefCell::new(None) };
+}
+
+impl de::Error for PathError {
+    /// Takes back the error handed by [`nested`] with this message, if any.
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        let message = message.to_string();
+        match NESTED.with(|nested| nested.borrow_mut().take()) {
+            Some(error) if error.to_string() == message => error,
+            _ => PathError { pointer: String::new(), message },
+        }
+    }
+}
+
+/// Converts an error of [`from_value`] into the error of the deserializer of
+/// the enclosing value. Within [`from_value`], the error keeps its pointer,
+/// to which the keys of the enclosing value are then prefixed. Other
+/// deserializers get it as a message, which starts with its pointer.
+pub(crate) fn nested<E: de::Error>(error: PathError) -> E {
+    let message = error.to_string();
+    NESTED.with(|nested| *nested.borrow_mut() = Some(error));
+    let error = E::custom(message);
+    NESTED.with(|nested| nested.borrow_mut().take());
+    error
+}
+
+/// Deserializes `T` from a buffered value.
+pub(crate) fn from_value<T: DeserializeOwned>(value: Value) -> Result<T, PathError> {
+    T::deserialize(ValueDeserializer(&value))
+}
+
+/// Buffers the value of a deserializer to pick the variant of an enum.
+pub(crate) fn buffer<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Value, D::Error> {
+    Value::deserialize(deserializer)
+}
+
+/// Deserializes the variant `T` of an enum from its buffered value.
+pub(crate) fn variant<T: DeserializeOwned, E: de::Error>(value: Value) -> Result<T, E> {
+    from_value(value).map_err(nested)
+}
+
+/// Removes the field `key` of a buffered object and deserializes it.
+pub(crate) fn field<T: DeserializeOwned, E: de::Error>(value: &mut Value, key: &str) -> Result<T, E> {
+    let field = value.as_object_mut().and_then(|object| object.shift_remove(key)).unwrap_or_default();
+    from_value(field).map_err(|error| nested(error.prefixed(key)))
+}
+
+/// Returns whether a buffered value is an object holding `key`.
+pub(crate) fn has_key(value: &Value, key: &str) -> bool {
+    value.as_object().is_some_and(|object| object.contains_key(key))
+}
+
+struct ValueDeserializer<'a>(&'a Value);
+
+impl<'de> Deserializer<'de> for ValueDeserializer<'_> {
+    type Error = PathError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PathError> {
+        match self.0 {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(value) => visitor.visit_bool(*value),
+            Value::Number(number) => match (number.as_u64(), number.as_i64(), number.as_f64()) {
+                (Some(value), _, _) => visitor.visit_u64(value),
+                (_, Some(value), _) => visitor.visit_i64(value),
+                (_, _, Some(value)) => visitor.visit_f64(value),
+                _ => Err(de::Error::custom(format!("unsupported number {number}"))),
+            },
+            Value::String(value) => visitor.visit_str(value),
+            Value::Array(values) => visitor.visit_seq(SeqDeserializer { values: values.iter().enumerate() }),
+            Value::Object(map) => visitor.visit_map(MapDeserializer { entries: map.iter(), value: None }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PathError> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            value => visitor.visit_some(ValueDeserializer(value)),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, PathError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, name: &'static str, variants: &'static [&'static str], visitor: V) -> Result<V::Value, PathError> {
+        match self.0 {
+            Value::String(variant) => visitor.visit_enum(IntoDeserializer::<PathError>::into_deserializer(variant.as_str())),
+            value => value.clone().deserialize_enum(name, variants, visitor).map_err(de::Error::custom),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct SeqDeserializer<'a> {
+    values: std::iter::Enumerate<std::slice::Iter<'a, Value>>,
+}
+
+impl<'de> SeqAccess<'de> for SeqDeserializer<'_> {
+    type Error = PathError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, PathError> {
+        match self.values.next() {
+            Some((index, value)) => seed.deserialize(ValueDeserializer(value)).map(Some).map_err(|error| error.prefixed(&index.to_string())),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+struct MapDeserializer<'a> {
+    entries: serde_json::map::Iter<'a>,
+    value: Option<(&'a String, &'a Value)>,
+}
+
+impl<'de> MapAccess<'de> for MapDeserializer<'_> {
+    type Error = PathError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, PathError> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                let deserializer: StrDeserializer<PathError> = key.as_str().into_deserializer();
+                self.value = Some((key, value));
+                seed.deserialize(deserializer).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, PathError> {
+        let (key, value) = self.value.take().ok_or_else(|| de::Error::custom("value is missing"))?;
+        seed.deserialize(ValueDeserializer(value)).map_err(|error| error.prefixed(key))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+#[test]
+fn test_from_value() {
+    use crate::{AsyncAPI, Schema};
+
+    let schema: Value = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "tags": { "type": "array", "items": { "type": "string", "minLength": "one" } }
+        }
+    });
+    let error = from_value::<Schema>(schema).unwrap_err();
+    assert_eq!(error.pointer, "/properties/tags/items/minLength");
+    assert!(error.message.starts_with("invalid type: string \"one\""), "{}", error.message);
+
+    let document = serde_json::json!({
+        "asyncapi": "2.6.0",
+        "info": { "title": "Errors", "version": "1.0.0" },
+        "channels": {
+            "user/signedup": {
+                "subscribe": { "message": { "payload": { "$ref": 42 } } }
+            }
+        }
+    });
+    let error = from_value::<AsyncAPI>(document).unwrap_err();
+    assert_eq!(error.pointer, "/channels/user~1signedup/subscribe/message/payload/$ref");
+    assert_eq!(error.message, "invalid type: integer `42`, expected a string");
+
+    // The same invalid node appears first under a key which is not a schema.
+    let schema: Value = serde_json::json!({
+        "examples": [{ "minLength": "one" }],
+        "not": { "type": "string", "minLength": "one" }
+    });
+    assert_eq!(from_value::<Schema>(schema).unwrap_err().pointer, "/not/minLength");
+}
+
+#[test]
+fn test_buffered_document() {
+    use crate::AsyncAPI;
+
+    // Other deserializers get the pointer in the message, from the root.
+    let yaml = "asyncapi: 2.6.0\ninfo: {title: Errors, version: 1.0.0}\nchannels:\n  prices:\n    subscribe:\n      message:\n        payload: {type: number, minimum: zero}\n";
+    let error = serde_yaml::from_str::<AsyncAPI>(yaml).unwrap_err().to_string();
+    assert!(error.starts_with("at `/channels/prices/subscribe/message/payload/minimum`: invalid type: string \"zero\""), "{error}");
+}
@@ -0,0 +1,519 @@
+use indexmap::IndexMap;
+
+use crate::{
+    resolver::{escape_pointer_token, Component},
+    schema::{AdditionalProperties, Dependency, Items, Type},
+    AsyncAPI, Channel, ChannelBinding, Components, Message, MessageBinding, MessageTrait, Operation, OperationBinding, OperationMessageType, OperationTrait, Parameter, Payload, ReferenceOr,
+    ResolveError, Schema, SchemaKind, Server,
+};
+
+/// A fully dereferenced copy of an [AsyncAPI Object][crate::AsyncAPI] as
+/// returned by [`AsyncAPI::dereference`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dereferenced {
+    /// The document with every reference replaced by the object it points to,
+    /// except for the references listed in `recursive_schemas`.
+    pub asyncapi: AsyncAPI,
+    /// The schema references which were kept because inlining them would
+    /// never terminate.
+    pub recursive_schemas: Vec<RecursiveSchema>,
+}
+
+/// A schema reference pointing to one of the schemas it is nested in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecursiveSchema {
+    /// JSON Pointer to the reference in the dereferenced document.
+    pub path: String,
+    /// The value of the `$ref` field which was kept.
+    pub reference: String,
+}
+
+impl AsyncAPI {
+    /// Creates a copy of this document in which every
+    /// [Reference Object][crate::ReferenceOr] found in servers, channels,
+    /// operations, messages, traits, parameters, schemas and bindings is
+    /// replaced by a copy of the object it points to.
+    ///
+    /// Recursive schemas can not be inlined. These references are kept as is,
+    /// pointing into the `components` of the returned document, and are
+    /// listed in [`Dereferenced::recursive_schemas`].
+    pub fn dereference(&self) -> Result<Dereferenced, ResolveError> {
+        let mut asyncapi = self.clone();
+        let mut dereferencer = Dereferencer {
+            document: self,
+            path: Vec::new(),
+            schema_stack: Vec::new(),
+            recursive_schemas: Vec::new(),
+        };
+        dereferencer.at("servers", |d| d.map(&mut asyncapi.servers, Dereferencer::server))?;
+        dereferencer.at("channels", |d| d.channels(&mut asyncapi.channels))?;
+        if let Some(components) = asyncapi.components.as_mut() {
+            dereferencer.at("components", |d| d.components(components))?;
+        }
+        Ok(Dereferenced {
+            asyncapi,
+            recursive_schemas: dereferencer.recursive_schemas,
+        })
+    }
+}
+
+struct Dereferencer<'a> {
+    document: &'a AsyncAPI,
+    path: Vec<String>,
+    schema_stack: Vec<String>,
+    recursive_schemas: Vec<RecursiveSchema>,
+}
+
+impl<'a> Dereferencer<'a> {
+    fn at<R>(&mut self, token: &str, f: impl FnOnce(&mut Self) -> R) -> R {
+        self.path.push(escape_pointer_token(token));
+        let result = f(self);
+        self.path.pop();
+        result
+    }
+
+    fn pointer(&self) -> String {
+        self.path.iter().map(|token| format!("/{token}")).collect()
+    }
+
+    /// Replaces a reference by a copy of the object it points to and returns
+    /// the now inline object.
+    fn inline<'b, T: Component + Clone>(&self, item: &'b mut ReferenceOr<T>) -> Result<&'b mut T, ResolveError> {
+        if let ReferenceOr::Reference { reference } = item {
+            let resolved = self.document.resolve_reference::<T>(reference)?.clone();
+            *item = ReferenceOr::Item(resolved);
+        }
+        match item {
+            ReferenceOr::Item(item) => Ok(item),
+            ReferenceOr::Reference { .. } => unreachable!("reference was inlined above"),
+        }
+    }
+
+    fn inline_option<T: Component + Clone>(&mut self, token: &str, item: &mut Option<ReferenceOr<T>>) -> Result<(), ResolveError> {
+        if let Some(item) = item {
+            self.at(token, |d| d.inline(item).map(|_| ()))?;
+        }
+        Ok(())
+    }
+
+    fn map<T>(&mut self, map: &mut IndexMap<String, T>, mut f: impl FnMut(&mut Self, &mut T) -> Result<(), ResolveError>) -> Result<(), ResolveError> {
+        for (name, item) in map.iter_mut() {
+            self.at(name, |d| f(d, item))?;
+        }
+        Ok(())
+    }
+
+    fn components(&mut self, components: &mut Components) -> Result<(), ResolveError> {
+        self.at("schemas", |d| {
+            d.map(&mut components.schemas, |d, schema| {
+                // The component's own name is on the stack so that schemas
+                // referencing themselves are detected as recursive.
+                let own_reference = format!("#/components/schemas/{}", d.path.last().map(String::as_str).unwrap_or_default());
+                d.schema_stack.push(own_reference);
+                let result = d.schema_ref(schema);
+                d.schema_stack.pop();
+                result
+            })
+        })?;
+        self.at("messages", |d| d.map(&mut components.messages, |d, message| d.message(d.inline(message)?)))?;
+        self.at("securitySchemes", |d| d.map(&mut components.security_schemes, |d, scheme| d.inline(scheme).map(|_| ())))?;
+        self.at("parameters", |d| d.map(&mut components.parameters, Dereferencer::parameter))?;
+        self.at("correlationIds", |d| d.map(&mut components.correlation_ids, |d, id| d.inline(id).map(|_| ())))?;
+        self.at("operationTraits", |d| d.map(&mut components.operation_traits, Dereferencer::operation_trait))?;
+        self.at("messageTraits", |d| d.map(&mut components.message_traits, Dereferencer::message_trait))?;
+        self.at("servers", |d| d.map(&mut components.servers, Dereferencer::server))?;
+        self.at("serverVariables", |d| d.map(&mut components.server_variables, |d, variable| d.inline(variable).map(|_| ())))?;
+        self.at("serverBindings", |d| d.map(&mut components.server_bindings, |d, binding| d.inline(binding).map(|_| ())))?;
+        self.at("channels", |d| d.channels(&mut components.channels))?;
+        self.at("channelBindings", |d| d.map(&mut components.channel_bindings, Dereferencer::channel_binding))?;
+        self.at("operationBindings", |d| d.map(&mut components.operation_bindings, Dereferencer::operation_binding))?;
+        self.at("messageBindings", |d| d.map(&mut components.message_bindings, Dereferencer::message_binding))
+    }
+
+    fn server(&mut self, server: &mut ReferenceOr<Server>) -> Result<(), ResolveError> {
+        let server = self.inline(server)?;
+        self.inline_option("bindings", &mut server.bindings)
+    }
+
+    fn channels(&mut self, channels: &mut IndexMap<String, Channel>) -> Result<(), ResolveError> {
+        self.map(channels, |d, channel| {
+            d.at("parameters", |d| d.map(&mut channel.parameters, Dereferencer::parameter))?;
+            if let Some(bindings) = channel.bindings.as_mut() {
+                d.at("bindings", |d| d.channel_binding(bindings))?;
+            }
+            if let Some(subscribe) = channel.subscribe.as_mut() {
+                d.at("subscribe", |d| d.operation(subscribe))?;
+            }
+            if let Some(publish) = channel.publish.as_mut() {
+                d.at("publish", |d| d.operation(publish))?;
+            }
+            Ok(())
+        })
+    }
+
+    fn parameter(&mut self, parameter: &mut ReferenceOr<Parameter>) -> Result<(), ResolveError> {
+        let parameter = self.inline(parameter)?;
+        if let Some(schema) = parameter.schema.as_mut() {
+            self.at("schema", |d| d.schema_ref(schema))?;
+        }
+        Ok(())
+    }
+
+    fn operation(&mut self, operation: &mut Operation) -> Result<(), ResolveError> {
+        if let Some(bindings) = operation.bindings.as_mut() {
+            self.at("bindings", |d| d.operation_binding(bindings))?;
+        }
+        self.at("traits", |d| {
+            for (index, operation_trait) in operation.traits.iter_mut().enumerate() {
+                d.at(&index.to_string(), |d| d.operation_trait(operation_trait))?;
+            }
+            Ok(())
+        })?;
+        match operation.message.as_mut() {
+            Some(OperationMessageType::Single(message)) => self.at("message", |d| d.message(d.inline(message)?)),
+            Some(OperationMessageType::Map(messages)) => self.at("message", |d| d.map(messages, |d, message| d.message(d.inline(message)?))),
+            Some(OperationMessageType::OneOf(messages)) => self.at("message", |d| {
+                d.at("oneOf", |d| {
+                    for (index, message) in messages.iter_mut().enumerate() {
+                        d.at(&index.to_string(), |d| d.message(d.inline(message)?))?;
+                    }
+                    Ok(())
+                })
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn operation_trait(&mut self, operation_trait: &mut ReferenceOr<OperationTrait>) -> Result<(), ResolveError> {
+        let operation_trait = self.inline(operation_trait)?;
+        match operation_trait.bindings.as_mut() {
+            Some(bindings) => self.at("bindings", |d| d.operation_binding(bindings)),
+            None => Ok(()),
+        }
+    }
+
+    fn message(&mut self, message: &mut Message) -> Result<(), ResolveError> {
+        if let Some(headers) = message.headers.as_mut() {
+            self.at("headers", |d| d.schema_ref(headers))?;
+        }
+        match message.payload.as_mut() {
+            Some(Payload::Schema(payload)) => self.at("payload", |d| d.schema_ref(payload))?,
+            Some(Payload::OneOf { one_of, .. }) => self.at("payload", |d| {
+                d.at("oneOf", |d| {
+                    for (index, kind) in one_of.iter_mut().enumerate() {
+                        d.at(&index.to_string(), |d| d.at("payload", |d| d.schema(&mut kind.payload)))?;
+                    }
+                    Ok(())
+                })
+            })?,
+            _ => {}
+        }
+        self.inline_option("correlationId", &mut message.correlation_id)?;
+        if let Some(bindings) = message.bindings.as_mut() {
+            self.at("bindings", |d| d.message_binding(bindings))?;
+        }
+        self.at("traits", |d| {
+            for (index, message_trait) in message.traits.iter_mut().enumerate() {
+                d.at(&index.to_string(), |d| d.message_trait(message_trait))?;
+            }
+            Ok(())
+        })
+    }
+
+    fn message_trait(&mut self, message_trait: &mut ReferenceOr<MessageTrait>) -> Result<(), ResolveError> {
+        let message_trait = self.inline(message_trait)?;
+        if let Some(headers) = message_trait.headers.as_mut() {
+            self.at("headers", |d| d.schema_ref(headers))?;
+        }
+        self.inline_option("correlationId", &mut message_trait.correlation_id)?;
+        match message_trait.bindings.as_mut() {
+            Some(bindings) => self.at("bindings", |d| d.message_binding(bindings)),
+            None => Ok(()),
+        }
+    }
+
+    fn channel_binding(&mut self, binding: &mut ReferenceOr<ChannelBinding>) -> Result<(), ResolveError> {
+        let binding = self.inline(binding)?;
+        if let Some(ws) = binding.ws.as_mut() {
+            self.at("ws", |d| d.binding_schemas(&mut [("query", &mut ws.query), ("headers", &mut ws.headers)]))?;
+        }
+        Ok(())
+    }
+
+    fn operation_binding(&mut self, binding: &mut ReferenceOr<OperationBinding>) -> Result<(), ResolveError> {
+        let binding = self.inline(binding)?;
+        if let Some(http) = binding.http.as_mut() {
+            self.at("http", |d| d.binding_schemas(&mut [("query", &mut http.query)]))?;
+        }
+        if let Some(kafka) = binding.kafka.as_mut() {
+            self.at("kafka", |d| d.binding_schemas(&mut [("groupId", &mut kafka.group_id), ("clientId", &mut kafka.client_id)]))?;
+        }
+        Ok(())
+    }
+
+    fn message_binding(&mut self, binding: &mut ReferenceOr<MessageBinding>) -> Result<(), ResolveError> {
+        let binding = self.inline(binding)?;
+        if let Some(http) = binding.http.as_mut() {
+            self.at("http", |d| d.binding_schemas(&mut [("headers", &mut http.headers)]))?;
+        }
+        if let Some(ws) = binding.ws.as_mut() {
+            self.at("ws", |d| d.binding_schemas(&mut [("query", &mut ws.query), ("headers", &mut ws.headers)]))?;
+        }
+        if let Some(kafka) = binding.kafka.as_mut() {
+            self.at("kafka", |d| d.binding_schemas(&mut [("key", &mut kafka.key)]))?;
+        }
+        if let Some(anypointmq) = binding.anypointmq.as_mut() {
+            self.at("anypointmq", |d| d.binding_schemas(&mut [("headers", &mut anypointmq.headers)]))?;
+        }
+        Ok(())
+    }
+
+    /// Dereferences the schemas of the fields of a binding.
+    fn binding_schemas(&mut self, schemas: &mut [(&str, &mut Option<Schema>)]) -> Result<(), ResolveError> {
+        for (token, schema) in schemas {
+            if let Some(schema) = schema {
+                self.at(token, |d| d.schema(schema))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves a schema reference and dereferences the resolved schema.
+    /// Returns `None` if the reference is recursive and must be kept.
+    fn expand_schema_reference(&mut self, reference: &str) -> Result<Option<Schema>, ResolveError> {
+        if self.schema_stack.iter().any(|visited| visited == reference) {
+            self.recursive_schemas.push(RecursiveSchema {
+                path: self.pointer(),
+                reference: reference.to_owned(),
+            });
+            return Ok(None);
+        }
+        let mut schema = self.document.resolve_reference::<Schema>(reference)?.clone();
+        self.schema_stack.push(reference.to_owned());
+        let result = self.schema(&mut schema);
+        self.schema_stack.pop();
+        result.map(|_| Some(schema))
+    }
+
+    fn schema_ref(&mut self, schema: &mut ReferenceOr<Schema>) -> Result<(), ResolveError> {
+        match schema {
+            ReferenceOr::Reference { reference } => {
+                if let Some(expanded) = self.expand_schema_reference(&reference.clone())? {
+                    *schema = ReferenceOr::Item(expanded);
+                }
+                Ok(())
+            }
+            ReferenceOr::Item(schema) => self.schema(schema),
+        }
+    }
+
+    fn boxed_schema_ref(&mut self, schema: &mut ReferenceOr<Box<Schema>>) -> Result<(), ResolveError> {
+        match schema {
+            ReferenceOr::Reference { reference } => {
+                if let Some(expanded) = self.expand_schema_reference(&reference.clone())? {
+                    *schema = ReferenceOr::boxed_item(expanded);
+                }
+                Ok(())
+            }
+            ReferenceOr::Item(schema) => self.schema(schema),
+        }
+    }
+
+    fn schema_list(&mut self, token: &str, schemas: &mut [ReferenceOr<Schema>]) -> Result<(), ResolveError> {
+        self.at(token, |d| {
+            for (index, schema) in schemas.iter_mut().enumerate() {
+                d.at(&index.to_string(), |d| d.schema_ref(schema))?;
+            }
+            Ok(())
+        })
+    }
+
+    fn boxed_option(&mut self, token: &str, schema: &mut Option<ReferenceOr<Box<Schema>>>) -> Result<(), ResolveError> {
+        match schema {
+            Some(schema) => self.at(token, |d| d.boxed_schema_ref(schema)),
+            None => Ok(()),
+        }
+    }
+
+    fn additional(&mut self, token: &str, additional: &mut Option<AdditionalProperties>) -> Result<(), ResolveError> {
+        match additional {
+            Some(AdditionalProperties::Schema(schema)) => self.at(token, |d| d.schema_ref(schema)),
+            _ => Ok(()),
+        }
+    }
+
+    fn object_like(
+        &mut self,
+        properties: &mut IndexMap<String, ReferenceOr<Box<Schema>>>,
+        pattern_properties: &mut IndexMap<String, ReferenceOr<Box<Schema>>>,
+        additional_properties: &mut Option<AdditionalProperties>,
+        property_names: &mut Option<ReferenceOr<Box<Schema>>>,
+        dependencies: &mut IndexMap<String, Dependency>,
+    ) -> Result<(), ResolveError> {
+        self.at("properties", |d| d.map(properties, Dereferencer::boxed_schema_ref))?;
+        self.at("patternProperties", |d| d.map(pattern_properties, Dereferencer::boxed_schema_ref))?;
+        self.additional("additionalProperties", additional_properties)?;
+        self.boxed_option("propertyNames", property_names)?;
+        self.at("dependencies", |d| {
+            d.map(dependencies, |d, dependency| match dependency {
+                Dependency::Schema(schema) => d.boxed_schema_ref(schema),
+                Dependency::Properties(_) => Ok(()),
+            })
+        })
+    }
+
+    fn array_like(&mut self, items: &mut Option<Items>, additional_items: &mut Option<AdditionalProperties>, contains: &mut Option<ReferenceOr<Box<Schema>>>) -> Result<(), ResolveError> {
+        match items {
+            Some(Items::Schema(schema)) => self.at("items", |d| d.boxed_schema_ref(schema))?,
+            Some(Items::Tuple(schemas)) => self.at("items", |d| {
+                for (index, schema) in schemas.iter_mut().enumerate() {
+                    d.at(&index.to_string(), |d| d.boxed_schema_ref(schema))?;
+                }
+                Ok(())
+            })?,
+            None => {}
+        }
+        self.additional("additionalItems", additional_items)?;
+        self.boxed_option("contains", contains)
+    }
+
+    fn schema(&mut self, schema: &mut Schema) -> Result<(), ResolveError> {
+        // A schema in place of which a Reference Object is not allowed, such
+        // as the schemas of bindings, can still be a `$ref`.
+        if let Some(reference) = schema.schema_data.reference.clone() {
+            if let Some(expanded) = self.expand_schema_reference(&reference)? {
+                *schema = expanded;
+            }
+            return Ok(());
+        }
+        let data = &mut schema.schema_data;
+        self.at("definitions", |d| d.map(&mut data.definitions, Dereferencer::boxed_schema_ref))?;
+        self.boxed_option("not", &mut data.not)?;
+        self.boxed_option("if", &mut data.if_schema)?;
+        self.boxed_option("then", &mut data.then_schema)?;
+        self.boxed_option("else", &mut data.else_schema)?;
+        self.schema_list("allOf", &mut data.all_of)?;
+        self.schema_list("anyOf", &mut data.any_of)?;
+        self.schema_list("oneOf", &mut data.one_of)?;
+        match &mut schema.schema_kind {
+            SchemaKind::Type(Type::Object(object)) => self.object_like(
+                &mut object.properties,
+                &mut object.pattern_properties,
+                &mut object.additional_properties,
+                &mut object.property_names,
+                &mut object.dependencies,
+            ),
+            SchemaKind::Type(Type::Array(array)) => self.array_like(&mut array.items, &mut array.additional_items, &mut array.contains),
+            SchemaKind::Type(_) | SchemaKind::Bool(_) => Ok(()),
+            SchemaKind::OneOf { one_of } => self.schema_list("oneOf", one_of),
+            SchemaKind::AllOf { all_of } => self.schema_list("allOf", all_of),
+            SchemaKind::AnyOf { any_of } => self.schema_list("anyOf", any_of),
+            SchemaKind::Any(any) => {
+                self.object_like(
+                    &mut any.properties,
+                    &mut any.pattern_properties,
+                    &mut any.additional_properties,
+                    &mut any.property_names,
+                    &mut any.dependencies,
+                )?;
+                self.array_like(&mut any.items, &mut any.additional_items, &mut any.contains)
+            }
+        }
+    }
+}
+
+#[test]
+fn test_dereference() {
+    let example = r##"
+    asyncapi: 2.6.0
+    info:
+      title: Dereference
+      version: 1.0.0
+    channels:
+      user/signedup:
+        bindings:
+          ws:
+            headers:
+              type: object
+              properties:
+                token:
+                  $ref: "#/components/schemas/token"
+        subscribe:
+          message:
+            $ref: "#/components/messages/userSignedUp"
+        publish:
+          bindings:
+            http:
+              type: request
+              query:
+                $ref: "#/components/schemas/query"
+          message:
+            oneOf:
+              - $ref: "#/components/messages/userSignedUp"
+              - payload:
+                  $ref: "#/components/schemas/user"
+    components:
+      messages:
+        userSignedUp:
+          payload:
+            $ref: "#/components/schemas/user"
+          bindings:
+            $ref: "#/components/messageBindings/keyed"
+      messageBindings:
+        keyed:
+          kafka:
+            key:
+              type: object
+              properties:
+                id:
+                  $ref: "#/components/schemas/token"
+      schemas:
+        token:
+          type: string
+        query:
+          type: object
+          properties:
+            token:
+              $ref: "#/components/schemas/token"
+        user:
+          type: object
+          properties:
+            name:
+              type: string
+            friends:
+              type: array
+              items:
+                $ref: "#/components/schemas/user"
+    "##;
+    let asyncapi: AsyncAPI = serde_yaml::from_str(example).expect("Could not deserialize dereference example");
+    let dereferenced = asyncapi.dereference().unwrap();
+
+    let message = match &dereferenced.asyncapi.channels["user/signedup"].subscribe.as_ref().unwrap().message {
+        Some(OperationMessageType::Single(ReferenceOr::Item(message))) => message,
+        other => panic!("message was not inlined: {other:?}"),
+    };
+    assert!(matches!(message.payload, Some(Payload::Schema(ReferenceOr::Item(_)))));
+    let one_of = match &dereferenced.asyncapi.channels["user/signedup"].publish.as_ref().unwrap().message {
+        Some(OperationMessageType::OneOf(messages)) => messages,
+        other => panic!("unexpected message: {other:?}"),
+    };
+    for message in one_of {
+        assert!(matches!(
+            message,
+            ReferenceOr::Item(Message {
+                payload: Some(Payload::Schema(ReferenceOr::Item(_))),
+                ..
+            })
+        ));
+    }
+    let document = serde_json::to_string(&dereferenced.asyncapi).unwrap();
+    assert!(!document.contains("#/components/schemas/token"), "binding schemas were not dereferenced: {document}");
+    assert!(!document.contains("#/components/schemas/query"), "binding schema references were not expanded: {document}");
+    assert_eq!(
+        dereferenced.recursive_schemas[0],
+        RecursiveSchema {
+            path: "/channels/user~1signedup/subscribe/message/payload/properties/friends/items".to_string(),
+            reference: "#/components/schemas/user".to_string(),
+        }
+    );
+}
@@ -0,0 +1,174 @@
+//! Functions called by the code generated by `#[derive(AsyncApiSchema)]`.
+//! They are not part of the public API.
+
+use indexmap::IndexMap;
+
+use crate::schema::{AdditionalProperties, AnySchema, ObjectType, StringType, Type};
+use crate::{Discriminator, ReferenceOr, Schema, SchemaData, SchemaKind, ToSchema};
+
+/// The properties of an object schema, in declaration order.
+pub struct Object {
+    description: Option<String>,
+    properties: IndexMap<String, ReferenceOr<Box<Schema>>>,
+    required: Vec<String>,
+    deny_unknown_fields: bool,
+}
+
+impl Object {
+    pub fn new(description: &str, deny_unknown_fields: bool) -> Self {
+        Object {
+            description: non_empty(description),
+            properties: IndexMap::new(),
+            required: Vec::new(),
+            deny_unknown_fields,
+        }
+    }
+
+    pub fn property<T: ToSchema + ?Sized>(&mut self, name: &str, description: &str, required: bool) {
+        self.insert(name, property::<T>(description), required);
+    }
+
+    pub fn insert(&mut self, name: &str, schema: ReferenceOr<Box<Schema>>, required: bool) {
+        if required {
+            self.required.push(name.to_owned());
+        }
+        self.properties.insert(name.to_owned(), schema);
+    }
+
+    /// Merges the properties of a `#[serde(flatten)]` field. Flattened
+    /// schemas which are not objects allow any extra property.
+    pub fn flatten(&mut self, schema: Schema) {
+        match schema.schema_kind {
+            SchemaKind::Type(Type::Object(object)) => {
+                self.properties.extend(object.properties);
+                self.required.extend(object.required);
+            }
+            _ => self.deny_unknown_fields = false,
+        }
+    }
+
+    pub fn into_schema(self) -> Schema {
+        Schema {
+            schema_data: SchemaData {
+                description: self.description,
+                ..Default::default()
+            },
+            schema_kind: SchemaKind::Type(Type::Object(ObjectType {
+                properties: self.properties,
+                required: self.required,
+                additional_properties: self.deny_unknown_fields.then_some(AdditionalProperties::Any(false)),
+                ..Default::default()
+            })),
+        }
+    }
+}
+
+pub fn property<T: ToSchema + ?Sized>(description: &str) -> ReferenceOr<Box<Schema>> {
+    let mut schema = T::schema();
+    if !description.is_empty() {
+        schema.schema_data.description = Some(description.to_owned());
+    }
+    ReferenceOr::Item(Box::new(schema))
+}
+
+pub fn titled(mut schema: Schema, title: &str) -> Schema {
+    schema.schema_data.title = Some(title.to_owned());
+    schema
+}
+
+pub fn inline(schema: ReferenceOr<Box<Schema>>) -> Schema {
+    match schema {
+        ReferenceOr::Item(schema) => *schema,
+        ReferenceOr::Reference { reference } => Schema {
+            schema_data: Default::default(),
+            schema_kind: SchemaKind::AllOf {
+                all_of: vec![ReferenceOr::Reference { reference }],
+            },
+        },
+    }
+}
+
+pub fn string_enum(description: &str, values: &[&str]) -> Schema {
+    Schema {
+        schema_data: SchemaData {
+            description: non_empty(description),
+            ..Default::default()
+        },
+        schema_kind: SchemaKind::Type(Type::String(StringType {
+            enumeration: values.iter().map(|value| Some((*value).to_owned())).collect(),
+            ..Default::default()
+        })),
+    }
+}
+
+/// The `null` serialized by unit structs and untagged unit variants.
+pub fn null(description: &str) -> Schema {
+    Schema {
+        schema_data: SchemaData {
+            nullable: Some(true),
+            description: non_empty(description),
+            ..Default::default()
+        },
+        schema_kind: SchemaKind::Any(AnySchema::default()),
+    }
+}
+
+pub fn tuple(description: &str, items: Vec<ReferenceOr<Box<Schema>>>) -> Schema {
+    let mut schema = crate::to_schema::tuple(items);
+    schema.schema_data.description = non_empty(description);
+    schema
+}
+
+/// An externally tagged variant: `{ "<name>": <content> }`.
+pub fn externally_tagged(name: &str, content: Schema) -> Schema {
+    let mut object = Object::new("", false);
+    object.insert(name, ReferenceOr::Item(Box::new(content)), true);
+    object.into_schema()
+}
+
+/// An internally tagged variant: the fields of `content` along with the tag.
+pub fn internally_tagged(tag: &str, name: &str, content: Option<Schema>) -> Schema {
+    let mut object = Object::new("", false);
+    object.insert(tag, ReferenceOr::Item(Box::new(string_enum("", &[name]))), true);
+    if let Some(content) = content {
+        object.flatten(content);
+    }
+    object.into_schema()
+}
+
+/// An adjacently tagged variant: `{ "<tag>": "<name>", "<content>": <content> }`.
+pub fn adjacently_tagged(tag: &str, name: &str, content_name: &str, content: Option<Schema>) -> Schema {
+    let mut object = Object::new("", false);
+    object.insert(tag, ReferenceOr::Item(Box::new(string_enum("", &[name]))), true);
+    if let Some(content) = content {
+        object.insert(content_name, ReferenceOr::Item(Box::new(content)), true);
+    }
+    object.into_schema()
+}
+
+/// The `oneOf` of the variants of an enum, as `(name, description, schema)`.
+pub fn one_of(description: &str, variants: Vec<(&str, &str, Schema)>, discriminator: Option<&str>) -> Schema {
+    Schema {
+        schema_data: SchemaData {
+            description: non_empty(description),
+            discriminator: discriminator.map(|discriminator| Box::new(Discriminator::new(discriminator))),
+            ..Default::default()
+        },
+        schema_kind: SchemaKind::OneOf {
+            one_of: variants
+                .into_iter()
+                .map(|(name, description, payload)| {
+                    let mut schema = titled(payload, name);
+                    if let Some(description) = non_empty(description) {
+                        schema.schema_data.description = Some(description);
+                    }
+                    ReferenceOr::Item(schema)
+                })
+                .collect(),
+        },
+    }
+}
+
+fn non_empty(text: &str) -> Option<String> {
+    (!text.is_empty()).then(|| text.to_owned())
+}
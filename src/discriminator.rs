@@ -1,5 +1,8 @@
 use indexmap::IndexMap;
-use serde::{Deserialize, Serialize};
+use serde::{ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::{de, Components, ReferenceOr, Schema, SchemaKind};
 
 /// When request bodies or response payloads may be one of a number of different schemas,
 /// a discriminator object MAY be used to aid in serialization, deserialization,
@@ -7,17 +10,215 @@ use serde::{Deserialize, Serialize};
 /// used to inform the consumer of the specification of an alternative schema based
 /// on the value associated with it.
 ///
-/// When using the discriminator, inline schemas will not be considered.
-#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
-#[serde(rename_all = "camelCase")]
+/// Deserialized from the AsyncAPI form, the name of the property, or from
+/// the OpenAPI form, an object. It is serialized in the AsyncAPI form when it
+/// has neither a mapping nor extensions.
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct Discriminator {
     /// REQUIRED. The name of the property in the payload that
     /// will hold the discriminator value.
     pub property_name: String,
     /// An object to hold mappings between payload values and schema names or references.
-    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub mapping: IndexMap<String, String>,
     /// Inline extensions to this object.
-    #[serde(flatten)]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
+
+impl Discriminator {
+    pub fn new(property_name: impl Into<String>) -> Discriminator {
+        Discriminator {
+            property_name: property_name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the value of the discriminator property of an instance.
+    pub fn value<'v>(&self, instance: &'v Value) -> Option<&'v str> {
+        instance.get(&self.property_name)?.as_str()
+    }
+
+    /// Returns the reference to the schema a discriminator value is mapped
+    /// to. Mappings to a schema name refer to `#/components/schemas`.
+    pub fn mapped_reference(&self, value: &str) -> Option<String> {
+        let target = self.mapping.get(value)?;
+        Some(match target.contains('/') || target.contains('#') {
+            true => target.clone(),
+            false => format!("#/components/schemas/{target}"),
+        })
+    }
+}
+
+/// The OpenAPI form of a [Discriminator].
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiscriminatorObject {
+    property_name: String,
+    #[serde(default)]
+    mapping: IndexMap<String, String>,
+    #[serde(flatten)]
+    extensions: IndexMap<String, serde_json::Value>,
+}
+
+impl Serialize for Discriminator {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.mapping.is_empty() && self.extensions.is_empty() {
+            return serializer.serialize_str(&self.property_name);
+        }
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("propertyName", &self.property_name)?;
+        if !self.mapping.is_empty() {
+            map.serialize_entry("mapping", &self.mapping)?;
+        }
+        for (key, value) in &self.extensions {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Discriminator {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match de::buffer(deserializer)? {
+            Value::String(property_name) => Ok(Discriminator::new(property_name)),
+            value => de::variant(value).map(|object: DiscriminatorObject| Discriminator {
+                property_name: object.property_name,
+                mapping: object.mapping,
+                extensions: object.extensions,
+            }),
+        }
+    }
+}
+
+impl Schema {
+    /// Picks the variant of a `oneOf` or `anyOf` schema an instance is of,
+    /// and returns its index and its schema, resolving references against
+    /// `components`.
+    ///
+    /// With a [Discriminator], the value of the discriminator property
+    /// selects the variant: through the mapping, then by the name of the
+    /// referenced schema, then by the single `enum` or `const` value of the
+    /// discriminator property of the variant. Without one, the variant is
+    /// the only one the instance is valid against.
+    ///
+    /// ```
+    /// # use asyncapi::Schema;
+    /// let schema: Schema = serde_yaml::from_str(r#"
+    /// discriminator: kind
+    /// oneOf:
+    ///   - type: object
+    ///     properties:
+    ///       kind: { type: string, enum: [created] }
+    ///       id: { type: string }
+    ///   - type: object
+    ///     properties:
+    ///       kind: { type: string, enum: [deleted] }
+    /// "#).unwrap();
+    /// let event = serde_json::json!({ "kind": "deleted" });
+    /// let (index, _) = schema.select_variant(&event, None).unwrap();
+    /// assert_eq!(index, 1);
+    /// ```
+    pub fn select_variant<'a>(&'a self, instance: &Value, components: Option<&'a Components>) -> Option<(usize, &'a Schema)> {
+        let variants: Vec<(Option<&str>, Option<&Schema>)> = match &self.schema_kind {
+            SchemaKind::OneOf { one_of: variants } | SchemaKind::AnyOf { any_of: variants } => variants
+                .iter()
+                .map(|variant| match variant {
+                    ReferenceOr::Reference { reference } => (Some(reference.as_str()), None),
+                    ReferenceOr::Item(variant) => (None, Some(variant)),
+                })
+                .collect(),
+            _ => return None,
+        };
+        let resolve = |reference: &str| components?.resolve_reference::<Schema>(reference).ok();
+        let variants: Vec<(Option<&str>, Option<&Schema>)> = variants.into_iter().map(|(reference, schema)| (reference, schema.or_else(|| resolve(reference?)))).collect();
+
+        if let Some(discriminator) = &self.schema_data.discriminator {
+            let value = discriminator.value(instance)?;
+            let mapped = discriminator.mapped_reference(value);
+            let index = variants
+                .iter()
+                .position(|(reference, _)| reference.is_some() && *reference == mapped.as_deref())
+                .or_else(|| {
+                    variants
+                        .iter()
+                        .position(|(reference, _)| reference.is_some_and(|reference| reference.rsplit('/').next() == Some(value)))
+                })
+                .or_else(|| {
+                    variants
+                        .iter()
+                        .position(|(_, schema)| schema.is_some_and(|schema| tag_value(schema, &discriminator.property_name) == Some(value)))
+                })?;
+            return Some((index, variants[index].1?));
+        }
+        let mut matching = variants
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (_, schema))| Some((index, (*schema)?)))
+            .filter(|(_, schema)| schema.validate_in(components, instance).is_ok());
+        match (matching.next(), matching.next()) {
+            (Some(variant), None) => Some(variant),
+            _ => None,
+        }
+    }
+}
+
+/// The single `enum` or `const` value of the discriminator property of a
+/// variant.
+fn tag_value<'s>(schema: &'s Schema, property_name: &str) -> Option<&'s str> {
+    let properties = match &schema.schema_kind {
+        SchemaKind::Type(crate::schema::Type::Object(object)) => &object.properties,
+        SchemaKind::Any(any) => &any.properties,
+        _ => return None,
+    };
+    let ReferenceOr::Item(property) = properties.get(property_name)? else {
+        return None;
+    };
+    if let Some(constant) = &property.schema_data.constant {
+        return constant.as_str();
+    }
+    match &property.schema_kind {
+        SchemaKind::Type(crate::schema::Type::String(string)) if string.enumeration.len() == 1 => string.enumeration[0].as_deref(),
+        SchemaKind::Any(any) if any.enumeration.len() == 1 => any.enumeration[0].as_str(),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_discriminator() {
+    let schema: Schema = serde_yaml::from_str(
+        r##"
+        discriminator:
+          propertyName: type
+          mapping:
+            dog: Hound
+        anyOf:
+          - $ref: "#/components/schemas/Hound"
+          - $ref: "#/components/schemas/Cat"
+        "##,
+    )
+    .unwrap();
+    let discriminator = schema.schema_data.discriminator.as_ref().unwrap();
+    assert_eq!(discriminator.mapped_reference("dog").as_deref(), Some("#/components/schemas/Hound"));
+    assert_eq!(
+        serde_json::to_value(discriminator).unwrap(),
+        serde_json::json!({ "propertyName": "type", "mapping": { "dog": "Hound" } })
+    );
+    assert_eq!(serde_json::to_value(Discriminator::new("type")).unwrap(), "type");
+
+    let components: Components = serde_yaml::from_str(
+        r#"
+        schemas:
+          Hound: { type: object, properties: { type: { type: string } } }
+          Cat: { type: object, properties: { type: { type: string } } }
+        "#,
+    )
+    .unwrap();
+    let select = |instance: Value| schema.select_variant(&instance, Some(&components)).map(|(index, _)| index);
+    assert_eq!(select(serde_json::json!({ "type": "dog" })), Some(0));
+    assert_eq!(select(serde_json::json!({ "type": "Cat" })), Some(1));
+    assert_eq!(select(serde_json::json!({ "type": "bird" })), None);
+    assert_eq!(select(serde_json::json!({})), None);
+
+    let mut schema = schema;
+    schema.schema_data.discriminator = None;
+    assert_eq!(schema.select_variant(&serde_json::json!({ "type": 1 }), Some(&components)), None, "neither variant matches");
+}
@@ -0,0 +1,737 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    net::{Ipv4Addr, Ipv6Addr},
+};
+
+use indexmap::IndexMap;
+use regex::Regex;
+use serde_json::Value;
+
+use crate::{
+    resolver::escape_pointer_token,
+    schema::{AdditionalProperties, AnySchema, ArrayType, Dependency, IntegerFormat, IntegerType, Items, NumberType, ObjectType, SchemaType, StringFormat, StringType, Type},
+    Components, ReferenceOr, Schema, SchemaKind, VariantOrUnknownOrEmpty,
+};
+
+/// An instance which does not match a [Schema Object][crate::Schema].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// JSON Pointer to the offending value inside of the instance.
+    pub instance_path: String,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.instance_path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{} at `{}`", self.message, self.instance_path)
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl Schema {
+    /// Validates a JSON instance, e.g. a message payload, against this schema.
+    ///
+    /// References to other schemas can not be followed and are reported as
+    /// errors, use [`Schema::validate_with_components`] for schemas
+    /// referencing `#/components/schemas`.
+    pub fn validate(&self, instance: &Value) -> Result<(), Vec<ValidationError>> {
+        self.validate_in(None, instance)
+    }
+
+    /// Validates a JSON instance against this schema, resolving references
+    /// against the given [Components Object][crate::Components].
+    pub fn validate_with_components(&self, instance: &Value, components: &Components) -> Result<(), Vec<ValidationError>> {
+        self.validate_in(Some(components), instance)
+    }
+
+    pub(crate) fn validate_in(&self, components: Option<&Components>, instance: &Value) -> Result<(), Vec<ValidationError>> {
+        let mut validator = InstanceValidator {
+            components,
+            path: Vec::new(),
+            references: Vec::new(),
+            patterns: HashMap::new(),
+            errors: Vec::new(),
+        };
+        validator.schema(self, instance);
+        if validator.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(validator.errors)
+        }
+    }
+}
+
+struct InstanceValidator<'a> {
+    components: Option<&'a Components>,
+    /// The path of the value currently validated.
+    path: Vec<String>,
+    /// The references being followed together with the depth of the value
+    /// they were followed at, to stop on references which loop without
+    /// descending into the instance.
+    references: Vec<(String, usize)>,
+    /// The `pattern` and `patternProperties` regular expressions, compiled
+    /// once per validation.
+    patterns: HashMap<String, Result<Regex, String>>,
+    errors: Vec<ValidationError>,
+}
+
+impl<'a> InstanceValidator<'a> {
+    fn error(&mut self, message: String) {
+        self.errors.push(ValidationError {
+            instance_path: self.path.iter().map(|token| format!("/{token}")).collect(),
+            message,
+        });
+    }
+
+    /// Returns whether `value` matches `pattern`, or `None` after reporting
+    /// an invalid pattern.
+    fn is_match(&mut self, pattern: &str, value: &str) -> Option<bool> {
+        if !self.patterns.contains_key(pattern) {
+            self.patterns.insert(pattern.to_owned(), Regex::new(pattern).map_err(|error| error.to_string()));
+        }
+        match &self.patterns[pattern] {
+            Ok(regex) => Some(regex.is_match(value)),
+            Err(error) => {
+                let message = format!("invalid pattern `{pattern}`: {error}");
+                self.error(message);
+                None
+            }
+        }
+    }
+
+    fn reference(&mut self, reference: &str, instance: &Value, f: impl FnOnce(&mut Self, &Schema, &Value)) {
+        let depth = self.path.len();
+        if self.references.iter().any(|(visited, at)| visited == reference && *at == depth) {
+            return;
+        }
+        let resolved = match self.components {
+            Some(components) => components.resolve_reference::<Schema>(reference).map_err(|error| error.to_string()),
+            None => Err(format!("reference `{reference}` can not be resolved without components")),
+        };
+        match resolved {
+            Ok(schema) => {
+                self.references.push((reference.to_owned(), depth));
+                f(self, schema, instance);
+                self.references.pop();
+            }
+            Err(message) => self.error(message),
+        }
+    }
+
+    fn schema_ref(&mut self, schema: &ReferenceOr<Schema>, instance: &Value) {
+        match schema {
+            ReferenceOr::Item(schema) => self.schema(schema, instance),
+            ReferenceOr::Reference { reference } => self.reference(reference, instance, |v, schema, instance| v.schema(schema, instance)),
+        }
+    }
+
+    fn boxed_schema_ref(&mut self, token: &str, schema: &ReferenceOr<Box<Schema>>, instance: &Value) {
+        self.path.push(escape_pointer_token(token));
+        self.boxed(schema, instance);
+        self.path.pop();
+    }
+
+    fn boxed(&mut self, schema: &ReferenceOr<Box<Schema>>, instance: &Value) {
+        match schema {
+            ReferenceOr::Item(schema) => self.schema(schema, instance),
+            ReferenceOr::Reference { reference } => self.reference(reference, instance, |v, schema, instance| v.schema(schema, instance)),
+        }
+    }
+
+    fn matches_boxed(&mut self, schema: &ReferenceOr<Box<Schema>>, instance: &Value) -> bool {
+        let errors = std::mem::take(&mut self.errors);
+        self.boxed(schema, instance);
+        let matched = self.errors.is_empty();
+        self.errors = errors;
+        matched
+    }
+
+    fn matches_ref(&mut self, schema: &ReferenceOr<Schema>, instance: &Value) -> bool {
+        let errors = std::mem::take(&mut self.errors);
+        self.schema_ref(schema, instance);
+        let matched = self.errors.is_empty();
+        self.errors = errors;
+        matched
+    }
+
+    fn schema(&mut self, schema: &Schema, instance: &Value) {
+        if instance.is_null() && schema.schema_data.nullable == Some(true) {
+            return;
+        }
+        self.keywords(schema, instance);
+        match &schema.schema_kind {
+            SchemaKind::Type(Type::String(string)) => self.string(string, instance),
+            SchemaKind::Type(Type::Number(number)) => self.number(number, instance),
+            SchemaKind::Type(Type::Integer(integer)) => self.integer(integer, instance),
+            SchemaKind::Type(Type::Object(object)) => self.object(object, instance),
+            SchemaKind::Type(Type::Array(array)) => self.array(array, instance),
+            SchemaKind::Type(Type::Boolean(boolean)) => match instance.as_bool() {
+                Some(value) if !boolean.enumeration.is_empty() && !boolean.enumeration.contains(&value) => {
+                    self.error(format!("{value} is not one of the allowed values"));
+                }
+                Some(_) => {}
+                None => self.error(format!("expected a boolean, found {}", kind_of(instance))),
+            },
+            SchemaKind::OneOf { one_of } => self.one_of(one_of, instance),
+            SchemaKind::AllOf { all_of } => self.all_of(all_of, instance),
+            SchemaKind::AnyOf { any_of } => self.any_of(any_of, instance),
+            SchemaKind::Any(any) => self.any(any, instance),
+            SchemaKind::Bool(true) => {}
+            SchemaKind::Bool(false) => self.error("no value is allowed by the `false` schema".to_owned()),
+        }
+    }
+
+    fn one_of(&mut self, one_of: &[ReferenceOr<Schema>], instance: &Value) {
+        let mut matched = 0;
+        for schema in one_of {
+            matched += usize::from(self.matches_ref(schema, instance));
+        }
+        if matched != 1 {
+            self.error(format!("expected exactly one `oneOf` schema to match, {matched} matched"));
+        }
+    }
+
+    fn all_of(&mut self, all_of: &[ReferenceOr<Schema>], instance: &Value) {
+        for schema in all_of {
+            self.schema_ref(schema, instance);
+        }
+    }
+
+    fn any_of(&mut self, any_of: &[ReferenceOr<Schema>], instance: &Value) {
+        if !any_of.iter().any(|schema| self.matches_ref(schema, instance)) {
+            self.error("expected at least one `anyOf` schema to match".to_owned());
+        }
+    }
+
+    /// Checks the keywords which apply to every type.
+    fn keywords(&mut self, schema: &Schema, instance: &Value) {
+        let data = &schema.schema_data;
+        if let Some(reference) = &data.reference {
+            self.reference(reference, instance, |v, schema, instance| v.schema(schema, instance));
+        }
+        if let Some(constant) = data.constant.as_ref().filter(|constant| !json_equal(constant, instance)) {
+            self.error(format!("expected the constant {constant}"));
+        }
+        if let Some(not) = &data.not {
+            if self.matches_boxed(not, instance) {
+                self.error("expected the `not` schema not to match".to_owned());
+            }
+        }
+        if let Some(if_schema) = &data.if_schema {
+            let branch = match self.matches_boxed(if_schema, instance) {
+                true => &data.then_schema,
+                false => &data.else_schema,
+            };
+            if let Some(branch) = branch {
+                self.boxed(branch, instance);
+            }
+        }
+        if !data.all_of.is_empty() {
+            self.all_of(&data.all_of, instance);
+        }
+        if !data.any_of.is_empty() {
+            self.any_of(&data.any_of, instance);
+        }
+        if !data.one_of.is_empty() {
+            self.one_of(&data.one_of, instance);
+        }
+    }
+
+    fn string(&mut self, string: &StringType, instance: &Value) {
+        let Some(value) = instance.as_str() else {
+            if !(instance.is_null() && string.enumeration.contains(&None)) {
+                self.error(format!("expected a string, found {}", kind_of(instance)));
+            }
+            return;
+        };
+        if !string.enumeration.is_empty() && !string.enumeration.iter().any(|allowed| allowed.as_deref() == Some(value)) {
+            self.error(format!("`{value}` is not one of the allowed values"));
+        }
+        self.string_keywords(value, string.pattern.as_deref(), string.min_length, string.max_length);
+        match &string.format {
+            VariantOrUnknownOrEmpty::Item(format) => {
+                let valid = match format {
+                    StringFormat::Date => is_date(value),
+                    StringFormat::DateTime => is_date_time(value),
+                    StringFormat::Byte => is_base64(value),
+                    StringFormat::Password | StringFormat::Binary => true,
+                };
+                if !valid {
+                    self.error(format!("`{value}` is not a valid `{}`", serde_json::to_value(format).unwrap_or_default().as_str().unwrap_or_default()));
+                }
+            }
+            VariantOrUnknownOrEmpty::Unknown(format) => self.format(format, value),
+            VariantOrUnknownOrEmpty::Empty => {}
+        }
+    }
+
+    fn string_keywords(&mut self, value: &str, pattern: Option<&str>, min_length: Option<usize>, max_length: Option<usize>) {
+        let length = value.chars().count();
+        if let Some(min_length) = min_length.filter(|min_length| length < *min_length) {
+            self.error(format!("expected at least {min_length} characters, found {length}"));
+        }
+        if let Some(max_length) = max_length.filter(|max_length| length > *max_length) {
+            self.error(format!("expected at most {max_length} characters, found {length}"));
+        }
+        if let Some(pattern) = pattern {
+            if self.is_match(pattern, value) == Some(false) {
+                self.error(format!("`{value}` does not match the pattern `{pattern}`"));
+            }
+        }
+    }
+
+    /// Checks the formats which are not part of [StringFormat] but defined
+    /// by JSON Schema. Other formats are accepted as is.
+    fn format(&mut self, format: &str, value: &str) {
+        let valid = match format {
+            "date" => is_date(value),
+            "date-time" => is_date_time(value),
+            "email" => value.split_once('@').is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.')),
+            "uuid" => is_uuid(value),
+            "ipv4" => value.parse::<Ipv4Addr>().is_ok(),
+            "ipv6" => value.parse::<Ipv6Addr>().is_ok(),
+            _ => true,
+        };
+        if !valid {
+            self.error(format!("`{value}` is not a valid `{format}`"));
+        }
+    }
+
+    fn number(&mut self, number: &NumberType, instance: &Value) {
+        let Some(value) = instance.as_f64() else {
+            self.error(format!("expected a number, found {}", kind_of(instance)));
+            return;
+        };
+        if !number.enumeration.is_empty() && !number.enumeration.contains(&value) {
+            self.error(format!("{value} is not one of the allowed values"));
+        }
+        self.bounds(value, number.minimum, number.maximum, number.exclusive_minimum, number.exclusive_maximum, number.multiple_of);
+    }
+
+    fn integer(&mut self, integer: &IntegerType, instance: &Value) {
+        // Integers are compared as `i128` to cover both `i64` and `u64`.
+        let value = match instance.as_i64().map(i128::from).or_else(|| instance.as_u64().map(i128::from)) {
+            Some(value) => value,
+            None => match instance.as_f64() {
+                Some(value) if value.fract() == 0.0 && value.abs() < i128::MAX as f64 => value as i128,
+                _ => {
+                    self.error(format!("expected an integer, found {}", kind_of(instance)));
+                    return;
+                }
+            },
+        };
+        if !integer.enumeration.is_empty() && !integer.enumeration.iter().any(|allowed| i128::from(*allowed) == value) {
+            self.error(format!("{value} is not one of the allowed values"));
+        }
+        let (min, max) = match &integer.format {
+            VariantOrUnknownOrEmpty::Item(IntegerFormat::Int32) => (i32::MIN.into(), i32::MAX.into()),
+            VariantOrUnknownOrEmpty::Item(IntegerFormat::Int64) => (i64::MIN.into(), i64::MAX.into()),
+            VariantOrUnknownOrEmpty::Item(IntegerFormat::UInt32) => (0, u32::MAX.into()),
+            VariantOrUnknownOrEmpty::Item(IntegerFormat::UInt64) => (0, u64::MAX.into()),
+            _ => (i128::MIN, i128::MAX),
+        };
+        if value < min || value > max {
+            self.error(format!("{value} is out of range for its format"));
+        }
+        let as_f64 = |bound: Option<i64>| bound.map(|bound| bound as f64);
+        self.bounds(
+            value as f64,
+            as_f64(integer.minimum),
+            as_f64(integer.maximum),
+            as_f64(integer.exclusive_minimum),
+            as_f64(integer.exclusive_maximum),
+            as_f64(integer.multiple_of),
+        );
+    }
+
+    fn bounds(&mut self, value: f64, minimum: Option<f64>, maximum: Option<f64>, exclusive_minimum: Option<f64>, exclusive_maximum: Option<f64>, multiple_of: Option<f64>) {
+        if let Some(minimum) = minimum.filter(|minimum| value < *minimum) {
+            self.error(format!("{value} is less than the minimum of {minimum}"));
+        }
+        if let Some(maximum) = maximum.filter(|maximum| value > *maximum) {
+            self.error(format!("{value} is greater than the maximum of {maximum}"));
+        }
+        if let Some(minimum) = exclusive_minimum.filter(|minimum| value <= *minimum) {
+            self.error(format!("{value} is not greater than the exclusive minimum of {minimum}"));
+        }
+        if let Some(maximum) = exclusive_maximum.filter(|maximum| value >= *maximum) {
+            self.error(format!("{value} is not less than the exclusive maximum of {maximum}"));
+        }
+        if let Some(multiple_of) = multiple_of.filter(|multiple_of| *multiple_of > 0.0) {
+            let quotient = value / multiple_of;
+            if (quotient - quotient.round()).abs() > f64::EPSILON * quotient.abs().max(1.0) {
+                self.error(format!("{value} is not a multiple of {multiple_of}"));
+            }
+        }
+    }
+
+    fn object(&mut self, object: &ObjectType, instance: &Value) {
+        if !instance.is_object() {
+            self.error(format!("expected an object, found {}", kind_of(instance)));
+            return;
+        }
+        self.object_keywords(
+            &ObjectKeywords {
+                properties: &object.properties,
+                pattern_properties: &object.pattern_properties,
+                additional_properties: object.additional_properties.as_ref(),
+                property_names: object.property_names.as_ref(),
+                dependencies: &object.dependencies,
+                required: &object.required,
+                min_properties: object.min_properties,
+                max_properties: object.max_properties,
+            },
+            instance,
+        );
+    }
+
+    fn object_keywords(&mut self, keywords: &ObjectKeywords, instance: &Value) {
+        let Some(values) = instance.as_object() else {
+            return;
+        };
+        for name in keywords.required {
+            if !values.contains_key(name) {
+                self.error(format!("missing required property `{name}`"));
+            }
+        }
+        for (name, value) in values {
+            let mut matched = false;
+            if let Some(schema) = keywords.properties.get(name) {
+                self.boxed_schema_ref(name, schema, value);
+                matched = true;
+            }
+            for (pattern, schema) in keywords.pattern_properties {
+                if self.is_match(pattern, name) == Some(true) {
+                    self.boxed_schema_ref(name, schema, value);
+                    matched = true;
+                }
+            }
+            match (matched, keywords.additional_properties) {
+                (false, Some(AdditionalProperties::Any(false))) => self.error(format!("additional property `{name}` is not allowed")),
+                (false, Some(AdditionalProperties::Schema(schema))) => {
+                    self.path.push(escape_pointer_token(name));
+                    self.schema_ref(schema, value);
+                    self.path.pop();
+                }
+                _ => {}
+            }
+            if let Some(property_names) = keywords.property_names {
+                self.boxed_schema_ref(name, property_names, &Value::String(name.clone()));
+            }
+        }
+        for (name, dependency) in keywords.dependencies.iter().filter(|(name, _)| values.contains_key(*name)) {
+            match dependency {
+                Dependency::Properties(required) => {
+                    for required in required.iter().filter(|required| !values.contains_key(*required)) {
+                        self.error(format!("property `{name}` requires property `{required}`"));
+                    }
+                }
+                Dependency::Schema(schema) => self.boxed(schema, instance),
+            }
+        }
+        if let Some(min_properties) = keywords.min_properties.filter(|min_properties| values.len() < *min_properties) {
+            self.error(format!("expected at least {min_properties} properties, found {}", values.len()));
+        }
+        if let Some(max_properties) = keywords.max_properties.filter(|max_properties| values.len() > *max_properties) {
+            self.error(format!("expected at most {max_properties} properties, found {}", values.len()));
+        }
+    }
+
+    fn array(&mut self, array: &ArrayType, instance: &Value) {
+        if !instance.is_array() {
+            self.error(format!("expected an array, found {}", kind_of(instance)));
+            return;
+        }
+        self.array_keywords(
+            &ArrayKeywords {
+                items: array.items.as_ref(),
+                additional_items: array.additional_items.as_ref(),
+                contains: array.contains.as_ref(),
+                min_items: array.min_items,
+                max_items: array.max_items,
+                unique_items: array.unique_items,
+            },
+            instance,
+        );
+    }
+
+    fn array_keywords(&mut self, keywords: &ArrayKeywords, instance: &Value) {
+        let Some(values) = instance.as_array() else {
+            return;
+        };
+        if let Some(items) = keywords.items {
+            for (index, value) in values.iter().enumerate() {
+                match (items.get(index), keywords.additional_items) {
+                    (Some(schema), _) => self.boxed_schema_ref(&index.to_string(), schema, value),
+                    (None, Some(AdditionalProperties::Any(false))) => self.error(format!("additional item {index} is not allowed")),
+                    (None, Some(AdditionalProperties::Schema(schema))) => {
+                        self.path.push(index.to_string());
+                        self.schema_ref(schema, value);
+                        self.path.pop();
+                    }
+                    (None, _) => {}
+                }
+            }
+        }
+        if let Some(contains) = keywords.contains {
+            if !values.iter().any(|value| self.matches_boxed(contains, value)) {
+                self.error("expected at least one item to match the `contains` schema".to_owned());
+            }
+        }
+        let (min_items, max_items, unique_items) = (keywords.min_items, keywords.max_items, keywords.unique_items);
+        if let Some(min_items) = min_items.filter(|min_items| values.len() < *min_items) {
+            self.error(format!("expected at least {min_items} items, found {}", values.len()));
+        }
+        if let Some(max_items) = max_items.filter(|max_items| values.len() > *max_items) {
+            self.error(format!("expected at most {max_items} items, found {}", values.len()));
+        }
+        if unique_items {
+            for (index, value) in values.iter().enumerate() {
+                if values[..index].iter().any(|previous| json_equal(previous, value)) {
+                    self.error(format!("item {index} is a duplicate"));
+                }
+            }
+        }
+    }
+
+    /// A schema without `type` applies each keyword to the instances of the
+    /// type the keyword is defined for.
+    fn any(&mut self, any: &AnySchema, instance: &Value) {
+        if !any.types.is_empty() && !any.types.iter().any(|typ| is_of_type(*typ, instance)) {
+            let types: Vec<_> = any
+                .types
+                .iter()
+                .map(|typ| serde_json::to_value(typ).unwrap_or_default().as_str().unwrap_or_default().to_owned())
+                .collect();
+            self.error(format!("expected {}, found {}", types.join(" or "), kind_of(instance)));
+            return;
+        }
+        if !any.enumeration.is_empty() && !any.enumeration.iter().any(|allowed| json_equal(allowed, instance)) {
+            self.error(format!("{instance} is not one of the allowed values"));
+        }
+        match instance {
+            Value::String(value) => {
+                self.string_keywords(value, any.pattern.as_deref(), any.min_length, any.max_length);
+                if let Some(format) = &any.format {
+                    self.format(format, value);
+                }
+            }
+            Value::Number(number) => {
+                let value = number.as_f64().unwrap_or_default();
+                let exclusive = |exclusive: Option<bool>, bound: Option<f64>| if exclusive == Some(true) { bound } else { None };
+                let inclusive = |exclusive: Option<bool>, bound: Option<f64>| if exclusive == Some(true) { None } else { bound };
+                self.bounds(
+                    value,
+                    inclusive(any.exclusive_minimum, any.minimum),
+                    inclusive(any.exclusive_maximum, any.maximum),
+                    exclusive(any.exclusive_minimum, any.minimum),
+                    exclusive(any.exclusive_maximum, any.maximum),
+                    any.multiple_of,
+                );
+            }
+            Value::Object(_) => self.object_keywords(
+                &ObjectKeywords {
+                    properties: &any.properties,
+                    pattern_properties: &any.pattern_properties,
+                    additional_properties: any.additional_properties.as_ref(),
+                    property_names: any.property_names.as_ref(),
+                    dependencies: &any.dependencies,
+                    required: &any.required,
+                    min_properties: any.min_properties,
+                    max_properties: any.max_properties,
+                },
+                instance,
+            ),
+            Value::Array(_) => self.array_keywords(
+                &ArrayKeywords {
+                    items: any.items.as_ref(),
+                    additional_items: any.additional_items.as_ref(),
+                    contains: any.contains.as_ref(),
+                    min_items: any.min_items,
+                    max_items: any.max_items,
+                    unique_items: any.unique_items == Some(true),
+                },
+                instance,
+            ),
+            Value::Null | Value::Bool(_) => {}
+        }
+    }
+}
+
+/// The keywords of [ObjectType] and [AnySchema] which apply to objects.
+struct ObjectKeywords<'s> {
+    properties: &'s IndexMap<String, ReferenceOr<Box<Schema>>>,
+    pattern_properties: &'s IndexMap<String, ReferenceOr<Box<Schema>>>,
+    additional_properties: Option<&'s AdditionalProperties>,
+    property_names: Option<&'s ReferenceOr<Box<Schema>>>,
+    dependencies: &'s IndexMap<String, Dependency>,
+    required: &'s [String],
+    min_properties: Option<usize>,
+    max_properties: Option<usize>,
+}
+
+/// The keywords of [ArrayType] and [AnySchema] which apply to arrays.
+struct ArrayKeywords<'s> {
+    items: Option<&'s Items>,
+    additional_items: Option<&'s AdditionalProperties>,
+    contains: Option<&'s ReferenceOr<Box<Schema>>>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    unique_items: bool,
+}
+
+/// Compares JSON values as JSON Schema does: numbers are equal when their
+/// mathematical values are, e.g. `1` and `1.0`.
+fn json_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => match (a.as_i64(), b.as_i64(), a.as_u64(), b.as_u64()) {
+            (Some(a), Some(b), ..) => a == b,
+            (.., Some(a), Some(b)) => a == b,
+            _ => a.as_f64() == b.as_f64(),
+        },
+        (Value::Array(a), Value::Array(b)) => a.len() == b.len() && a.iter().zip(b).all(|(a, b)| json_equal(a, b)),
+        (Value::Object(a), Value::Object(b)) => a.len() == b.len() && a.iter().all(|(key, a)| b.get(key).is_some_and(|b| json_equal(a, b))),
+        (a, b) => a == b,
+    }
+}
+
+fn is_of_type(typ: SchemaType, instance: &Value) -> bool {
+    match typ {
+        SchemaType::Null => instance.is_null(),
+        SchemaType::Boolean => instance.is_boolean(),
+        SchemaType::Object => instance.is_object(),
+        SchemaType::Array => instance.is_array(),
+        SchemaType::Number => instance.is_number(),
+        SchemaType::String => instance.is_string(),
+        SchemaType::Integer => instance.as_f64().is_some_and(|value| value.fract() == 0.0),
+    }
+}
+
+fn kind_of(instance: &Value) -> &'static str {
+    match instance {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+fn is_digits(value: &str, count: usize) -> bool {
+    value.len() == count && value.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+/// `full-date` of [RFC 3339](https://tools.ietf.org/html/rfc3339#section-5.6).
+fn is_date(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('-').collect();
+    match parts.as_slice() {
+        [year, month, day] if is_digits(year, 4) && is_digits(month, 2) && is_digits(day, 2) => {
+            let (month, day): (u32, u32) = (month.parse().unwrap_or_default(), day.parse().unwrap_or_default());
+            (1..=12).contains(&month) && (1..=31).contains(&day)
+        }
+        _ => false,
+    }
+}
+
+/// `date-time` of [RFC 3339](https://tools.ietf.org/html/rfc3339#section-5.6).
+fn is_date_time(value: &str) -> bool {
+    let Some((date, time)) = value.split_once(['T', 't', ' ']) else {
+        return false;
+    };
+    let (time, offset) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        (time, "")
+    } else {
+        match time.rfind(['+', '-']) {
+            Some(index) => time.split_at(index),
+            None => return false,
+        }
+    };
+    let time = time
+        .split_once('.')
+        .map_or(time, |(time, fraction)| if is_digits(fraction, fraction.len()) && !fraction.is_empty() { time } else { "" });
+    let is_time = |time: &str, parts: usize| {
+        let time: Vec<&str> = time.split(':').collect();
+        time.len() == parts && time.iter().all(|part| is_digits(part, 2))
+    };
+    is_date(date) && is_time(time, 3) && (offset.is_empty() || is_time(&offset[1..], 2))
+}
+
+fn is_base64(value: &str) -> bool {
+    let trimmed = value.trim_end_matches('=');
+    value.len().is_multiple_of(4) && value.len() - trimmed.len() <= 2 && trimmed.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'+' || byte == b'/')
+}
+
+fn is_uuid(value: &str) -> bool {
+    let groups: Vec<&str> = value.split('-').collect();
+    groups.iter().map(|group| group.len()).eq([8, 4, 4, 4, 12]) && groups.iter().all(|group| group.bytes().all(|byte| byte.is_ascii_hexdigit()))
+}
+
+#[test]
+fn test_validate_instance() {
+    let schema: Schema = serde_yaml::from_str(
+        r#"
+        type: object
+        required: [id, name]
+        additionalProperties: false
+        properties:
+          id:
+            type: string
+            format: uuid
+          name:
+            type: string
+            minLength: 2
+            pattern: "^[A-Z]"
+          age:
+            type: integer
+            format: int32
+            minimum: 0
+            exclusiveMaximum: 150
+          ratio:
+            type: number
+            multipleOf: 0.25
+          tags:
+            type: array
+            uniqueItems: true
+            items:
+              type: string
+              enum: [a, b]
+          contact:
+            oneOf:
+              - type: string
+                format: email
+              - type: integer
+        "#,
+    )
+    .expect("Could not deserialize instance schema");
+
+    let valid = serde_json::json!({"id": "123e4567-e89b-12d3-a456-426614174000", "name": "Ada", "age": 36, "ratio": 0.75, "tags": ["a", "b"], "contact": 42});
+    assert_eq!(schema.validate(&valid), Ok(()));
+
+    let invalid = serde_json::json!({"age": 150, "contact": true, "extra": 1, "id": "nope", "name": "a", "ratio": 0.3, "tags": ["a", "a", "c"]});
+    let errors: Vec<String> = schema.validate(&invalid).unwrap_err().iter().map(|error| error.instance_path.clone()).collect();
+    assert_eq!(errors, vec!["/age", "/contact", "", "/id", "/name", "/name", "/ratio", "/tags/2", "/tags"]);
+
+    let integer = |format: &str| -> Schema { serde_json::from_value(serde_json::json!({ "type": "integer", "format": format })).unwrap() };
+    assert_eq!(integer("uint64").validate(&serde_json::json!(u64::MAX)), Ok(()));
+    assert!(integer("uint64").validate(&serde_json::json!(-1)).is_err());
+    assert!(integer("int64").validate(&serde_json::json!(u64::MAX)).is_err());
+
+    let unique: Schema = serde_json::from_value(serde_json::json!({ "type": "array", "uniqueItems": true })).unwrap();
+    assert!(unique.validate(&serde_json::json!([1, 1.0])).is_err());
+    assert!(unique.validate(&serde_json::json!([{ "a": [1] }, { "a": [1.0] }])).is_err());
+    assert_eq!(unique.validate(&serde_json::json!([1, 1.5, "1"])), Ok(()));
+    let constant: Schema = serde_json::from_value(serde_json::json!({ "const": 2.0 })).unwrap();
+    assert_eq!(constant.validate(&serde_json::json!(2)), Ok(()));
+
+    let patterns: Schema = serde_json::from_value(serde_json::json!({ "type": "object", "patternProperties": { "^x-": { "type": "string", "pattern": "^[a-z]+$" }, "(": {} } })).unwrap();
+    let errors = patterns.validate(&serde_json::json!({ "x-a": "abc", "x-b": "ABC" })).unwrap_err();
+    let errors: Vec<&str> = errors.iter().map(|error| error.instance_path.as_str()).collect();
+    assert_eq!(errors, ["", "/x-b", ""]);
+}
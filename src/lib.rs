@@ -1,43 +1,81 @@
 mod api;
+pub mod avro;
+mod builder;
+mod bundle;
 mod channel;
 pub mod channel_binding;
+#[cfg(feature = "codegen")]
+pub mod codegen;
 mod components;
 mod correlation_id;
+mod de;
+mod dereference;
+#[doc(hidden)]
+pub mod derive_support;
+mod discriminator;
 mod example;
 mod external_documentation;
 mod info;
+mod instance;
+mod lint;
+mod loader;
 mod message;
 pub mod message_binding;
 mod message_trait;
 pub mod operation_binding;
 mod operation_trait;
 mod parameter;
+mod pointer;
 mod reference;
+mod resolver;
 pub mod schema;
+mod schema_format;
 mod security_scheme;
 mod server;
 pub mod server_binding;
+mod source_map;
 mod tag;
+mod to_schema;
+mod traits;
+pub mod v3;
+mod validate;
 mod variant_or;
+mod version;
+pub mod visit;
 
 pub use api::AsyncAPI;
+pub use avro::AvroSchema;
+pub use builder::{AsyncAPIBuilder, BuildError, ChannelBuilder, ObjectBuilder, OperationBuilder, ServerBuilder};
 pub use channel::{Channel, Operation, OperationMessageType};
 pub use channel_binding::ChannelBinding;
 pub use components::Components;
 pub use correlation_id::CorrelationId;
+pub use dereference::{Dereferenced, RecursiveSchema};
+pub use discriminator::Discriminator;
 pub use example::Example;
 pub use external_documentation::ExternalDocumentation;
 pub use info::{Contact, Info, License};
+pub use instance::ValidationError;
+pub use loader::{LoadError, Loaded, Loader};
 pub use message::{Message, Payload};
 pub use message_binding::MessageBinding;
-pub use message_trait::MessageTrait;
+pub use message_trait::{MessageExample, MessageTrait};
 pub use operation_binding::OperationBinding;
 pub use operation_trait::OperationTrait;
 pub use parameter::Parameter;
+pub use pointer::{NodeMut, NodeRef};
 pub use reference::ReferenceOr;
+pub use resolver::{Component, ResolveError};
 pub use schema::{Schema, SchemaData, SchemaKind, PayloadKind};
+pub use schema_format::SchemaFormat;
+#[cfg(feature = "derive")]
+pub use asyncapi_derive::AsyncApiSchema;
 pub use security_scheme::SecurityScheme;
 pub use server::{SecurityRequirement, Server, ServerVariable};
 pub use server_binding::ServerBinding;
+pub use source_map::{load_with_source_map, Location, SourceError, SourceMap};
 pub use tag::Tag;
+pub use to_schema::ToSchema;
+pub use validate::{Diagnostic, Severity};
 pub use variant_or::{VariantOrUnknown, VariantOrUnknownOrEmpty};
+pub use version::{load_any_version, VersionError};
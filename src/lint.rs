@@ -0,0 +1,291 @@
+use std::borrow::Cow;
+
+use indexmap::IndexMap;
+use serde_json::Value;
+
+use crate::{
+    resolver::escape_pointer_token, AsyncAPI, Channel, Components, Diagnostic, Message, MessageTrait, OperationMessageType, Payload, ReferenceOr, Schema, SchemaFormat, Severity, ValidationError,
+};
+
+impl AsyncAPI {
+    /// Checks that the examples of every [Message Object][crate::Message]
+    /// and [Message Trait Object][crate::MessageTrait] validate against the
+    /// `payload` and `headers` schemas of the message.
+    ///
+    /// Messages are checked with their traits applied. The examples of a
+    /// trait are checked against its own `headers`, and their payload against
+    /// the `payload` of each message applying the trait. Avro payloads are
+    /// checked against the schema they convert into, and payloads using
+    /// another `schemaFormat` than AsyncAPI, JSON Schema or OpenAPI are not
+    /// checked. Messages whose traits can not be applied are reported as
+    /// warnings at their `traits`, instead of being checked without them.
+    pub fn lint_examples(&self) -> Vec<Diagnostic> {
+        let mut linter = ExampleLinter {
+            components: self.components.as_ref(),
+            diagnostics: Vec::new(),
+        };
+        linter.channels("/channels", &self.channels);
+        if let Some(components) = &self.components {
+            linter.channels("/components/channels", &components.channels);
+            for (name, message) in &components.messages {
+                if let ReferenceOr::Item(message) = message {
+                    linter.message(&pointer("/components/messages", name), message);
+                }
+            }
+            for (name, message_trait) in &components.message_traits {
+                if let ReferenceOr::Item(message_trait) = message_trait {
+                    linter.message_trait(&pointer("/components/messageTraits", name), message_trait);
+                }
+            }
+        }
+        linter.diagnostics
+    }
+}
+
+struct ExampleLinter<'a> {
+    components: Option<&'a Components>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+fn pointer(parent: &str, token: &str) -> String {
+    format!("{parent}/{}", escape_pointer_token(token))
+}
+
+/// Names an example by its `name`, or by its index if it has none.
+fn example_name(name: Option<&str>, index: usize) -> String {
+    match name {
+        Some(name) => format!("example `{name}`"),
+        None => format!("example {index}"),
+    }
+}
+
+impl<'a> ExampleLinter<'a> {
+    fn channels(&mut self, path: &str, channels: &IndexMap<String, Channel>) {
+        for (name, channel) in channels {
+            let path = pointer(path, name);
+            for (field, operation) in [("subscribe", &channel.subscribe), ("publish", &channel.publish)] {
+                let path = pointer(&path, field);
+                match operation.as_ref().and_then(|operation| operation.message.as_ref()) {
+                    Some(OperationMessageType::Single(ReferenceOr::Item(message))) => self.message(&pointer(&path, "message"), message),
+                    Some(OperationMessageType::Map(messages)) => {
+                        for (name, message) in messages {
+                            if let ReferenceOr::Item(message) = message {
+                                self.message(&pointer(&pointer(&path, "message"), name), message);
+                            }
+                        }
+                    }
+                    Some(OperationMessageType::OneOf(messages)) => {
+                        for (index, message) in messages.iter().enumerate() {
+                            if let ReferenceOr::Item(message) = message {
+                                self.message(&pointer(&pointer(&pointer(&path, "message"), "oneOf"), &index.to_string()), message);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn message(&mut self, path: &str, message: &Message) {
+        let effective = match message.traits.is_empty() {
+            true => None,
+            false => match message.with_traits_applied(self.components.unwrap_or(&Components::default())) {
+                Ok(effective) => Some(effective),
+                Err(error) => {
+                    self.report(pointer(path, "traits"), Severity::Warning, format!("examples can not be checked: {error}"));
+                    return;
+                }
+            },
+        };
+        let effective = effective.as_ref().unwrap_or(message);
+        let headers = self.schema(path, "headers", effective.headers.as_ref());
+        let payload = self.payload(path, effective);
+        let payload = payload.as_deref();
+        for (index, example) in message.examples.iter().enumerate() {
+            let path = pointer(&pointer(path, "examples"), &index.to_string());
+            let name = example_name(example.name.as_deref(), index);
+            if let (Some(schema), Some(headers)) = (headers, &example.headers) {
+                self.check(&path, &name, "headers", schema, headers);
+            }
+            if let (Some(schema), Some(payload)) = (payload, &example.payload) {
+                self.check(&path, &name, "payload", schema, payload);
+            }
+        }
+        for (index, message_trait) in message.traits.iter().enumerate() {
+            let (Some(payload), Some(components)) = (payload, self.components) else {
+                break;
+            };
+            let trait_path = match message_trait {
+                ReferenceOr::Item(_) => pointer(&pointer(path, "traits"), &index.to_string()),
+                ReferenceOr::Reference { reference } => reference.trim_start_matches('#').to_owned(),
+            };
+            let message_trait = match components.resolve(message_trait) {
+                Ok(message_trait) => message_trait,
+                Err(error) => {
+                    let path = pointer(&pointer(path, "traits"), &index.to_string());
+                    self.report(path, Severity::Warning, format!("examples can not be checked: {error}"));
+                    continue;
+                }
+            };
+            for (index, example) in message_trait.examples.iter().enumerate() {
+                if let Some(instance) = &example.payload {
+                    let path = pointer(&pointer(&trait_path, "examples"), &index.to_string());
+                    let name = format!("{} applied to `{path}`", example_name(example.name.as_deref(), index));
+                    self.check(&path, &name, "payload", payload, instance);
+                }
+            }
+        }
+    }
+
+    fn message_trait(&mut self, path: &str, message_trait: &MessageTrait) {
+        let Some(headers) = self.schema(path, "headers", message_trait.headers.as_ref()) else {
+            return;
+        };
+        for (index, example) in message_trait.examples.iter().enumerate() {
+            if !example.headers.is_empty() {
+                let path = pointer(&pointer(path, "examples"), &index.to_string());
+                let instance = Value::Object(example.headers.clone().into_iter().collect());
+                self.check(&path, &example_name(example.name.as_deref(), index), "headers", headers, &instance);
+            }
+        }
+    }
+
+    /// Returns the payload schema of a message, unless it uses a schema
+    /// format which is neither made of [Schema Objects][crate::Schema] nor
+    /// Avro.
+    fn payload<'s>(&mut self, path: &str, message: &'s Message) -> Option<Cow<'s, Schema>>
+    where
+        'a: 's,
+    {
+        let is_schema = SchemaFormat::of(message.schema_format.as_deref()).is_schema();
+        match &message.payload {
+            Some(Payload::Schema(schema)) if is_schema => self.schema(path, "payload", Some(schema)).map(Cow::Borrowed),
+            Some(payload @ (Payload::OneOf { .. } | Payload::Avro(_))) => match payload.to_schema()? {
+                ReferenceOr::Item(schema) => Some(Cow::Owned(schema)),
+                ReferenceOr::Reference { .. } => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn schema<'s>(&mut self, path: &str, field: &str, schema: Option<&'s ReferenceOr<Schema>>) -> Option<&'s Schema>
+    where
+        'a: 's,
+    {
+        match schema? {
+            ReferenceOr::Item(schema) => Some(schema),
+            ReferenceOr::Reference { reference } => match self.components.map(|components| components.resolve_reference::<Schema>(reference)) {
+                Some(Ok(schema)) => Some(schema),
+                Some(Err(error)) => {
+                    self.report(pointer(path, field), Severity::Warning, format!("examples can not be checked: {error}"));
+                    None
+                }
+                None => {
+                    self.report(
+                        pointer(path, field),
+                        Severity::Warning,
+                        format!("examples can not be checked: `{reference}` can not be resolved without components"),
+                    );
+                    None
+                }
+            },
+        }
+    }
+
+    fn check(&mut self, path: &str, name: &str, field: &str, schema: &Schema, instance: &Value) {
+        if let Err(errors) = schema.validate_in(self.components, instance) {
+            for ValidationError { instance_path, message } in errors {
+                self.report(
+                    format!("{}{instance_path}", pointer(path, field)),
+                    Severity::Error,
+                    format!("{name} does not match the {field} schema: {message}"),
+                );
+            }
+        }
+    }
+
+    fn report(&mut self, path: String, severity: Severity, message: String) {
+        self.diagnostics.push(Diagnostic { path, severity, message });
+    }
+}
+
+#[test]
+fn test_lint_examples() {
+    let example = r##"
+    asyncapi: 2.6.0
+    info:
+      title: Examples
+      version: 1.0.0
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            $ref: "#/components/messages/UserSignedUp"
+      user/deleted:
+        publish:
+          message:
+            oneOf:
+              - payload:
+                  type: string
+                examples:
+                  - payload: 1
+    components:
+      messages:
+        UserSignedUp:
+          payload:
+            $ref: "#/components/schemas/User"
+          traits:
+            - $ref: "#/components/messageTraits/commonHeaders"
+          examples:
+            - name: valid
+              headers:
+                my-app-header: 12
+              payload:
+                name: Ada
+            - name: stale
+              headers:
+                my-app-header: twelve
+              payload:
+                fullName: Ada
+        Dangling:
+          payload:
+            type: string
+          traits:
+            - $ref: "#/components/messageTraits/missing"
+          examples:
+            - payload: 1
+      messageTraits:
+        commonHeaders:
+          headers:
+            type: object
+            properties:
+              my-app-header:
+                type: integer
+          examples:
+            - headers:
+                my-app-header: 1.5
+              payload:
+                name: 42
+      schemas:
+        User:
+          type: object
+          required: [name]
+          properties:
+            name:
+              type: string
+    "##;
+    let asyncapi: AsyncAPI = serde_yaml::from_str(example).expect("Could not deserialize examples example");
+    let diagnostics: Vec<String> = asyncapi.lint_examples().iter().map(|diagnostic| diagnostic.path.clone()).collect();
+    assert_eq!(
+        diagnostics,
+        vec![
+            "/channels/user~1deleted/publish/message/oneOf/0/examples/0/payload",
+            "/components/messages/UserSignedUp/examples/1/headers/my-app-header",
+            "/components/messages/UserSignedUp/examples/1/payload",
+            "/components/messageTraits/commonHeaders/examples/0/payload/name",
+            "/components/messages/Dangling/traits",
+            "/components/messageTraits/commonHeaders/examples/0/headers/my-app-header",
+        ]
+    );
+}
@@ -0,0 +1,215 @@
+use std::{
+    collections::HashMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    resolver::{decode_pointer_token, escape_pointer_token},
+    AsyncAPI,
+};
+
+/// The error returned when a document or one of the files it references can
+/// not be loaded.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file could not be read.
+    Io { path: PathBuf, error: io::Error },
+    /// The file is neither valid JSON nor valid YAML.
+    Parse { path: PathBuf, message: String },
+    /// The fragment of the reference does not point to a value of the file.
+    MissingPointer { path: PathBuf, pointer: String },
+    /// The referenced value does not have the expected structure.
+    Deserialize { path: PathBuf, pointer: String, message: String },
+    /// Following the reference leads back to a reference already visited.
+    /// Holds the chain of `file#pointer` locations in the order they were followed.
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io { path, error } => write!(f, "could not read `{}`: {error}", path.display()),
+            LoadError::Parse { path, message } => write!(f, "could not parse `{}`: {message}", path.display()),
+            LoadError::MissingPointer { path, pointer } => write!(f, "`{}` has no value at `#{pointer}`", path.display()),
+            LoadError::Deserialize { path, pointer, message } => write!(f, "invalid value at `{}#{pointer}`: {message}", path.display()),
+            LoadError::Cycle(chain) => write!(f, "reference cycle detected: {}", chain.join(" -> ")),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// A value loaded by following a reference, together with the file it was
+/// found in. References nested in the value are relative to that file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Loaded<T> {
+    /// The canonical path of the file holding the value.
+    pub path: PathBuf,
+    /// The JSON Pointer of the value inside of the file, without the leading `#`.
+    pub pointer: String,
+    /// The deserialized value.
+    pub value: T,
+}
+
+/// Loads AsyncAPI documents split across several JSON or YAML files.
+///
+/// Every file is read and parsed only once and kept in a cache keyed by its
+/// canonical path. References such as `./schemas/user.yaml#/User`,
+/// `common.json` or `#/components/schemas/User` are resolved relative to the
+/// file they appear in.
+///
+/// ```no_run
+/// # use asyncapi::{Loader, Schema};
+/// # fn example() -> Result<(), asyncapi::LoadError> {
+/// let mut loader = Loader::new();
+/// let root = loader.load_root("asyncapi.yaml")?;
+/// let user = loader.resolve::<Schema>(&root.path, "./schemas/user.yaml#/User")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct Loader {
+    files: HashMap<PathBuf, serde_json::Value>,
+}
+
+impl Loader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the root document of a specification.
+    pub fn load_root(&mut self, path: impl AsRef<Path>) -> Result<Loaded<AsyncAPI>, LoadError> {
+        let path = canonicalize(path.as_ref())?;
+        let value = self.load_file(&path)?.clone();
+        Ok(Loaded {
+            value: from_value(&path, "", value)?,
+            path,
+            pointer: String::new(),
+        })
+    }
+
+    /// Reads and parses a file, or returns it from the cache if it has
+    /// already been loaded. Files ending in `.json` are parsed as JSON,
+    /// every other file as YAML.
+    pub fn load_file(&mut self, path: impl AsRef<Path>) -> Result<&serde_json::Value, LoadError> {
+        let path = canonicalize(path.as_ref())?;
+        if !self.files.contains_key(&path) {
+            let content = fs::read_to_string(&path).map_err(|error| LoadError::Io { path: path.clone(), error })?;
+            let value = if path.extension().is_some_and(|extension| extension == "json") {
+                serde_json::from_str(&content).map_err(|error| error.to_string())
+            } else {
+                serde_yaml::from_str(&content).map_err(|error| error.to_string())
+            }
+            .map_err(|message| LoadError::Parse { path: path.clone(), message })?;
+            self.files.insert(path.clone(), value);
+        }
+        Ok(&self.files[&path])
+    }
+
+    /// Returns the canonical paths of all files loaded so far.
+    pub fn files(&self) -> impl Iterator<Item = &Path> {
+        self.files.keys().map(PathBuf::as_path)
+    }
+
+    /// Follows a reference found in the file at `base` and returns the raw
+    /// value it points to. Values which are themselves references are
+    /// followed until a value which is not a reference is found.
+    pub fn resolve_value(&mut self, base: impl AsRef<Path>, reference: &str) -> Result<Loaded<serde_json::Value>, LoadError> {
+        let mut base = base.as_ref().to_path_buf();
+        let mut reference = reference.to_owned();
+        let mut chain: Vec<String> = Vec::new();
+        loop {
+            let (file, pointer) = split_reference(&reference);
+            let path = if file.is_empty() {
+                canonicalize(&base)?
+            } else {
+                canonicalize(&base.parent().unwrap_or_else(|| Path::new("")).join(file))?
+            };
+            let location = format!("{}#{pointer}", path.display());
+            if chain.contains(&location) {
+                chain.push(location);
+                return Err(LoadError::Cycle(chain));
+            }
+            chain.push(location);
+            let value = self.load_file(&path)?.pointer(&pointer).ok_or_else(|| LoadError::MissingPointer {
+                path: path.clone(),
+                pointer: pointer.clone(),
+            })?;
+            match value.get("$ref").and_then(serde_json::Value::as_str) {
+                Some(next) => {
+                    reference = next.to_owned();
+                    base = path;
+                }
+                None => return Ok(Loaded { value: value.clone(), path, pointer }),
+            }
+        }
+    }
+
+    /// Follows a reference found in the file at `base` and deserializes the
+    /// value it points to.
+    pub fn resolve<T: DeserializeOwned>(&mut self, base: impl AsRef<Path>, reference: &str) -> Result<Loaded<T>, LoadError> {
+        let Loaded { path, pointer, value } = self.resolve_value(base, reference)?;
+        Ok(Loaded {
+            value: from_value(&path, &pointer, value)?,
+            path,
+            pointer,
+        })
+    }
+}
+
+/// Splits a reference into its file part and its JSON Pointer, undoing the
+/// percent-encoding of the URI fragment.
+fn split_reference(reference: &str) -> (&str, String) {
+    let (file, fragment) = reference.split_once('#').unwrap_or((reference, ""));
+    let pointer = fragment.split('/').map(|token| escape_pointer_token(&decode_pointer_token(token))).collect::<Vec<_>>().join("/");
+    (file, pointer)
+}
+
+fn canonicalize(path: &Path) -> Result<PathBuf, LoadError> {
+    fs::canonicalize(path).map_err(|error| LoadError::Io { path: path.to_path_buf(), error })
+}
+
+fn from_value<T: DeserializeOwned>(path: &Path, pointer: &str, value: serde_json::Value) -> Result<T, LoadError> {
+    crate::de::from_value(value).map_err(|error| LoadError::Deserialize {
+        path: path.to_path_buf(),
+        pointer: format!("{pointer}{}", error.pointer),
+        message: error.message,
+    })
+}
+
+#[test]
+fn test_load_external_references() {
+    use crate::Schema;
+
+    let dir = std::env::temp_dir().join(format!("asyncapi-loader-{}", std::process::id()));
+    fs::create_dir_all(dir.join("schemas")).unwrap();
+    fs::write(
+        dir.join("schemas").join("user.yaml"),
+        "User:\n  type: object\n  properties:\n    name:\n      $ref: '../common.json#/definitions/name'\n",
+    )
+    .unwrap();
+    fs::write(dir.join("common.json"), r##"{"definitions": {"name": {"type": "string"}, "alias": {"$ref": "#/definitions/name"}}}"##).unwrap();
+
+    let mut loader = Loader::new();
+    let user = loader.resolve::<Schema>(dir.join("asyncapi.yaml"), "./schemas/user.yaml#/User").unwrap();
+    assert_eq!(user.path, fs::canonicalize(dir.join("schemas").join("user.yaml")).unwrap());
+    let name = loader.resolve::<Schema>(&user.path, "../common.json#/definitions/alias").unwrap();
+    assert_eq!(name.pointer, "/definitions/name");
+    assert!(matches!(
+        loader.resolve_value(&user.path, "#/Missing"),
+        Err(LoadError::MissingPointer { pointer, .. }) if pointer == "/Missing"
+    ));
+    assert_eq!(loader.files().count(), 2);
+
+    fs::remove_dir_all(dir).unwrap();
+}
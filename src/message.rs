@@ -1,7 +1,7 @@
 use indexmap::IndexMap;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{CorrelationId, Example, ExternalDocumentation, MessageBinding, ReferenceOr, Schema, Tag};
+use crate::{de, de::PathError, AvroSchema, CorrelationId, Example, ExternalDocumentation, MessageBinding, MessageTrait, PayloadKind, ReferenceOr, Schema, SchemaData, SchemaFormat, SchemaKind, Tag};
 
 /// Describes a message received on a given channel and operation.
 ///
@@ -167,7 +167,7 @@ use crate::{CorrelationId, Example, ExternalDocumentation, MessageBinding, Refer
 ///   $ref: 'path/to/user-create.avsc/#UserCreate'
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-#[serde(rename_all = "camelCase")]
+#[serde(remote = "Self", rename_all = "camelCase")]
 pub struct Message {
     /// Unique string used to identify the message.
     /// The id MUST be unique among all messages described in the API.
@@ -232,15 +232,191 @@ pub struct Message {
     /// An array with examples of valid message objects.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub examples: Vec<Example>, // TODO try to parse better
+    /// A list of traits to apply to the message object. Traits MUST be merged
+    /// into the message object using the
+    /// [JSON Merge Patch](https://tools.ietf.org/html/rfc7386) algorithm in
+    /// the same order they are defined here. The resulting object MUST be a
+    /// valid [Message Object][crate::Message].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub traits: Vec<ReferenceOr<MessageTrait>>,
     /// This object MAY be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.6.0#specificationExtensions).
     #[serde(flatten)]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+impl Serialize for Message {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Message::serialize(self, serializer)
+    }
+}
+
+/// The payload is deserialized once the `schemaFormat` is known.
+impl<'de> Deserialize<'de> for Message {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = de::buffer(deserializer)?;
+        let payload = value.as_object_mut().and_then(|object| object.remove("payload"));
+        let Fields(mut message) = de::variant(value)?;
+        // The extensions of the message are read into its own `extensions`.
+        if let Some(schema) = message.same_channel_schema.as_mut() {
+            schema.schema_data.extensions.clear();
+        }
+        let format = SchemaFormat::of(message.schema_format.as_deref());
+        message.payload = match payload {
+            Some(payload) if message.schema_format.is_none() && !message.traits.is_empty() => {
+                Some(Payload::from_format(&format, payload.clone()).unwrap_or(Payload::Any(payload)))
+            }
+            Some(payload) => Some(Payload::from_format(&format, payload).map_err(|error| de::nested(error.prefixed("payload")))?),
+            None => None,
+        };
+        Ok(*message)
+    }
+}
+
+/// The fields of a [Message], deserialized by its derived implementation.
+/// The message is boxed to keep it out of the stack frames in between.
+struct Fields(Box<Message>);
+
+impl<'de> Deserialize<'de> for Fields {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Message::deserialize(deserializer).map(|message| Fields(Box::new(message)))
+    }
+}
+
+/// Deserialized within a [Message] according to its [SchemaFormat]: an
+/// Avro, Protocol Buffers or RAML payload as such, a payload of another
+/// format which is not a [Schema] as is, and a reference as a reference
+/// whatever the format.
+///
+/// The payload of a [Schema] format, or of a message without a
+/// `schemaFormat`, is deserialized as [Payload::OneOf] when it is a `oneOf`
+/// of which every variant has a `payload` field, and as a [Schema]
+/// otherwise. A payload which is not a schema is an error, unless the
+/// message has no `schemaFormat` but has traits: it is then kept as
+/// [Payload::Any], and read again under the `schemaFormat` of the traits by
+/// [`Message::with_traits_applied`].
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 #[serde(untagged)]
 pub enum Payload {
-    Schema(Schema),
+    Schema(ReferenceOr<Schema>),
+    /// The named variants of a message carrying one of several payloads.
+    OneOf {
+        #[serde(flatten)]
+        schema_data: SchemaData,
+        #[serde(rename = "oneOf")]
+        one_of: Vec<PayloadKind>,
+    },
+    Avro(AvroSchema),
+    /// The source of the Protocol Buffers definition of the payload.
+    Protobuf(String),
+    /// A RAML data type.
+    Raml(serde_json::Value),
     Any(serde_json::Value),
 }
+
+impl Payload {
+    /// Deserializes a payload of the given format.
+    pub(crate) fn from_format(format: &SchemaFormat, value: serde_json::Value) -> Result<Payload, PathError> {
+        if format.is_schema() || de::has_key(&value, "$ref") {
+            return de::from_value(value);
+        }
+        match format {
+            SchemaFormat::Avro => de::from_value(value).map(Payload::Avro),
+            SchemaFormat::Protobuf => de::from_value(value).map(Payload::Protobuf),
+            SchemaFormat::Raml => Ok(Payload::Raml(value)),
+            _ => Ok(Payload::Any(value)),
+        }
+    }
+
+    /// The payload as a schema: a [Payload::OneOf] is the `oneOf` of the
+    /// schemas its variants convert into, an Avro payload is converted by
+    /// [AvroSchema::to_schema], and the other payloads have none.
+    pub fn to_schema(&self) -> Option<ReferenceOr<Schema>> {
+        match self {
+            Payload::Schema(schema) => Some(schema.clone()),
+            Payload::OneOf { schema_data, one_of } => Some(ReferenceOr::Item(Schema {
+                schema_data: schema_data.clone(),
+                schema_kind: SchemaKind::OneOf {
+                    one_of: one_of.iter().cloned().map(|kind| ReferenceOr::Item(kind.into())).collect(),
+                },
+            })),
+            Payload::Avro(avro) => avro.to_schema().ok().map(ReferenceOr::Item),
+            Payload::Protobuf(_) | Payload::Raml(_) | Payload::Any(_) => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Payload {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = de::buffer(deserializer)?;
+        let variants = value.get("oneOf").and_then(serde_json::Value::as_array);
+        if variants.is_some_and(|variants| !variants.is_empty() && variants.iter().all(|variant| de::has_key(variant, "payload"))) {
+            let one_of = de::field(&mut value, "oneOf")?;
+            return de::variant(value).map(|schema_data| Payload::OneOf { schema_data, one_of });
+        }
+        de::variant(value).map(Payload::Schema)
+    }
+}
+
+#[test]
+fn test_payload_formats() {
+    let message = |format: &str, payload: serde_json::Value| -> Result<Message, serde_json::Error> { serde_json::from_value(serde_json::json!({ "schemaFormat": format, "payload": payload })) };
+    let avro = serde_json::json!({ "type": "record", "name": "User", "fields": [{ "name": "name", "type": "string" }, { "name": "emails", "type": { "type": "array", "items": "string" } }] });
+    let user = message("application/vnd.apache.avro+json;version=1.9.0", avro.clone()).unwrap();
+    assert!(matches!(&user.payload, Some(Payload::Avro(AvroSchema::Record(record))) if record.fields.len() == 2));
+    assert_eq!(serde_json::to_value(&user).unwrap()["payload"], avro);
+    let Some(ReferenceOr::Item(schema)) = user.payload.as_ref().and_then(Payload::to_schema) else {
+        panic!("expected the Avro record to convert into a schema");
+    };
+    assert_eq!(schema.validate(&serde_json::json!({ "name": "Ann", "emails": [] })), Ok(()));
+
+    let proto = "message User { string name = 1; }";
+    assert_eq!(
+        message("application/vnd.google.protobuf;version=3", proto.into()).unwrap().payload,
+        Some(Payload::Protobuf(proto.to_owned()))
+    );
+    let raml = serde_json::json!({ "type": "object", "properties": { "name?": "string" } });
+    assert_eq!(message("application/raml+yaml;version=1.0", raml.clone()).unwrap().payload, Some(Payload::Raml(raml)));
+    let openapi = message("application/vnd.oai.openapi;version=3.0.0", serde_json::json!({ "type": "string", "nullable": true })).unwrap();
+    assert!(matches!(openapi.payload, Some(Payload::Schema(ReferenceOr::Item(_)))));
+    let reference = message("application/vnd.apache.avro+yaml;version=1.9.0", serde_json::json!({ "$ref": "user.avsc" })).unwrap();
+    assert_eq!(reference.payload, Some(Payload::Schema(ReferenceOr::ref_("user.avsc"))));
+    assert_eq!(message("application/x-custom", 42.into()).unwrap().payload, Some(Payload::Any(42.into())));
+
+    let error = message(
+        "application/vnd.apache.avro;version=1.9.0",
+        serde_json::json!({ "type": "record", "name": "User", "fields": [{ "name": "id" }] }),
+    )
+    .unwrap_err();
+    assert_eq!(error.to_string(), "at `/payload/fields/0`: missing field `type`");
+
+    let error = serde_json::from_value::<Message>(serde_json::json!({ "payload": { "type": "record", "name": "User", "fields": [] } })).unwrap_err();
+    assert!(error.to_string().starts_with("at `/payload"), "{error}");
+}
+
+#[test]
+fn test_openapi_payload() {
+    let payload = serde_json::json!({
+        "type": "object",
+        "required": ["kind"],
+        "discriminator": { "propertyName": "kind" },
+        "properties": {
+            "kind": { "type": "string", "nullable": true, "enum": ["cat", null], "example": "cat" },
+            "size": { "type": "integer", "nullable": true, "enum": [1, 2, null], "minimum": 0, "exclusiveMinimum": true },
+            "weight": { "type": "number", "format": "float", "maximum": 10, "exclusiveMaximum": true, "readOnly": true },
+            "indoor": { "type": "boolean", "nullable": true, "enum": [true, null] }
+        },
+        "x-internal": true
+    });
+    let message: Message = serde_json::from_value(serde_json::json!({ "schemaFormat": "application/vnd.oai.openapi+json;version=3.0.0", "payload": payload })).unwrap();
+    let Some(Payload::Schema(ReferenceOr::Item(schema))) = &message.payload else {
+        panic!("expected the OpenAPI payload to be a schema, found {:?}", message.payload);
+    };
+    assert_eq!(schema.schema_data.extensions["x-internal"], serde_json::json!(true));
+    assert_eq!(schema.validate(&serde_json::json!({ "kind": null, "size": null, "weight": 9.5, "indoor": null })), Ok(()));
+    assert_eq!(schema.validate(&serde_json::json!({ "kind": "cat", "size": 2, "indoor": true })), Ok(()));
+    let errors = schema.validate(&serde_json::json!({ "kind": "dog", "size": 0, "weight": 10, "indoor": false })).unwrap_err();
+    let paths: Vec<_> = errors.iter().map(|error| error.instance_path.as_str()).collect();
+    assert_eq!(paths, ["/kind", "/size", "/size", "/weight", "/indoor"]);
+}
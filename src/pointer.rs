@@ -0,0 +1,554 @@
+use indexmap::IndexMap;
+
+use crate::{
+    resolver::unescape_pointer_token,
+    schema::{AdditionalProperties, Dependency, Items, Type},
+    AsyncAPI, Channel, ChannelBinding, Components, CorrelationId, Message, MessageBinding, MessageTrait, Operation, OperationBinding, OperationMessageType, OperationTrait, Parameter, Payload,
+    PayloadKind, ReferenceOr, Schema, SchemaKind, SecurityScheme, Server, ServerBinding, ServerVariable,
+};
+
+/// A typed node of a document, as returned by [`AsyncAPI::pointer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NodeRef<'a> {
+    AsyncAPI(&'a AsyncAPI),
+    Components(&'a Components),
+    /// The value of the `$ref` field of a Reference Object.
+    Reference(&'a str),
+    Server(&'a Server),
+    ServerVariable(&'a ServerVariable),
+    SecurityScheme(&'a SecurityScheme),
+    Channel(&'a Channel),
+    Parameter(&'a Parameter),
+    Operation(&'a Operation),
+    OperationTrait(&'a OperationTrait),
+    Message(&'a Message),
+    MessageTrait(&'a MessageTrait),
+    CorrelationId(&'a CorrelationId),
+    Schema(&'a Schema),
+    ServerBinding(&'a ServerBinding),
+    ChannelBinding(&'a ChannelBinding),
+    OperationBinding(&'a OperationBinding),
+    MessageBinding(&'a MessageBinding),
+}
+
+/// A mutable typed node of a document, as returned by
+/// [`AsyncAPI::pointer_mut`].
+#[derive(Debug, PartialEq)]
+pub enum NodeMut<'a> {
+    AsyncAPI(&'a mut AsyncAPI),
+    Components(&'a mut Components),
+    /// The value of the `$ref` field of a Reference Object.
+    Reference(&'a mut String),
+    Server(&'a mut Server),
+    ServerVariable(&'a mut ServerVariable),
+    SecurityScheme(&'a mut SecurityScheme),
+    Channel(&'a mut Channel),
+    Parameter(&'a mut Parameter),
+    Operation(&'a mut Operation),
+    OperationTrait(&'a mut OperationTrait),
+    Message(&'a mut Message),
+    MessageTrait(&'a mut MessageTrait),
+    CorrelationId(&'a mut CorrelationId),
+    Schema(&'a mut Schema),
+    ServerBinding(&'a mut ServerBinding),
+    ChannelBinding(&'a mut ChannelBinding),
+    OperationBinding(&'a mut OperationBinding),
+    MessageBinding(&'a mut MessageBinding),
+}
+
+impl AsyncAPI {
+    /// Returns the node at a [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901),
+    /// e.g. `/channels/user~1signedup/subscribe/message/payload`. The empty
+    /// pointer is the document itself.
+    ///
+    /// Only the objects modeled by this crate can be addressed, not their
+    /// scalar fields. References are not followed: a pointer to a Reference
+    /// Object returns [`NodeRef::Reference`] and a pointer into it returns
+    /// `None`.
+    ///
+    /// ```
+    /// # use asyncapi::{AsyncAPI, NodeRef};
+    /// let asyncapi: AsyncAPI = serde_yaml::from_str(r#"
+    /// asyncapi: 2.6.0
+    /// info:
+    ///   title: Account Service
+    ///   version: 1.0.0
+    /// channels:
+    ///   user/signedup:
+    ///     subscribe:
+    ///       operationId: userSignedUp
+    /// "#).unwrap();
+    /// match asyncapi.pointer("/channels/user~1signedup/subscribe") {
+    ///     Some(NodeRef::Operation(operation)) => assert_eq!(operation.operation_id.as_deref(), Some("userSignedUp")),
+    ///     other => panic!("unexpected node {other:?}"),
+    /// }
+    /// ```
+    pub fn pointer(&self, pointer: &str) -> Option<NodeRef<'_>> {
+        let mut tokens = tokens(pointer)?;
+        let mut node = NodeRef::AsyncAPI(self);
+        while let Some(token) = tokens.next() {
+            node = step(node, &token, &mut tokens)?;
+        }
+        Some(node)
+    }
+
+    /// Returns the node at a JSON Pointer, as [`AsyncAPI::pointer`], for
+    /// modification.
+    pub fn pointer_mut(&mut self, pointer: &str) -> Option<NodeMut<'_>> {
+        let mut tokens = tokens(pointer)?;
+        let mut node = NodeMut::AsyncAPI(self);
+        while let Some(token) = tokens.next() {
+            node = step_mut(node, &token, &mut tokens)?;
+        }
+        Some(node)
+    }
+}
+
+fn tokens(pointer: &str) -> Option<impl Iterator<Item = String> + '_> {
+    let rest = match pointer {
+        "" => None,
+        _ => Some(pointer.strip_prefix('/')?),
+    };
+    Some(rest.into_iter().flat_map(|rest| rest.split('/')).map(unescape_pointer_token))
+}
+
+/// Parses an array index, which has no leading zeros.
+fn index(token: &str) -> Option<usize> {
+    let index: usize = token.parse().ok()?;
+    (index.to_string() == token).then_some(index)
+}
+
+fn reference_or<'a, T>(item: &'a ReferenceOr<T>, node: impl FnOnce(&'a T) -> NodeRef<'a>) -> NodeRef<'a> {
+    match item {
+        ReferenceOr::Reference { reference } => NodeRef::Reference(reference),
+        ReferenceOr::Item(item) => node(item),
+    }
+}
+
+fn reference_or_mut<'a, T>(item: &'a mut ReferenceOr<T>, node: impl FnOnce(&'a mut T) -> NodeMut<'a>) -> NodeMut<'a> {
+    match item {
+        ReferenceOr::Reference { reference } => NodeMut::Reference(reference),
+        ReferenceOr::Item(item) => node(item),
+    }
+}
+
+fn entry<'a, T>(map: &'a IndexMap<String, ReferenceOr<T>>, key: Option<String>, node: impl FnOnce(&'a T) -> NodeRef<'a>) -> Option<NodeRef<'a>> {
+    Some(reference_or(map.get(&key?)?, node))
+}
+
+fn entry_mut<'a, T>(map: &'a mut IndexMap<String, ReferenceOr<T>>, key: Option<String>, node: impl FnOnce(&'a mut T) -> NodeMut<'a>) -> Option<NodeMut<'a>> {
+    Some(reference_or_mut(map.get_mut(&key?)?, node))
+}
+
+fn element<'a, T>(list: &'a [ReferenceOr<T>], token: Option<String>, node: impl FnOnce(&'a T) -> NodeRef<'a>) -> Option<NodeRef<'a>> {
+    Some(reference_or(list.get(index(&token?)?)?, node))
+}
+
+fn element_mut<'a, T>(list: &'a mut [ReferenceOr<T>], token: Option<String>, node: impl FnOnce(&'a mut T) -> NodeMut<'a>) -> Option<NodeMut<'a>> {
+    Some(reference_or_mut(list.get_mut(index(&token?)?)?, node))
+}
+
+/// Takes the `oneOf/{index}/payload` tokens addressing the payload of a
+/// [PayloadKind] and returns its index.
+fn payload_kind(one_of: &[PayloadKind], tokens: &mut impl Iterator<Item = String>) -> Option<usize> {
+    (tokens.next()? == "oneOf").then_some(())?;
+    let kind = index(&tokens.next()?).filter(|&kind| kind < one_of.len())?;
+    (tokens.next()? == "payload").then_some(kind)
+}
+
+/// Returns the child of `node` named by `token`, taking the next token from
+/// `tokens` when the child is the entry of a map or list.
+fn step<'a>(node: NodeRef<'a>, token: &str, tokens: &mut impl Iterator<Item = String>) -> Option<NodeRef<'a>> {
+    match node {
+        NodeRef::AsyncAPI(asyncapi) => match token {
+            "servers" => entry(&asyncapi.servers, tokens.next(), NodeRef::Server),
+            "channels" => asyncapi.channels.get(&tokens.next()?).map(NodeRef::Channel),
+            "components" => asyncapi.components.as_ref().map(NodeRef::Components),
+            _ => None,
+        },
+        NodeRef::Components(components) => {
+            let key = tokens.next();
+            match token {
+                "schemas" => entry(&components.schemas, key, NodeRef::Schema),
+                "messages" => entry(&components.messages, key, NodeRef::Message),
+                "securitySchemes" => entry(&components.security_schemes, key, NodeRef::SecurityScheme),
+                "parameters" => entry(&components.parameters, key, NodeRef::Parameter),
+                "correlationIds" => entry(&components.correlation_ids, key, NodeRef::CorrelationId),
+                "operationTraits" => entry(&components.operation_traits, key, NodeRef::OperationTrait),
+                "messageTraits" => entry(&components.message_traits, key, NodeRef::MessageTrait),
+                "servers" => entry(&components.servers, key, NodeRef::Server),
+                "serverVariables" => entry(&components.server_variables, key, NodeRef::ServerVariable),
+                "serverBindings" => entry(&components.server_bindings, key, NodeRef::ServerBinding),
+                "channels" => components.channels.get(&key?).map(NodeRef::Channel),
+                "channelBindings" => entry(&components.channel_bindings, key, NodeRef::ChannelBinding),
+                "operationBindings" => entry(&components.operation_bindings, key, NodeRef::OperationBinding),
+                "messageBindings" => entry(&components.message_bindings, key, NodeRef::MessageBinding),
+                _ => None,
+            }
+        }
+        NodeRef::Server(server) => match token {
+            "variables" => server.variables.get(&tokens.next()?).map(NodeRef::ServerVariable),
+            "bindings" => Some(reference_or(server.bindings.as_ref()?, NodeRef::ServerBinding)),
+            _ => None,
+        },
+        NodeRef::Channel(channel) => match token {
+            "parameters" => entry(&channel.parameters, tokens.next(), NodeRef::Parameter),
+            "bindings" => Some(reference_or(channel.bindings.as_ref()?, NodeRef::ChannelBinding)),
+            "subscribe" => channel.subscribe.as_ref().map(NodeRef::Operation),
+            "publish" => channel.publish.as_ref().map(NodeRef::Operation),
+            _ => None,
+        },
+        NodeRef::Parameter(parameter) => match token {
+            "schema" => Some(reference_or(parameter.schema.as_ref()?, NodeRef::Schema)),
+            _ => None,
+        },
+        NodeRef::Operation(operation) => match token {
+            "bindings" => Some(reference_or(operation.bindings.as_ref()?, NodeRef::OperationBinding)),
+            "traits" => element(&operation.traits, tokens.next(), NodeRef::OperationTrait),
+            "message" => match operation.message.as_ref()? {
+                OperationMessageType::Single(message) => Some(reference_or(message, NodeRef::Message)),
+                OperationMessageType::Map(messages) => entry(messages, tokens.next(), NodeRef::Message),
+                OperationMessageType::OneOf(messages) => {
+                    (tokens.next()? == "oneOf").then_some(())?;
+                    element(messages, tokens.next(), NodeRef::Message)
+                }
+            },
+            _ => None,
+        },
+        NodeRef::OperationTrait(operation_trait) => match token {
+            "bindings" => Some(reference_or(operation_trait.bindings.as_ref()?, NodeRef::OperationBinding)),
+            _ => None,
+        },
+        NodeRef::Message(message) => match token {
+            "headers" => Some(reference_or(message.headers.as_ref()?, NodeRef::Schema)),
+            "payload" => match message.payload.as_ref()? {
+                Payload::Schema(payload) => Some(reference_or(payload, NodeRef::Schema)),
+                Payload::OneOf { one_of, .. } => {
+                    let kind = payload_kind(one_of, tokens)?;
+                    Some(NodeRef::Schema(&one_of[kind].payload))
+                }
+                Payload::Avro(_) | Payload::Protobuf(_) | Payload::Raml(_) | Payload::Any(_) => None,
+            },
+            "correlationId" => Some(reference_or(message.correlation_id.as_ref()?, NodeRef::CorrelationId)),
+            "bindings" => Some(reference_or(message.bindings.as_ref()?, NodeRef::MessageBinding)),
+            "traits" => element(&message.traits, tokens.next(), NodeRef::MessageTrait),
+            _ => None,
+        },
+        NodeRef::MessageTrait(message_trait) => match token {
+            "headers" => Some(reference_or(message_trait.headers.as_ref()?, NodeRef::Schema)),
+            "correlationId" => Some(reference_or(message_trait.correlation_id.as_ref()?, NodeRef::CorrelationId)),
+            "bindings" => Some(reference_or(message_trait.bindings.as_ref()?, NodeRef::MessageBinding)),
+            _ => None,
+        },
+        NodeRef::Schema(schema) => {
+            let (object, array) = match &schema.schema_kind {
+                SchemaKind::Type(Type::Object(object)) => (
+                    Some((
+                        &object.properties,
+                        &object.pattern_properties,
+                        &object.additional_properties,
+                        &object.property_names,
+                        &object.dependencies,
+                    )),
+                    None,
+                ),
+                SchemaKind::Type(Type::Array(array)) => (None, Some((&array.items, &array.additional_items, &array.contains))),
+                SchemaKind::Any(any) => (
+                    Some((&any.properties, &any.pattern_properties, &any.additional_properties, &any.property_names, &any.dependencies)),
+                    Some((&any.items, &any.additional_items, &any.contains)),
+                ),
+                SchemaKind::OneOf { one_of } if token == "oneOf" => return element(one_of, tokens.next(), NodeRef::Schema),
+                SchemaKind::AllOf { all_of } if token == "allOf" => return element(all_of, tokens.next(), NodeRef::Schema),
+                SchemaKind::AnyOf { any_of } if token == "anyOf" => return element(any_of, tokens.next(), NodeRef::Schema),
+                _ => (None, None),
+            };
+            let data = &schema.schema_data;
+            let subschema = match token {
+                "allOf" => return element(&data.all_of, tokens.next(), NodeRef::Schema),
+                "anyOf" => return element(&data.any_of, tokens.next(), NodeRef::Schema),
+                "oneOf" => return element(&data.one_of, tokens.next(), NodeRef::Schema),
+                "definitions" => data.definitions.get(&tokens.next()?),
+                "not" => data.not.as_ref(),
+                "if" => data.if_schema.as_ref(),
+                "then" => data.then_schema.as_ref(),
+                "else" => data.else_schema.as_ref(),
+                "properties" => object?.0.get(&tokens.next()?),
+                "patternProperties" => object?.1.get(&tokens.next()?),
+                "additionalProperties" => match object?.2.as_ref()? {
+                    AdditionalProperties::Schema(schema) => return Some(reference_or(schema, NodeRef::Schema)),
+                    AdditionalProperties::Any(_) => None,
+                },
+                "propertyNames" => object?.3.as_ref(),
+                "dependencies" => match object?.4.get(&tokens.next()?)? {
+                    Dependency::Schema(schema) => Some(schema),
+                    Dependency::Properties(_) => None,
+                },
+                "items" => match array?.0.as_ref()? {
+                    Items::Schema(schema) => Some(schema),
+                    Items::Tuple(schemas) => return element(schemas, tokens.next(), |schema| NodeRef::Schema(schema)),
+                },
+                "additionalItems" => match array?.1.as_ref()? {
+                    AdditionalProperties::Schema(schema) => return Some(reference_or(schema, NodeRef::Schema)),
+                    AdditionalProperties::Any(_) => None,
+                },
+                "contains" => array?.2.as_ref(),
+                _ => None,
+            };
+            Some(reference_or(subschema?, |schema| NodeRef::Schema(schema)))
+        }
+        NodeRef::ChannelBinding(binding) => match (token, tokens.next()?.as_str()) {
+            ("ws", "query") => binding.ws.as_ref()?.query.as_ref().map(NodeRef::Schema),
+            ("ws", "headers") => binding.ws.as_ref()?.headers.as_ref().map(NodeRef::Schema),
+            _ => None,
+        },
+        NodeRef::OperationBinding(binding) => match (token, tokens.next()?.as_str()) {
+            ("http", "query") => binding.http.as_ref()?.query.as_ref().map(NodeRef::Schema),
+            ("kafka", "groupId") => binding.kafka.as_ref()?.group_id.as_ref().map(NodeRef::Schema),
+            ("kafka", "clientId") => binding.kafka.as_ref()?.client_id.as_ref().map(NodeRef::Schema),
+            _ => None,
+        },
+        NodeRef::MessageBinding(binding) => match (token, tokens.next()?.as_str()) {
+            ("http", "headers") => binding.http.as_ref()?.headers.as_ref().map(NodeRef::Schema),
+            ("ws", "query") => binding.ws.as_ref()?.query.as_ref().map(NodeRef::Schema),
+            ("ws", "headers") => binding.ws.as_ref()?.headers.as_ref().map(NodeRef::Schema),
+            ("kafka", "key") => binding.kafka.as_ref()?.key.as_ref().map(NodeRef::Schema),
+            ("anypointmq", "headers") => binding.anypointmq.as_ref()?.headers.as_ref().map(NodeRef::Schema),
+            _ => None,
+        },
+        NodeRef::Reference(_) | NodeRef::ServerVariable(_) | NodeRef::SecurityScheme(_) | NodeRef::CorrelationId(_) | NodeRef::ServerBinding(_) => None,
+    }
+}
+
+fn step_mut<'a>(node: NodeMut<'a>, token: &str, tokens: &mut impl Iterator<Item = String>) -> Option<NodeMut<'a>> {
+    match node {
+        NodeMut::AsyncAPI(asyncapi) => match token {
+            "servers" => entry_mut(&mut asyncapi.servers, tokens.next(), NodeMut::Server),
+            "channels" => asyncapi.channels.get_mut(&tokens.next()?).map(NodeMut::Channel),
+            "components" => asyncapi.components.as_mut().map(NodeMut::Components),
+            _ => None,
+        },
+        NodeMut::Components(components) => {
+            let key = tokens.next();
+            match token {
+                "schemas" => entry_mut(&mut components.schemas, key, NodeMut::Schema),
+                "messages" => entry_mut(&mut components.messages, key, NodeMut::Message),
+                "securitySchemes" => entry_mut(&mut components.security_schemes, key, NodeMut::SecurityScheme),
+                "parameters" => entry_mut(&mut components.parameters, key, NodeMut::Parameter),
+                "correlationIds" => entry_mut(&mut components.correlation_ids, key, NodeMut::CorrelationId),
+                "operationTraits" => entry_mut(&mut components.operation_traits, key, NodeMut::OperationTrait),
+                "messageTraits" => entry_mut(&mut components.message_traits, key, NodeMut::MessageTrait),
+                "servers" => entry_mut(&mut components.servers, key, NodeMut::Server),
+                "serverVariables" => entry_mut(&mut components.server_variables, key, NodeMut::ServerVariable),
+                "serverBindings" => entry_mut(&mut components.server_bindings, key, NodeMut::ServerBinding),
+                "channels" => components.channels.get_mut(&key?).map(NodeMut::Channel),
+                "channelBindings" => entry_mut(&mut components.channel_bindings, key, NodeMut::ChannelBinding),
+                "operationBindings" => entry_mut(&mut components.operation_bindings, key, NodeMut::OperationBinding),
+                "messageBindings" => entry_mut(&mut components.message_bindings, key, NodeMut::MessageBinding),
+                _ => None,
+            }
+        }
+        NodeMut::Server(server) => match token {
+            "variables" => server.variables.get_mut(&tokens.next()?).map(NodeMut::ServerVariable),
+            "bindings" => Some(reference_or_mut(server.bindings.as_mut()?, NodeMut::ServerBinding)),
+            _ => None,
+        },
+        NodeMut::Channel(channel) => match token {
+            "parameters" => entry_mut(&mut channel.parameters, tokens.next(), NodeMut::Parameter),
+            "bindings" => Some(reference_or_mut(channel.bindings.as_mut()?, NodeMut::ChannelBinding)),
+            "subscribe" => channel.subscribe.as_mut().map(NodeMut::Operation),
+            "publish" => channel.publish.as_mut().map(NodeMut::Operation),
+            _ => None,
+        },
+        NodeMut::Parameter(parameter) => match token {
+            "schema" => Some(reference_or_mut(parameter.schema.as_mut()?, NodeMut::Schema)),
+            _ => None,
+        },
+        NodeMut::Operation(operation) => match token {
+            "bindings" => Some(reference_or_mut(operation.bindings.as_mut()?, NodeMut::OperationBinding)),
+            "traits" => element_mut(&mut operation.traits, tokens.next(), NodeMut::OperationTrait),
+            "message" => match operation.message.as_mut()? {
+                OperationMessageType::Single(message) => Some(reference_or_mut(message, NodeMut::Message)),
+                OperationMessageType::Map(messages) => entry_mut(messages, tokens.next(), NodeMut::Message),
+                OperationMessageType::OneOf(messages) => {
+                    (tokens.next()? == "oneOf").then_some(())?;
+                    element_mut(messages, tokens.next(), NodeMut::Message)
+                }
+            },
+            _ => None,
+        },
+        NodeMut::OperationTrait(operation_trait) => match token {
+            "bindings" => Some(reference_or_mut(operation_trait.bindings.as_mut()?, NodeMut::OperationBinding)),
+            _ => None,
+        },
+        NodeMut::Message(message) => match token {
+            "headers" => Some(reference_or_mut(message.headers.as_mut()?, NodeMut::Schema)),
+            "payload" => match message.payload.as_mut()? {
+                Payload::Schema(payload) => Some(reference_or_mut(payload, NodeMut::Schema)),
+                Payload::OneOf { one_of, .. } => {
+                    let kind = payload_kind(one_of, tokens)?;
+                    Some(NodeMut::Schema(&mut one_of[kind].payload))
+                }
+                Payload::Avro(_) | Payload::Protobuf(_) | Payload::Raml(_) | Payload::Any(_) => None,
+            },
+            "correlationId" => Some(reference_or_mut(message.correlation_id.as_mut()?, NodeMut::CorrelationId)),
+            "bindings" => Some(reference_or_mut(message.bindings.as_mut()?, NodeMut::MessageBinding)),
+            "traits" => element_mut(&mut message.traits, tokens.next(), NodeMut::MessageTrait),
+            _ => None,
+        },
+        NodeMut::MessageTrait(message_trait) => match token {
+            "headers" => Some(reference_or_mut(message_trait.headers.as_mut()?, NodeMut::Schema)),
+            "correlationId" => Some(reference_or_mut(message_trait.correlation_id.as_mut()?, NodeMut::CorrelationId)),
+            "bindings" => Some(reference_or_mut(message_trait.bindings.as_mut()?, NodeMut::MessageBinding)),
+            _ => None,
+        },
+        NodeMut::Schema(schema) => {
+            let (object, array) = match &mut schema.schema_kind {
+                SchemaKind::Type(Type::Object(object)) => (
+                    Some((
+                        &mut object.properties,
+                        &mut object.pattern_properties,
+                        &mut object.additional_properties,
+                        &mut object.property_names,
+                        &mut object.dependencies,
+                    )),
+                    None,
+                ),
+                SchemaKind::Type(Type::Array(array)) => (None, Some((&mut array.items, &mut array.additional_items, &mut array.contains))),
+                SchemaKind::Any(any) => (
+                    Some((
+                        &mut any.properties,
+                        &mut any.pattern_properties,
+                        &mut any.additional_properties,
+                        &mut any.property_names,
+                        &mut any.dependencies,
+                    )),
+                    Some((&mut any.items, &mut any.additional_items, &mut any.contains)),
+                ),
+                SchemaKind::OneOf { one_of } if token == "oneOf" => return element_mut(one_of, tokens.next(), NodeMut::Schema),
+                SchemaKind::AllOf { all_of } if token == "allOf" => return element_mut(all_of, tokens.next(), NodeMut::Schema),
+                SchemaKind::AnyOf { any_of } if token == "anyOf" => return element_mut(any_of, tokens.next(), NodeMut::Schema),
+                _ => (None, None),
+            };
+            let data = &mut schema.schema_data;
+            let subschema = match token {
+                "allOf" => return element_mut(&mut data.all_of, tokens.next(), NodeMut::Schema),
+                "anyOf" => return element_mut(&mut data.any_of, tokens.next(), NodeMut::Schema),
+                "oneOf" => return element_mut(&mut data.one_of, tokens.next(), NodeMut::Schema),
+                "definitions" => data.definitions.get_mut(&tokens.next()?),
+                "not" => data.not.as_mut(),
+                "if" => data.if_schema.as_mut(),
+                "then" => data.then_schema.as_mut(),
+                "else" => data.else_schema.as_mut(),
+                "properties" => object?.0.get_mut(&tokens.next()?),
+                "patternProperties" => object?.1.get_mut(&tokens.next()?),
+                "additionalProperties" => match object?.2.as_mut()? {
+                    AdditionalProperties::Schema(schema) => return Some(reference_or_mut(schema, NodeMut::Schema)),
+                    AdditionalProperties::Any(_) => None,
+                },
+                "propertyNames" => object?.3.as_mut(),
+                "dependencies" => match object?.4.get_mut(&tokens.next()?)? {
+                    Dependency::Schema(schema) => Some(schema),
+                    Dependency::Properties(_) => None,
+                },
+                "items" => match array?.0.as_mut()? {
+                    Items::Schema(schema) => Some(schema),
+                    Items::Tuple(schemas) => return element_mut(schemas, tokens.next(), |schema| NodeMut::Schema(schema)),
+                },
+                "additionalItems" => match array?.1.as_mut()? {
+                    AdditionalProperties::Schema(schema) => return Some(reference_or_mut(schema, NodeMut::Schema)),
+                    AdditionalProperties::Any(_) => None,
+                },
+                "contains" => array?.2.as_mut(),
+                _ => None,
+            };
+            Some(reference_or_mut(subschema?, |schema| NodeMut::Schema(schema)))
+        }
+        NodeMut::ChannelBinding(binding) => match (token, tokens.next()?.as_str()) {
+            ("ws", "query") => binding.ws.as_mut()?.query.as_mut().map(NodeMut::Schema),
+            ("ws", "headers") => binding.ws.as_mut()?.headers.as_mut().map(NodeMut::Schema),
+            _ => None,
+        },
+        NodeMut::OperationBinding(binding) => match (token, tokens.next()?.as_str()) {
+            ("http", "query") => binding.http.as_mut()?.query.as_mut().map(NodeMut::Schema),
+            ("kafka", "groupId") => binding.kafka.as_mut()?.group_id.as_mut().map(NodeMut::Schema),
+            ("kafka", "clientId") => binding.kafka.as_mut()?.client_id.as_mut().map(NodeMut::Schema),
+            _ => None,
+        },
+        NodeMut::MessageBinding(binding) => match (token, tokens.next()?.as_str()) {
+            ("http", "headers") => binding.http.as_mut()?.headers.as_mut().map(NodeMut::Schema),
+            ("ws", "query") => binding.ws.as_mut()?.query.as_mut().map(NodeMut::Schema),
+            ("ws", "headers") => binding.ws.as_mut()?.headers.as_mut().map(NodeMut::Schema),
+            ("kafka", "key") => binding.kafka.as_mut()?.key.as_mut().map(NodeMut::Schema),
+            ("anypointmq", "headers") => binding.anypointmq.as_mut()?.headers.as_mut().map(NodeMut::Schema),
+            _ => None,
+        },
+        NodeMut::Reference(_) | NodeMut::ServerVariable(_) | NodeMut::SecurityScheme(_) | NodeMut::CorrelationId(_) | NodeMut::ServerBinding(_) => None,
+    }
+}
+
+#[test]
+fn test_pointer() {
+    let example = r##"
+    asyncapi: 2.6.0
+    info:
+      title: Pointer
+      version: 1.0.0
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            $ref: "#/components/messages/userSignedUp"
+        publish:
+          message:
+            oneOf:
+              - $ref: "#/components/messages/userSignedUp"
+              - payload:
+                  type: string
+    components:
+      messages:
+        userSignedUp:
+          payload:
+            type: object
+            properties:
+              a~b:
+                type: string
+              tags:
+                type: array
+                items:
+                  $ref: "#/components/schemas/tag"
+          traits:
+            - headers:
+                type: object
+    "##;
+    let mut asyncapi: AsyncAPI = serde_yaml::from_str(example).expect("Could not deserialize pointer example");
+
+    assert!(matches!(asyncapi.pointer(""), Some(NodeRef::AsyncAPI(_))));
+    assert_eq!(
+        asyncapi.pointer("/channels/user~1signedup/subscribe/message"),
+        Some(NodeRef::Reference("#/components/messages/userSignedUp"))
+    );
+    assert_eq!(asyncapi.pointer("/channels/user~1signedup/subscribe/message/payload"), None);
+    assert_eq!(
+        asyncapi.pointer("/channels/user~1signedup/publish/message/oneOf/0"),
+        Some(NodeRef::Reference("#/components/messages/userSignedUp"))
+    );
+    assert!(matches!(asyncapi.pointer_mut("/channels/user~1signedup/publish/message/oneOf/1/payload"), Some(NodeMut::Schema(_))));
+    assert_eq!(asyncapi.pointer("/channels/user~1signedup/publish/message/0"), None);
+    assert!(matches!(asyncapi.pointer("/components/messages/userSignedUp/payload/properties/a~0b"), Some(NodeRef::Schema(_))));
+    assert!(matches!(asyncapi.pointer("/components/messages/userSignedUp/traits/0/headers"), Some(NodeRef::Schema(_))));
+    assert_eq!(asyncapi.pointer("/components/messages/userSignedUp/traits/00"), None);
+    assert_eq!(
+        asyncapi.pointer("/components/messages/userSignedUp/payload/properties/tags/items"),
+        Some(NodeRef::Reference("#/components/schemas/tag"))
+    );
+    assert_eq!(asyncapi.pointer("channels"), None);
+
+    match asyncapi.pointer_mut("/components/messages/userSignedUp/payload/properties/tags/items") {
+        Some(NodeMut::Reference(reference)) => *reference = "#/components/schemas/Tag".to_owned(),
+        other => panic!("unexpected node {other:?}"),
+    }
+    assert_eq!(
+        asyncapi.pointer("/components/messages/userSignedUp/payload/properties/tags/items"),
+        Some(NodeRef::Reference("#/components/schemas/Tag"))
+    );
+}
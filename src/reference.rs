@@ -1,6 +1,9 @@
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+use crate::de;
+
+/// Deserialized as a reference when the object has a `$ref` field.
+#[derive(Serialize, Clone, Debug, PartialEq)]
 #[serde(untagged)]
 pub enum ReferenceOr<T> {
     /// A simple object to allow referencing other components in the specification,
@@ -24,6 +27,18 @@ pub enum ReferenceOr<T> {
     Item(T),
 }
 
+impl<'de, T: DeserializeOwned> Deserialize<'de> for ReferenceOr<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = de::buffer(deserializer)?;
+        match de::has_key(&value, "$ref") {
+            true => Ok(ReferenceOr::Reference {
+                reference: de::field(&mut value, "$ref")?,
+            }),
+            false => de::variant(value).map(ReferenceOr::Item),
+        }
+    }
+}
+
 impl<T> ReferenceOr<T> {
     pub fn ref_(r: &str) -> Self {
         ReferenceOr::Reference { reference: r.to_owned() }
@@ -33,6 +48,12 @@ impl<T> ReferenceOr<T> {
     }
 }
 
+impl<T> From<T> for ReferenceOr<T> {
+    fn from(item: T) -> Self {
+        ReferenceOr::Item(item)
+    }
+}
+
 impl<T> ReferenceOr<Box<T>> {
     pub fn unbox(self) -> ReferenceOr<T> {
         match self {
@@ -0,0 +1,275 @@
+use std::fmt;
+
+use indexmap::IndexMap;
+
+use crate::{
+    AsyncAPI, ChannelBinding, Components, CorrelationId, Message, MessageBinding, MessageTrait, OperationBinding, OperationTrait, Parameter, ReferenceOr, Schema, SecurityScheme, Server,
+    ServerBinding, ServerVariable,
+};
+
+/// An object that can be stored in one of the maps of the
+/// [Components Object][crate::Components] and therefore be the target of a
+/// local reference such as `#/components/messages/userSignedUp`.
+pub trait Component: Sized {
+    /// The name of the field of the [Components Object][crate::Components]
+    /// holding objects of this type, e.g. `messages`.
+    const KIND: &'static str;
+
+    /// Returns the map of the [Components Object][crate::Components]
+    /// holding objects of this type.
+    fn component_map(components: &Components) -> &IndexMap<String, ReferenceOr<Self>>;
+
+    /// Returns the map of the [Components Object][crate::Components]
+    /// holding objects of this type for modification.
+    fn component_map_mut(components: &mut Components) -> &mut IndexMap<String, ReferenceOr<Self>>;
+}
+
+macro_rules! impl_component {
+    ($ty:ty, $kind:literal, $field:ident) => {
+        impl Component for $ty {
+            const KIND: &'static str = $kind;
+
+            fn component_map(components: &Components) -> &IndexMap<String, ReferenceOr<Self>> {
+                &components.$field
+            }
+
+            fn component_map_mut(components: &mut Components) -> &mut IndexMap<String, ReferenceOr<Self>> {
+                &mut components.$field
+            }
+        }
+    };
+}
+
+impl_component!(Schema, "schemas", schemas);
+impl_component!(Message, "messages", messages);
+impl_component!(SecurityScheme, "securitySchemes", security_schemes);
+impl_component!(Parameter, "parameters", parameters);
+impl_component!(CorrelationId, "correlationIds", correlation_ids);
+impl_component!(OperationTrait, "operationTraits", operation_traits);
+impl_component!(MessageTrait, "messageTraits", message_traits);
+impl_component!(Server, "servers", servers);
+impl_component!(ServerVariable, "serverVariables", server_variables);
+impl_component!(ServerBinding, "serverBindings", server_bindings);
+impl_component!(ChannelBinding, "channelBindings", channel_bindings);
+impl_component!(OperationBinding, "operationBindings", operation_bindings);
+impl_component!(MessageBinding, "messageBindings", message_bindings);
+
+/// The error returned when a [Reference Object][crate::ReferenceOr] can not
+/// be resolved against the document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolveError {
+    /// The reference points outside of the current document.
+    External(String),
+    /// The reference is not a pointer of the form `#/components/{kind}/{name}`.
+    InvalidPointer(String),
+    /// The reference points to a component map holding objects of a different type.
+    WrongKind { reference: String, expected: &'static str },
+    /// The reference points to a component which does not exist.
+    Dangling(String),
+    /// Following the reference leads back to a reference already visited.
+    /// Holds the chain of references in the order they were followed.
+    Cycle(Vec<String>),
+    /// Merging the traits of an object into it yields an invalid object,
+    /// e.g. a payload which does not match a `schemaFormat` set by a trait.
+    Merge(String),
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResolveError::External(reference) => write!(f, "external reference `{reference}` can not be resolved locally"),
+            ResolveError::InvalidPointer(reference) => write!(f, "reference `{reference}` is not a pointer to a component"),
+            ResolveError::WrongKind { reference, expected } => write!(f, "reference `{reference}` does not point to `#/components/{expected}`"),
+            ResolveError::Dangling(reference) => write!(f, "reference `{reference}` points to a missing component"),
+            ResolveError::Cycle(chain) => write!(f, "reference cycle detected: {}", chain.join(" -> ")),
+            ResolveError::Merge(error) => write!(f, "applying traits yields an invalid object: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
+/// Decodes a single JSON Pointer token as found in a URI fragment, undoing
+/// both the percent-encoding and the `~1`/`~0` escaping.
+pub(crate) fn decode_pointer_token(token: &str) -> String {
+    let bytes = token.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let (Some(high), Some(low)) = ((bytes[i + 1] as char).to_digit(16), (bytes[i + 2] as char).to_digit(16)) {
+                decoded.push((high * 16 + low) as u8);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    unescape_pointer_token(&String::from_utf8_lossy(&decoded))
+}
+
+/// Undoes the `~1`/`~0` escaping of a single JSON Pointer token.
+pub(crate) fn unescape_pointer_token(token: &str) -> String {
+    token.replace("~1", "/").replace("~0", "~")
+}
+
+/// Escapes a single JSON Pointer token, the inverse of
+/// [`unescape_pointer_token`].
+pub(crate) fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Splits a local reference of the form `#/components/{kind}/{name}` into
+/// its decoded `kind` and `name`.
+pub(crate) fn parse_component_pointer(reference: &str) -> Result<(String, String), ResolveError> {
+    let pointer = match reference.strip_prefix('#') {
+        Some(pointer) => pointer,
+        None => return Err(ResolveError::External(reference.to_owned())),
+    };
+    let tokens: Vec<&str> = pointer.split('/').collect();
+    match tokens.as_slice() {
+        ["", "components", kind, name] => Ok((decode_pointer_token(kind), decode_pointer_token(name))),
+        _ => Err(ResolveError::InvalidPointer(reference.to_owned())),
+    }
+}
+
+impl AsyncAPI {
+    /// Resolves a [Reference Object][crate::ReferenceOr] against the
+    /// [Components Object][crate::Components] of this document.
+    ///
+    /// Inline items are returned as is. References are followed, possibly
+    /// through several other references, until an inline item is found.
+    ///
+    /// ```
+    /// # use asyncapi::{AsyncAPI, Message, ReferenceOr};
+    /// # fn example(asyncapi: &AsyncAPI) -> Result<(), asyncapi::ResolveError> {
+    /// let message: &Message = asyncapi.resolve(&ReferenceOr::ref_("#/components/messages/userSignedUp"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resolve<'a, T: Component>(&'a self, item: &'a ReferenceOr<T>) -> Result<&'a T, ResolveError> {
+        match item {
+            ReferenceOr::Item(item) => Ok(item),
+            ReferenceOr::Reference { reference } => self.resolve_reference(reference),
+        }
+    }
+
+    /// Resolves a reference string such as `#/components/messages/userSignedUp`
+    /// to the component it points to.
+    pub fn resolve_reference<T: Component>(&self, reference: &str) -> Result<&T, ResolveError> {
+        resolve_in(self.components.as_ref(), reference)
+    }
+
+    /// Resolves a [Schema Object][crate::Schema] which may be a reference
+    /// to `#/components/schemas`.
+    pub fn resolve_schema<'a>(&'a self, schema: &'a ReferenceOr<Schema>) -> Result<&'a Schema, ResolveError> {
+        self.resolve(schema)
+    }
+
+    /// Resolves a boxed [Schema Object][crate::Schema], as found in object
+    /// properties and array items.
+    pub fn resolve_boxed_schema<'a>(&'a self, schema: &'a ReferenceOr<Box<Schema>>) -> Result<&'a Schema, ResolveError> {
+        match schema {
+            ReferenceOr::Item(schema) => Ok(schema),
+            ReferenceOr::Reference { reference } => self.resolve_reference(reference),
+        }
+    }
+}
+
+impl Components {
+    /// Resolves a [Reference Object][crate::ReferenceOr] against this
+    /// [Components Object][crate::Components].
+    ///
+    /// See [`AsyncAPI::resolve`].
+    pub fn resolve<'a, T: Component>(&'a self, item: &'a ReferenceOr<T>) -> Result<&'a T, ResolveError> {
+        match item {
+            ReferenceOr::Item(item) => Ok(item),
+            ReferenceOr::Reference { reference } => self.resolve_reference(reference),
+        }
+    }
+
+    /// Resolves a reference string such as `#/components/messages/userSignedUp`
+    /// to the component it points to.
+    pub fn resolve_reference<T: Component>(&self, reference: &str) -> Result<&T, ResolveError> {
+        resolve_in(Some(self), reference)
+    }
+}
+
+fn resolve_in<'a, T: Component>(components: Option<&'a Components>, reference: &str) -> Result<&'a T, ResolveError> {
+    let mut chain: Vec<String> = Vec::new();
+    let mut reference = reference;
+    loop {
+        if chain.iter().any(|visited| visited == reference) {
+            chain.push(reference.to_owned());
+            return Err(ResolveError::Cycle(chain));
+        }
+        chain.push(reference.to_owned());
+        match lookup_component::<T>(components, reference)? {
+            ReferenceOr::Item(item) => return Ok(item),
+            ReferenceOr::Reference { reference: next } => reference = next,
+        }
+    }
+}
+
+fn lookup_component<'a, T: Component>(components: Option<&'a Components>, reference: &str) -> Result<&'a ReferenceOr<T>, ResolveError> {
+    let (kind, name) = parse_component_pointer(reference)?;
+    if kind != T::KIND {
+        return Err(ResolveError::WrongKind {
+            reference: reference.to_owned(),
+            expected: T::KIND,
+        });
+    }
+    components
+        .and_then(|components| T::component_map(components).get(&name))
+        .ok_or_else(|| ResolveError::Dangling(reference.to_owned()))
+}
+
+#[test]
+fn test_resolve_references() {
+    let example = r##"
+    asyncapi: 2.6.0
+    info:
+      title: Resolver
+      version: 1.0.0
+    channels: {}
+    components:
+      messages:
+        userSignedUp:
+          name: UserSignedUp
+        alias:
+          $ref: "#/components/messages/userSignedUp"
+        loopA:
+          $ref: "#/components/messages/loopB"
+        loopB:
+          $ref: "#/components/messages/loopA"
+    "##;
+    let asyncapi: AsyncAPI = serde_yaml::from_str(example).expect("Could not deserialize resolver example");
+
+    let alias = ReferenceOr::ref_("#/components/messages/alias");
+    let message: &Message = asyncapi.resolve(&alias).unwrap();
+    assert_eq!(message.name.as_deref(), Some("UserSignedUp"));
+
+    assert_eq!(
+        asyncapi.resolve::<Message>(&ReferenceOr::ref_("#/components/messages/missing")),
+        Err(ResolveError::Dangling("#/components/messages/missing".to_string()))
+    );
+    assert!(matches!(
+        asyncapi.resolve::<Schema>(&ReferenceOr::ref_("#/components/messages/alias")),
+        Err(ResolveError::WrongKind { expected: "schemas", .. })
+    ));
+    assert_eq!(
+        asyncapi.resolve::<Message>(&ReferenceOr::ref_("#/components/messages/loopA")),
+        Err(ResolveError::Cycle(vec![
+            "#/components/messages/loopA".to_string(),
+            "#/components/messages/loopB".to_string(),
+            "#/components/messages/loopA".to_string(),
+        ]))
+    );
+    assert_eq!(decode_pointer_token("user%2Fsigned~1up%20"), "user/signed/up ");
+    assert_eq!(decode_pointer_token("%aé"), "%aé");
+    assert_eq!(
+        asyncapi.resolve::<Schema>(&ReferenceOr::ref_("#/components/schemas/%aé")),
+        Err(ResolveError::Dangling("#/components/schemas/%aé".to_string()))
+    );
+}
@@ -4,7 +4,6 @@ use serde::{Deserialize, Serialize};
 
 
 use std::{clone::Clone};
-use vek::*;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -34,21 +33,144 @@ pub struct SchemaData {
     /// it MUST be in the `required` property list. When used, the value MUST be
     ///  the name of this schema or any schema that inherits it. See
     /// [Composition and Inheritance](https://www.asyncapi.com/docs/specifications/v2.6.0#schemaComposition)
-    /// for more details.
+    /// for more details. The OpenAPI form, an object with a `propertyName`
+    /// and a `mapping`, is accepted as well.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub discriminator: Option<String>,
+    pub discriminator: Option<Box<Discriminator>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default: Option<serde_json::Value>,
+    #[serde(rename = "$id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The dialect of the schema, e.g. `http://json-schema.org/draft-07/schema#`.
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    #[serde(rename = "$comment", skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub definitions: IndexMap<String, ReferenceOr<Box<Schema>>>,
+    /// The only valid value. Unlike for the other optional keywords, an
+    /// explicit `null` is kept as `Some(Value::Null)`.
+    #[serde(rename = "const", default, deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub constant: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not: Option<ReferenceOr<Box<Schema>>>,
+    #[serde(rename = "if", skip_serializing_if = "Option::is_none")]
+    pub if_schema: Option<ReferenceOr<Box<Schema>>>,
+    #[serde(rename = "then", skip_serializing_if = "Option::is_none")]
+    pub then_schema: Option<ReferenceOr<Box<Schema>>>,
+    #[serde(rename = "else", skip_serializing_if = "Option::is_none")]
+    pub else_schema: Option<ReferenceOr<Box<Schema>>>,
+    /// The `allOf` of a schema whose [SchemaKind] is a [Type] or another
+    /// composition, which an instance must match as well.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub all_of: Vec<ReferenceOr<Schema>>,
+    /// The `anyOf` of a schema whose [SchemaKind] is a [Type] or another
+    /// composition, which an instance must match as well.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub any_of: Vec<ReferenceOr<Schema>>,
+    /// The `oneOf` of a schema whose [SchemaKind] is a [Type], which an
+    /// instance must match as well.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub one_of: Vec<ReferenceOr<Schema>>,
+    /// The schema this one is, in the places which take a schema rather than
+    /// a [Reference Object][crate::ReferenceOr], e.g. the fields of bindings.
+    #[serde(rename = "$ref", skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// The encoding of the content of a string, e.g. `base64`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_encoding: Option<String>,
+    /// The media type of the content of a string, e.g. `application/json`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_media_type: Option<String>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.6.0#specificationExtensions).
+    /// Only the fields starting with `x-` are kept.
+    #[serde(flatten, deserialize_with = "extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Keeps the fields of a schema which are extensions, the others being
+/// keywords of its [SchemaKind].
+fn extensions<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<IndexMap<String, serde_json::Value>, D::Error> {
+    let fields = IndexMap::<String, serde_json::Value>::deserialize(deserializer)?;
+    Ok(fields.into_iter().filter(|(key, _)| key.starts_with("x-")).collect())
+}
+
+pub(crate) fn present<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<serde_json::Value>, D::Error> {
+    serde_json::Value::deserialize(deserializer).map(Some)
+}
+
+/// A Schema Object, or one of the boolean schemas `true` and `false`, which
+/// are kept as [SchemaKind::Bool].
+#[derive(Debug, Clone, PartialEq)]
 pub struct Schema {
-    #[serde(flatten)]
     pub schema_data: SchemaData,
-    #[serde(flatten)]
     pub schema_kind: SchemaKind,
 }
 
+impl Schema {
+    /// The boolean schema `true`, which every instance matches, or `false`,
+    /// which no instance matches.
+    pub fn boolean(value: bool) -> Schema {
+        Schema {
+            schema_data: SchemaData::default(),
+            schema_kind: SchemaKind::Bool(value),
+        }
+    }
+}
+
+/// The fields of a [Schema] which is not a boolean schema.
+#[derive(Serialize)]
+struct SchemaObject<D, K> {
+    #[serde(flatten)]
+    schema_data: D,
+    #[serde(flatten)]
+    schema_kind: K,
+}
+
+/// A boolean schema is serialized as is, without its [SchemaData].
+///
+/// The [SchemaData] and [SchemaKind] of a schema are deserialized from the
+/// same value rather than flattened, so that the errors of their fields are
+/// reported at their JSON Pointer.
+impl Serialize for Schema {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.schema_kind {
+            SchemaKind::Bool(value) => serializer.serialize_bool(value),
+            _ => SchemaObject {
+                schema_data: &self.schema_data,
+                schema_kind: &self.schema_kind,
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Schema {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match de::buffer(deserializer)? {
+            serde_json::Value::Bool(value) => Ok(Schema::boolean(value)),
+            mut value => {
+                let schema_kind: SchemaKind = de::variant(value.clone())?;
+                if let (Some(keyword), Some(object)) = (schema_kind.keyword(), value.as_object_mut()) {
+                    object.shift_remove(keyword);
+                }
+                Ok(Schema {
+                    schema_data: de::variant(value)?,
+                    schema_kind,
+                })
+            }
+        }
+    }
+}
+
+/// A named variant of a message payload, see [Payload::OneOf].
+///
+/// Before `oneOf` held plain schemas, every variant of a `oneOf` was written
+/// in this shape. Such variants are still accepted in a `oneOf` and read as
+/// the [Schema] they convert into.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PayloadKind {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -59,13 +181,46 @@ pub struct PayloadKind {
     pub payload: Schema,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+impl From<PayloadKind> for Schema {
+    /// The payload, titled with the name of the variant and described by its
+    /// description, when they are given.
+    fn from(kind: PayloadKind) -> Schema {
+        let mut schema = kind.payload;
+        if kind.name.is_some() {
+            schema.schema_data.title = kind.name;
+        }
+        if kind.description.is_some() {
+            schema.schema_data.description = kind.description;
+        }
+        schema
+    }
+}
+
+/// A variant of a `oneOf`, which migrates a [PayloadKind] into a schema.
+struct OneOfVariant(ReferenceOr<Schema>);
+
+impl<'de> Deserialize<'de> for OneOfVariant {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = de::buffer(deserializer)?;
+        match de::has_key(&value, "payload") {
+            true => de::variant(value).map(|kind: PayloadKind| OneOfVariant(ReferenceOr::Item(kind.into()))),
+            false => de::variant(value).map(OneOfVariant),
+        }
+    }
+}
+
+/// Deserialized from a boolean as [SchemaKind::Bool], from the `type` field
+/// when it names one of the [Type]s, then from the `oneOf`, `allOf` or
+/// `anyOf` field, and as [AnySchema] otherwise. Within a [Schema], the
+/// compositions which are not its kind are kept in its [SchemaData].
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 #[serde(untagged)]
 pub enum SchemaKind {
     Type(Type),
     OneOf {
         #[serde(rename = "oneOf")]
-        one_of: Vec<ReferenceOr<PayloadKind>>,
+        one_of: Vec<ReferenceOr<Schema>>,
     },
     AllOf {
         #[serde(rename = "allOf")]
@@ -76,9 +231,13 @@ pub enum SchemaKind {
         any_of: Vec<ReferenceOr<Schema>>,
     },
     Any(AnySchema),
+    /// The boolean schema `true`, which every instance matches, or `false`,
+    /// which no instance matches.
+    Bool(bool),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Deserialized from the fields of the type named by the `type` field.
+#[derive(Debug, Clone, Serialize, PartialEq)]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum Type {
     String(StringType),
@@ -86,19 +245,221 @@ pub enum Type {
     Integer(IntegerType),
     Object(ObjectType),
     Array(ArrayType),
-    Boolean {},
+    Boolean(BooleanType),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// The names of the [Type]s.
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum TypeName {
+    String,
+    Number,
+    Integer,
+    Object,
+    Array,
+    Boolean,
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = de::buffer(deserializer)?;
+        match de::field(&mut value, "type")? {
+            TypeName::String => de::variant(value).map(Type::String),
+            TypeName::Number => de::variant(value).map(Type::Number),
+            TypeName::Integer => de::variant(value).map(Type::Integer),
+            TypeName::Object => de::variant(value).map(Type::Object),
+            TypeName::Array => de::variant(value).map(Type::Array),
+            TypeName::Boolean => de::variant(value).map(Type::Boolean),
+        }
+    }
+}
+
+/// The JSON types a schema without a single [Type] can list in `type`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SchemaType {
+    Null,
+    Boolean,
+    Object,
+    Array,
+    Number,
+    String,
+    Integer,
+}
+
+/// Serializes a single type as a string and several as a list.
+fn serialize_types<S: serde::Serializer>(types: &[SchemaType], serializer: S) -> Result<S::Ok, S::Error> {
+    match types {
+        [typ] => typ.serialize(serializer),
+        types => types.serialize(serializer),
+    }
+}
+
+fn deserialize_types<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<SchemaType>, D::Error> {
+    match de::buffer(deserializer)? {
+        serde_json::Value::Array(types) => de::variant(serde_json::Value::Array(types)),
+        typ => de::variant(typ).map(|typ| vec![typ]),
+    }
+}
+
+/// The `items` of an array: either one schema for all the items, or one
+/// schema for each position of a tuple.
+///
+/// Deserialized as a tuple from a list, and as a schema otherwise.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum Items {
+    Schema(ReferenceOr<Box<Schema>>),
+    Tuple(Vec<ReferenceOr<Box<Schema>>>),
+}
+
+impl Items {
+    /// Returns the schema of the item at `index`, or `None` when it is
+    /// governed by `additionalItems` instead.
+    pub fn get(&self, index: usize) -> Option<&ReferenceOr<Box<Schema>>> {
+        match self {
+            Items::Schema(schema) => Some(schema),
+            Items::Tuple(schemas) => schemas.get(index),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Items {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = de::buffer(deserializer)?;
+        match value.is_array() {
+            true => de::variant(value).map(Items::Tuple),
+            false => de::variant(value).map(Items::Schema),
+        }
+    }
+}
+
+/// A value of `dependencies`: the properties required by the property, or a
+/// schema the whole object must match when the property is present.
+///
+/// Deserialized as a list of properties from a list, and as a schema
+/// otherwise.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum Dependency {
+    Properties(Vec<String>),
+    Schema(ReferenceOr<Box<Schema>>),
+}
+
+impl<'de> Deserialize<'de> for Dependency {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = de::buffer(deserializer)?;
+        match value.is_array() {
+            true => de::variant(value).map(Dependency::Properties),
+            false => de::variant(value).map(Dependency::Schema),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
 #[serde(untagged)]
 pub enum AdditionalProperties {
     Any(bool),
     Schema(Box<ReferenceOr<Schema>>),
 }
 
+impl SchemaKind {
+    /// The field holding the schemas of a composition.
+    fn keyword(&self) -> Option<&'static str> {
+        match self {
+            SchemaKind::OneOf { .. } => Some("oneOf"),
+            SchemaKind::AllOf { .. } => Some("allOf"),
+            SchemaKind::AnyOf { .. } => Some("anyOf"),
+            SchemaKind::Type(_) | SchemaKind::Any(_) | SchemaKind::Bool(_) => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SchemaKind {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = de::buffer(deserializer)?;
+        let typ = value.get("type").and_then(serde_json::Value::as_str);
+        if let serde_json::Value::Bool(value) = value {
+            Ok(SchemaKind::Bool(value))
+        } else if matches!(typ, Some("string" | "number" | "integer" | "object" | "array" | "boolean")) {
+            exclusive_bounds(&mut value);
+            nullable_enumeration(&mut value);
+            de::variant(value).map(SchemaKind::Type)
+        } else if de::has_key(&value, "oneOf") {
+            let one_of: Vec<OneOfVariant> = de::field(&mut value, "oneOf")?;
+            Ok(SchemaKind::OneOf {
+                one_of: one_of.into_iter().map(|variant| variant.0).collect(),
+            })
+        } else if de::has_key(&value, "allOf") {
+            Ok(SchemaKind::AllOf {
+                all_of: de::field(&mut value, "allOf")?,
+            })
+        } else if de::has_key(&value, "anyOf") {
+            Ok(SchemaKind::AnyOf {
+                any_of: de::field(&mut value, "anyOf")?,
+            })
+        } else {
+            de::variant(value).map(SchemaKind::Any)
+        }
+    }
+}
+
+/// Maps the boolean `exclusiveMinimum` and `exclusiveMaximum` of OpenAPI 3.0
+/// and JSON Schema draft 4 onto the numeric form of [NumberType] and
+/// [IntegerType]: `true` makes `minimum` or `maximum` the exclusive bound,
+/// and `false` is dropped.
+fn exclusive_bounds(value: &mut serde_json::Value) {
+    let Some(object) = value.as_object_mut() else {
+        return;
+    };
+    for (exclusive, bound) in [("exclusiveMinimum", "minimum"), ("exclusiveMaximum", "maximum")] {
+        if let Some(&serde_json::Value::Bool(is_exclusive)) = object.get(exclusive) {
+            object.remove(exclusive);
+            if let Some(bound) = is_exclusive.then(|| object.remove(bound)).flatten() {
+                object.insert(exclusive.to_owned(), bound);
+            }
+        }
+    }
+}
+
+/// Drops the `null` which OpenAPI 3.0 lists in the `enum` of a `nullable`
+/// schema from the `enum` of [NumberType], [IntegerType] and [BooleanType],
+/// which can not hold it: [SchemaData::nullable] already allows it.
+fn nullable_enumeration(value: &mut serde_json::Value) {
+    let Some(object) = value.as_object_mut() else {
+        return;
+    };
+    let typed = matches!(object.get("type").and_then(serde_json::Value::as_str), Some("number" | "integer" | "boolean"));
+    if !typed || object.get("nullable") != Some(&serde_json::Value::Bool(true)) {
+        return;
+    }
+    if let Some(serde_json::Value::Array(enumeration)) = object.get_mut("enum") {
+        enumeration.retain(|allowed| !allowed.is_null());
+    }
+}
+
+impl<'de> Deserialize<'de> for AdditionalProperties {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match de::buffer(deserializer)? {
+            serde_json::Value::Bool(any) => Ok(AdditionalProperties::Any(any)),
+            value => de::variant(value).map(AdditionalProperties::Schema),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AnySchema {
+    /// The types allowed by `type`, when it is `null` or a list. Empty when
+    /// the schema has no `type`.
+    #[serde(rename = "type", default, serialize_with = "serialize_types", deserialize_with = "deserialize_types", skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<SchemaType>,
+    #[serde(rename = "enum", default, skip_serializing_if = "Vec::is_empty")]
+    pub enumeration: Vec<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pattern: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -117,12 +478,22 @@ pub struct AnySchema {
     pub required: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_properties: Option<AdditionalProperties>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub pattern_properties: IndexMap<String, ReferenceOr<Box<Schema>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub property_names: Option<ReferenceOr<Box<Schema>>>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub dependencies: IndexMap<String, Dependency>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub min_properties: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_properties: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub items: Option<ReferenceOr<Box<Schema>>>,
+    pub items: Option<Items>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_items: Option<AdditionalProperties>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contains: Option<ReferenceOr<Box<Schema>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub min_items: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -195,325 +566,40 @@ pub struct ObjectType {
     pub required: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_properties: Option<AdditionalProperties>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub pattern_properties: IndexMap<String, ReferenceOr<Box<Schema>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub property_names: Option<ReferenceOr<Box<Schema>>>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub dependencies: IndexMap<String, Dependency>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub min_properties: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_properties: Option<usize>,
 }
 
+/// Builds the schema of a property from an example given as text and a
+/// description.
+#[deprecated(note = "use `ToSchema::property`, which keeps the type of the example")]
 pub trait XValue {
     fn create_propertie(typ: String, ex_value: String, description: String) -> ReferenceOr<Box<Schema>>;
 }
 
-impl XValue for Vec<String> {
-    fn create_propertie(_typ: String, _ex_value: String, description: String) -> ReferenceOr<Box<Schema>> {
-        let schema = Schema {
-            schema_data: SchemaData {
-                description: Some(description.clone()),
-                ..Default::default()
-            },
-            schema_kind: SchemaKind::Type(schema::Type::Array(
-                ArrayType {
-                    items: Some(ReferenceOr::Item(Box::new(Schema {
-                        schema_data: SchemaData { ..Default::default() },
-                        schema_kind: SchemaKind::Type(Type::String(StringType {
-                            format: VariantOrUnknownOrEmpty::Item(StringFormat::Byte),
-                            ..Default::default()
-                        })),
-                    }))),
-                    ..Default::default()
-                },
-            )),
-        };
-        ReferenceOr::Item(Box::new(schema))
-    }
-}
-impl XValue for Vec<i32> {
-    fn create_propertie(_typ: String, _ex_value: String, description: String) -> ReferenceOr<Box<Schema>> {
-        let schema = Schema {
-            schema_data: SchemaData {
-                description: Some(description.clone()),
-                ..Default::default()
-            },
-            schema_kind: SchemaKind::Type(schema::Type::Array(
-                ArrayType {
-                    items: Some(ReferenceOr::Item(Box::new(Schema {
-                        schema_data: SchemaData { ..Default::default() },
-                        schema_kind: SchemaKind::Type(Type::Integer(IntegerType {
-                            format: VariantOrUnknownOrEmpty::Item(IntegerFormat::Int32),
-                            enumeration: Vec::new(),
-                            ..Default::default()
-                        })),
-                    }))),
-                    ..Default::default()
-                },
-            )),
-        };
-        ReferenceOr::Item(Box::new(schema))
-    }
-}
-impl XValue for Vec<u32> {
-    fn create_propertie(_typ: String, _ex_value: String, description: String) -> ReferenceOr<Box<Schema>> {
-        let schema = Schema {
-            schema_data: SchemaData {
-                description: Some(description.clone()),
-                ..Default::default()
-            },
-            schema_kind: SchemaKind::Type(schema::Type::Array(
-                ArrayType {
-                    items: Some(ReferenceOr::Item(Box::new(Schema {
-                        schema_data: SchemaData { ..Default::default() },
-                        schema_kind: SchemaKind::Type(Type::Integer(IntegerType {
-                            format: VariantOrUnknownOrEmpty::Item(IntegerFormat::UInt32),
-                            enumeration: Vec::new(),
-                            ..Default::default()
-                        })),
-                    }))),
-                    ..Default::default()
-                },
-            )),
-        };
-        ReferenceOr::Item(Box::new(schema))
-    }
-}
-impl XValue for Vec2<i32> {
-    fn create_propertie(_typ: String, _ex_value: String, description: String) -> ReferenceOr<Box<Schema>> {
-        let schema = Schema {
-            schema_data: SchemaData {
-                description: Some(description.clone()),
-                ..Default::default()
-            },
-            schema_kind: SchemaKind::Type(schema::Type::Object(
-                ObjectType {
-                    properties: IndexMap::from([
-                        (
-                            "x".to_owned(),
-                            ReferenceOr::Item(Box::new(Schema {
-                                schema_data: SchemaData { ..Default::default() },
-                                schema_kind: SchemaKind::Type(Type::Integer(IntegerType {
-                                    format: VariantOrUnknownOrEmpty::Item(IntegerFormat::Int32),
-                                    enumeration: Vec::new(),
-                                    ..Default::default()
-                                })),
-                            }))
-                        ),
-                        (
-                            "y".to_owned(),
-                            ReferenceOr::Item(Box::new(Schema {
-                                schema_data: SchemaData { ..Default::default() },
-                                schema_kind: SchemaKind::Type(Type::Integer(IntegerType {
-                                    format: VariantOrUnknownOrEmpty::Item(IntegerFormat::Int32),
-                                    enumeration: Vec::new(),
-                                    ..Default::default()
-                                })),
-                            }))
-                        ),
-                    ]),
-                    ..Default::default()
-                },
-            )),
-        };
-        ReferenceOr::Item(Box::new(schema))
-    }
-}
-impl XValue for Vec2<u32> {
-    fn create_propertie(_typ: String, _ex_value: String, description: String) -> ReferenceOr<Box<Schema>> {
-        let schema = Schema {
-            schema_data: SchemaData {
-                description: Some(description.clone()),
-                ..Default::default()
-            },
-            schema_kind: SchemaKind::Type(schema::Type::Object(
-                ObjectType {
-                    properties: IndexMap::from([
-                        (
-                            "x".to_owned(),
-                            ReferenceOr::Item(Box::new(Schema {
-                                schema_data: SchemaData { ..Default::default() },
-                                schema_kind: SchemaKind::Type(Type::Integer(IntegerType {
-                                    format: VariantOrUnknownOrEmpty::Item(IntegerFormat::UInt32),
-                                    enumeration: Vec::new(),
-                                    ..Default::default()
-                                })),
-                            }))
-                        ),
-                        (
-                            "y".to_owned(),
-                            ReferenceOr::Item(Box::new(Schema {
-                                schema_data: SchemaData { ..Default::default() },
-                                schema_kind: SchemaKind::Type(Type::Integer(IntegerType {
-                                    format: VariantOrUnknownOrEmpty::Item(IntegerFormat::UInt32),
-                                    enumeration: Vec::new(),
-                                    ..Default::default()
-                                })),
-                            }))
-                        ),
-                    ]),
-                    ..Default::default()
-                },
-            )),
-        };
-        ReferenceOr::Item(Box::new(schema))
-    }
-}
-impl XValue for Vec2<f32> {
-    fn create_propertie(_typ: String, _ex_value: String, description: String) -> ReferenceOr<Box<Schema>> {
-        let schema = Schema {
-            schema_data: SchemaData {
-                description: Some(description.clone()),
-                ..Default::default()
-            },
-            schema_kind: SchemaKind::Type(schema::Type::Object(
-                ObjectType {
-                    properties: IndexMap::from([
-                        (
-                            "x".to_owned(),
-                            ReferenceOr::Item(Box::new(Schema {
-                                schema_data: SchemaData { ..Default::default() },
-                                schema_kind: SchemaKind::Type(Type::Number(NumberType {
-                                    format: VariantOrUnknownOrEmpty::Item(NumberFormat::Float),
-                                    enumeration: Vec::new(),
-                                    ..Default::default()
-                                })),
-                            }))
-                        ),
-                        (
-                            "y".to_owned(),
-                            ReferenceOr::Item(Box::new(Schema {
-                                schema_data: SchemaData { ..Default::default() },
-                                schema_kind: SchemaKind::Type(Type::Number(NumberType {
-                                    format: VariantOrUnknownOrEmpty::Item(NumberFormat::Float),
-                                    enumeration: Vec::new(),
-                                    ..Default::default()
-                                })),
-                            }))
-                        ),
-                    ]),
-                    ..Default::default()
-                },
-            )),
-        };
-        ReferenceOr::Item(Box::new(schema))
-    }
-}
-impl XValue for i32 {
-    fn create_propertie(_typ: String, ex_value: String, description: String) -> ReferenceOr<Box<Schema>> {
-        let mut schema = Schema {
-            schema_kind: SchemaKind::Type(Type::Integer(IntegerType {
-                format: VariantOrUnknownOrEmpty::Item(IntegerFormat::Int32),
-                enumeration: Vec::new(),
-                ..Default::default()
-            })),
-            schema_data: SchemaData {
-                description: Some(description.clone()),
-                ..Default::default()
-            },
-        };
-        if ex_value.len() > 0 {
-            schema.schema_data.example = Some(serde_json::Value::from(ex_value));
-        }
-        ReferenceOr::Item(Box::new(schema))
-    }
-}
-impl XValue for u32 {
-    fn create_propertie(_typ: String, ex_value: String, description: String) -> ReferenceOr<Box<Schema>> {
-        let mut schema = Schema {
-            schema_kind: SchemaKind::Type(Type::Integer(IntegerType {
-                format: VariantOrUnknownOrEmpty::Item(IntegerFormat::UInt32),
-                enumeration: Vec::new(),
-                ..Default::default()
-            })),
-            schema_data: SchemaData {
-                description: Some(description.clone()),
-                ..Default::default()
-            },
-        };
-        if ex_value.len() > 0 {
-            schema.schema_data.example = Some(serde_json::Value::from(ex_value));
-        }
-        ReferenceOr::Item(Box::new(schema))
-    }
-}
-impl XValue for i64 {
-    fn create_propertie(_typ: String, ex_value: String, description: String) -> ReferenceOr<Box<Schema>> {
-        let mut schema = Schema {
-            schema_kind: SchemaKind::Type(Type::Integer(IntegerType {
-                format: VariantOrUnknownOrEmpty::Item(IntegerFormat::Int64),
-                enumeration: Vec::new(),
-                ..Default::default()
-            })),
-            schema_data: SchemaData {
-                description: Some(description.clone()),
-                ..Default::default()
-            },
-        };
-        if ex_value.len() > 0 {
-            schema.schema_data.example = Some(serde_json::Value::from(ex_value));
-        }
-        ReferenceOr::Item(Box::new(schema))
-    }
-}
-
-impl XValue for f32 {
+/// The example is parsed as JSON and kept as a string when it is not valid
+/// JSON or does not match the schema of the type.
+#[allow(deprecated)]
+impl<T: ToSchema> XValue for T {
     fn create_propertie(_typ: String, ex_value: String, description: String) -> ReferenceOr<Box<Schema>> {
-        let mut schema = Schema {
-            schema_kind: SchemaKind::Type(Type::Number(NumberType {
-                format: VariantOrUnknownOrEmpty::Item(NumberFormat::Float),
-                enumeration: Vec::new(),
-                ..Default::default()
-            })),
-            schema_data: SchemaData {
-                description: Some(description.clone()),
-                ..Default::default()
-            },
-        };
-        if ex_value.len() > 0 {
-            schema.schema_data.example = Some(serde_json::Value::from(ex_value));
+        let mut schema = T::schema();
+        schema.schema_data.description = Some(description);
+        if !ex_value.is_empty() {
+            let example = serde_json::from_str(&ex_value).ok().filter(|example| schema.validate(example).is_ok());
+            schema.schema_data.example = Some(example.unwrap_or(serde_json::Value::String(ex_value)));
         }
         ReferenceOr::Item(Box::new(schema))
     }
 }
 
-impl XValue for f64 {
-    fn create_propertie(_typ: String, ex_value: String, description: String) -> ReferenceOr<Box<Schema>> {
-        let mut schema = Schema {
-            schema_kind: SchemaKind::Type(Type::Number(NumberType {
-                format: VariantOrUnknownOrEmpty::Item(NumberFormat::Double),
-                enumeration: Vec::new(),
-                ..Default::default()
-            })),
-            schema_data: SchemaData {
-                description: Some(description.clone()),
-                ..Default::default()
-            },
-        };
-        if ex_value.len() > 0 {
-            schema.schema_data.example = Some(serde_json::Value::from(ex_value));
-        }
-        ReferenceOr::Item(Box::new(schema))
-    }
-}
-
-impl XValue for String {
-    fn create_propertie(_typ: String, ex_value: String, description: String) -> ReferenceOr<Box<Schema>> {
-        let mut schema = Schema {
-            schema_kind: SchemaKind::Type(Type::String(StringType {
-                format: VariantOrUnknownOrEmpty::Item(StringFormat::Byte),
-                ..Default::default()
-            })),
-            schema_data: SchemaData {
-                description: Some(description.clone()),
-                ..Default::default()
-            },
-        };
-        if ex_value.len() > 0 {
-            schema.schema_data.example = Some(serde_json::Value::from(ex_value));
-        }
-        ReferenceOr::Item(Box::new(schema))
-    }
-}
-
-
 impl ObjectType {
     pub fn create_propertie2(v_str: String, description: String) -> ReferenceOr<Box<Schema>> {
         let schema = Schema {
@@ -532,6 +618,7 @@ impl ObjectType {
                 description: Some(description.clone()),
                 discriminator: None,
                 default: None,
+                ..Default::default()
             },
         };
         ReferenceOr::Item(Box::new(schema))
@@ -542,15 +629,26 @@ impl ObjectType {
 #[serde(rename_all = "camelCase")]
 pub struct ArrayType {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub items: Option<ReferenceOr<Box<Schema>>>,
+    pub items: Option<Items>,
+    /// The schema of the items after those of a tuple of `items`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_items: Option<AdditionalProperties>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contains: Option<ReferenceOr<Box<Schema>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub min_items: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_items: Option<usize>,
-    #[serde(default, skip_serializing_if = "Clone::clone")]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub unique_items: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct BooleanType {
+    #[serde(rename = "enum", default, skip_serializing_if = "Vec::is_empty")]
+    pub enumeration: Vec<bool>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum NumberFormat {
@@ -577,3 +675,154 @@ pub enum StringFormat {
     Byte,
     Binary,
 }
+
+#[test]
+fn test_draft07_keywords() {
+    let yaml = r#"
+        $id: https://example.com/reading.json
+        $schema: http://json-schema.org/draft-07/schema#
+        definitions:
+          unit:
+            type: string
+            enum: [celsius, fahrenheit]
+        type: object
+        patternProperties:
+          "^x-":
+            type: string
+        propertyNames:
+          maxLength: 12
+        dependencies:
+          unit: [value]
+          sensor:
+            required: [site]
+        properties:
+          value:
+            type: [number, "null"]
+          unit:
+            type: string
+          calibrated:
+            type: boolean
+            enum: [true]
+          kind:
+            const: null
+          position:
+            type: array
+            items:
+              - type: number
+              - type: number
+            additionalItems: false
+            contains:
+              const: 0
+        not:
+          required: [legacy]
+        if:
+          required: [sensor]
+        then:
+          required: [value]
+        else:
+          maxProperties: 4
+        "#;
+    let value: serde_json::Value = serde_yaml::from_str(yaml).unwrap();
+    let schema: Schema = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&schema).unwrap(), value);
+
+    let valid = serde_json::json!({ "value": null, "sensor": "s1", "site": "lab", "position": [0, 1.5] });
+    assert_eq!(schema.validate(&valid), Ok(()));
+
+    let invalid = serde_json::json!({
+        "unit": "celsius",
+        "legacy": true,
+        "calibrated": false,
+        "kind": 1,
+        "position": [1, 2, 3],
+        "x-source": 5,
+        "a-long-property-name": 1
+    });
+    let errors: Vec<String> = schema.validate(&invalid).unwrap_err().iter().map(|error| error.instance_path.clone()).collect();
+    assert_eq!(errors, vec!["", "", "/calibrated", "/kind", "/position", "/position", "/x-source", "/a-long-property-name", ""]);
+}
+
+#[test]
+fn test_boolean_schemas() {
+    let value = serde_json::json!({
+        "type": "object",
+        "properties": { "id": true, "legacy": false },
+        "additionalProperties": false,
+        "not": { "type": "array", "items": true }
+    });
+    let schema: Schema = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&schema).unwrap(), value);
+    let SchemaKind::Type(Type::Object(object)) = &schema.schema_kind else {
+        panic!("expected an object schema, found {:?}", schema.schema_kind);
+    };
+    assert_eq!(object.properties["legacy"], ReferenceOr::Item(Box::new(Schema::boolean(false))));
+
+    assert_eq!(schema.validate(&serde_json::json!({ "id": [1, "a"] })), Ok(()));
+    let errors = schema.validate(&serde_json::json!({ "legacy": 1 })).unwrap_err();
+    assert_eq!(errors[0].instance_path, "/legacy");
+}
+
+#[test]
+fn test_one_of_schemas() {
+    let value = serde_json::json!({ "oneOf": [{ "type": "string" }, { "type": "integer" }, { "$ref": "#/components/schemas/Id" }] });
+    let schema: Schema = serde_json::from_value(value.clone()).unwrap();
+    assert!(matches!(&schema.schema_kind, SchemaKind::OneOf { one_of } if one_of.len() == 3));
+    assert_eq!(serde_json::to_value(&schema).unwrap(), value);
+    assert!(schema.validate(&serde_json::json!(1)).is_ok());
+    assert!(schema.validate(&serde_json::json!(true)).is_err());
+
+    let legacy: Schema = serde_json::from_value(serde_json::json!({
+        "oneOf": [{ "name": "text", "description": "Some text.", "payload": { "type": "string" } }]
+    }))
+    .unwrap();
+    assert_eq!(
+        serde_json::to_value(&legacy).unwrap(),
+        serde_json::json!({ "oneOf": [{ "title": "text", "description": "Some text.", "type": "string" }] })
+    );
+
+    let value = serde_json::json!({
+        "title": "Events",
+        "oneOf": [{ "name": "created", "payload": { "type": "object" } }, { "name": "deleted", "payload": { "type": "string" } }]
+    });
+    let payload: Payload = serde_json::from_value(value.clone()).unwrap();
+    let Payload::OneOf { schema_data, one_of } = &payload else {
+        panic!("expected payload variants, found {payload:?}");
+    };
+    assert_eq!((schema_data.title.as_deref(), one_of.len()), (Some("Events"), 2));
+    assert_eq!(serde_json::to_value(&payload).unwrap(), value);
+    let Some(ReferenceOr::Item(schema)) = payload.to_schema() else {
+        panic!("expected an inline schema");
+    };
+    assert_eq!(schema.select_variant(&serde_json::json!("id"), None).map(|(index, _)| index), Some(1));
+}
+
+#[test]
+fn test_boolean_exclusive_bounds() {
+    let schema: Schema = serde_json::from_value(serde_json::json!({ "type": "number", "minimum": 0, "exclusiveMinimum": true, "maximum": 10, "exclusiveMaximum": false })).unwrap();
+    assert_eq!(serde_json::to_value(&schema).unwrap(), serde_json::json!({ "type": "number", "exclusiveMinimum": 0.0, "maximum": 10.0 }));
+    assert!(schema.validate(&serde_json::json!(0)).is_err());
+    assert_eq!(schema.validate(&serde_json::json!(10)), Ok(()));
+
+    let schema: Schema = serde_json::from_value(serde_json::json!({ "type": "integer", "maximum": 5, "exclusiveMaximum": true })).unwrap();
+    assert_eq!(serde_json::to_value(&schema).unwrap(), serde_json::json!({ "type": "integer", "exclusiveMaximum": 5 }));
+    assert!(schema.validate(&serde_json::json!(5)).is_err());
+}
+
+#[test]
+fn test_lossless_round_trip() {
+    let cases = [
+        serde_json::json!({ "type": "object", "properties": { "id": { "type": "string" } }, "oneOf": [{ "required": ["id"] }, { "required": ["name"] }] }),
+        serde_json::json!({ "allOf": [{ "type": "object" }], "anyOf": [{ "required": ["id"] }, { "required": ["name"] }] }),
+        serde_json::json!({ "type": "string", "x-internal": true, "x-owner": { "team": "billing" } }),
+        serde_json::json!({ "type": "string", "contentEncoding": "base64", "contentMediaType": "image/png" }),
+        serde_json::json!({ "$ref": "#/components/schemas/Id" }),
+    ];
+    for value in cases {
+        let schema: Schema = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&schema).unwrap(), value);
+    }
+
+    let schema: Schema = serde_json::from_value(serde_json::json!({ "type": "object", "oneOf": [{ "required": ["id"] }, { "required": ["name"] }] })).unwrap();
+    assert_eq!(schema.validate(&serde_json::json!({ "id": 1 })), Ok(()));
+    assert!(schema.validate(&serde_json::json!({ "id": 1, "name": "a" })).is_err());
+}
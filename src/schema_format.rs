@@ -0,0 +1,58 @@
+/// The format of the payload of a message, named by its `schemaFormat`.
+///
+/// Only the media type matters: its `+json` or `+yaml` suffix and its
+/// parameters, such as the `version`, are ignored.
+///
+/// ```
+/// use asyncapi::SchemaFormat;
+///
+/// assert_eq!(SchemaFormat::parse("application/vnd.apache.avro+yaml;version=1.9.0"), SchemaFormat::Avro);
+/// assert_eq!(SchemaFormat::of(None), SchemaFormat::AsyncApi);
+/// assert!(SchemaFormat::parse("application/vnd.oai.openapi;version=3.0.0").is_schema());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaFormat {
+    /// `application/vnd.aai.asyncapi`, the default: a [Schema Object][crate::Schema].
+    AsyncApi,
+    /// `application/schema+json` or `application/schema+yaml`: a JSON Schema.
+    JsonSchema,
+    /// `application/vnd.oai.openapi`: an OpenAPI 3.0 Schema Object.
+    OpenApi,
+    /// `application/vnd.apache.avro`: an [Avro schema][crate::AvroSchema].
+    Avro,
+    /// `application/vnd.google.protobuf`: the source of a Protocol Buffers
+    /// definition.
+    Protobuf,
+    /// `application/raml+yaml`: a RAML data type.
+    Raml,
+    /// Any other format, holding the `schemaFormat`.
+    Other(String),
+}
+
+impl SchemaFormat {
+    pub fn parse(format: &str) -> SchemaFormat {
+        let media_type = format.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+        let media_type = media_type.strip_suffix("+json").or_else(|| media_type.strip_suffix("+yaml")).unwrap_or(&media_type);
+        match media_type {
+            "application/vnd.aai.asyncapi" => SchemaFormat::AsyncApi,
+            "application/schema" => SchemaFormat::JsonSchema,
+            "application/vnd.oai.openapi" => SchemaFormat::OpenApi,
+            "application/vnd.apache.avro" => SchemaFormat::Avro,
+            "application/vnd.google.protobuf" => SchemaFormat::Protobuf,
+            "application/raml" => SchemaFormat::Raml,
+            _ => SchemaFormat::Other(format.to_owned()),
+        }
+    }
+
+    /// The format of a message with the given `schemaFormat`, which is
+    /// [SchemaFormat::AsyncApi] when it is omitted.
+    pub fn of(format: Option<&str>) -> SchemaFormat {
+        format.map_or(SchemaFormat::AsyncApi, SchemaFormat::parse)
+    }
+
+    /// Returns whether payloads of this format are read as
+    /// [Schema Objects][crate::Schema].
+    pub fn is_schema(&self) -> bool {
+        matches!(self, SchemaFormat::AsyncApi | SchemaFormat::JsonSchema | SchemaFormat::OpenApi)
+    }
+}
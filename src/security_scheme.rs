@@ -141,6 +141,7 @@ use serde::{Deserialize, Serialize};
 /// type: scramSha512
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 #[serde(tag = "type")]
 pub enum SecurityScheme {
     #[serde(rename = "userPassword")]
@@ -479,7 +480,7 @@ fn test_deserialize_security_scheme() {
     in: user
     description: Provide your API key as the user and leave the password empty.
     "#;
-    let asyncapi: ReferenceOr<SecurityScheme> = serde_yaml::from_str(example).expect(&format!("Could not deserialize api key security scheme"));
+    let asyncapi: ReferenceOr<SecurityScheme> = serde_yaml::from_str(example).expect("Could not deserialize api key security scheme");
     assert_eq!(
         ReferenceOr::Item(SecurityScheme::ApiKey {
             location: "user".to_string(),
@@ -0,0 +1,266 @@
+use std::fmt;
+
+use indexmap::IndexMap;
+use yaml_rust2::{
+    parser::{Event, MarkedEventReceiver, Parser},
+    scanner::Marker,
+};
+
+use crate::{de, resolver::escape_pointer_token, AsyncAPI};
+
+/// A position in the text of a document. Lines and columns start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// The locations of the nodes of a YAML or JSON document, keyed by JSON
+/// Pointer.
+///
+/// The location of an entry of a mapping is the location of its key, and
+/// the location of an item of a sequence is the location of its value.
+///
+/// ```
+/// let text = "
+/// asyncapi: 2.6.0
+/// info:
+///   title: Account Service
+///   version: 1.0.0
+/// channels:
+///   user/signedup:
+///     servers: [production]
+/// ";
+/// let (asyncapi, source_map) = asyncapi::load_with_source_map(text).unwrap();
+/// let diagnostic = &asyncapi.validate()[0];
+/// let location = source_map.locate(&diagnostic.path).unwrap();
+/// assert_eq!(format!("account.yaml:{location}"), "account.yaml:8:15");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SourceMap {
+    locations: IndexMap<String, Location>,
+}
+
+impl SourceMap {
+    /// Records the locations of the nodes of a YAML or JSON text.
+    ///
+    /// Invalid text is not an error: the nodes found before the first syntax
+    /// error are recorded.
+    pub fn parse(text: &str) -> SourceMap {
+        let mut recorder = Recorder {
+            source_map: SourceMap::default(),
+            frames: Vec::new(),
+        };
+        let _ = Parser::new(text.chars()).load(&mut recorder, false);
+        recorder.source_map
+    }
+
+    /// Returns the location of the node at a JSON Pointer.
+    pub fn get(&self, pointer: &str) -> Option<Location> {
+        self.locations.get(pointer).copied()
+    }
+
+    /// Returns the location of the node at a JSON Pointer or, if it is not
+    /// in the text, e.g. a missing field, of its closest ancestor.
+    pub fn locate(&self, pointer: &str) -> Option<Location> {
+        let mut pointer = pointer;
+        loop {
+            if let Some(location) = self.get(pointer) {
+                return Some(location);
+            }
+            pointer = &pointer[..pointer.rfind('/')?];
+        }
+    }
+
+    /// Iterates over the JSON Pointers and locations of the nodes, in the
+    /// order of the text.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Location)> {
+        self.locations.iter().map(|(pointer, location)| (pointer.as_str(), *location))
+    }
+}
+
+/// The error returned by [`load_with_source_map`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceError {
+    pub message: String,
+    /// The JSON Pointer of the node which does not have the expected
+    /// structure, for errors which are not syntax errors.
+    pub pointer: Option<String>,
+    /// The location of the error in the text, when known.
+    pub location: Option<Location>,
+}
+
+impl fmt::Display for SourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location {
+            Some(location) => write!(f, "{location}: {}", self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for SourceError {}
+
+/// Deserializes a YAML or JSON document and records the locations of its
+/// nodes, so that the paths of [diagnostics][crate::Diagnostic] can be
+/// reported as lines and columns with [`SourceMap::locate`].
+///
+/// The text is parsed twice: once by `serde_yaml` into the document, and
+/// once by the event parser of `yaml-rust2` into the source map, as
+/// `serde_yaml` does not expose the locations of the nodes it reads.
+pub fn load_with_source_map(text: &str) -> Result<(AsyncAPI, SourceMap), SourceError> {
+    let document = serde_yaml::from_str(text).map_err(|error| SourceError {
+        message: error.to_string(),
+        pointer: None,
+        location: error.location().map(|location| Location {
+            line: location.line(),
+            column: location.column(),
+        }),
+    })?;
+    let source_map = SourceMap::parse(text);
+    match de::from_value(document) {
+        Ok(asyncapi) => Ok((asyncapi, source_map)),
+        Err(error) => Err(SourceError {
+            location: source_map.locate(&error.pointer),
+            message: error.message,
+            pointer: Some(error.pointer),
+        }),
+    }
+}
+
+/// A mapping or sequence being parsed.
+enum Frame {
+    /// `key` is the next key and its location, once it has been parsed. The
+    /// pointer is `None` inside nodes which can not be addressed, such as
+    /// the keys of mappings which are not scalars.
+    ///
+    /// The parser marks the start of a block mapping at the end of its first
+    /// key, so a mapping which is not the value of an entry is located at its
+    /// first key.
+    Mapping {
+        pointer: Option<String>,
+        key: Option<(Option<String>, Location)>,
+        located: bool,
+    },
+    Sequence {
+        pointer: Option<String>,
+        index: usize,
+    },
+}
+
+struct Recorder {
+    source_map: SourceMap,
+    frames: Vec<Frame>,
+}
+
+impl Recorder {
+    /// Returns the pointer and location of a new node, or `None` for a key.
+    fn node(&mut self, scalar: Option<&str>, location: Location) -> Option<(Option<String>, Location)> {
+        match self.frames.last_mut() {
+            None => Some((Some(String::new()), location)),
+            Some(Frame::Mapping { pointer, key, located }) => match key.take() {
+                None => {
+                    if let (Some(pointer), false) = (pointer.as_ref(), *located) {
+                        self.source_map.locations.entry(pointer.clone()).or_insert(location);
+                    }
+                    *located = true;
+                    *key = Some((scalar.map(str::to_owned), location));
+                    None
+                }
+                Some((key, key_location)) => {
+                    let pointer = pointer.as_ref().zip(key).map(|(pointer, key)| format!("{pointer}/{}", escape_pointer_token(&key)));
+                    Some((pointer, key_location))
+                }
+            },
+            Some(Frame::Sequence { pointer, index }) => {
+                let pointer = pointer.as_ref().map(|pointer| format!("{pointer}/{index}"));
+                *index += 1;
+                Some((pointer, location))
+            }
+        }
+    }
+
+    fn record(&mut self, pointer: &Option<String>, location: Location) {
+        if let Some(pointer) = pointer {
+            self.source_map.locations.entry(pointer.clone()).or_insert(location);
+        }
+    }
+}
+
+impl MarkedEventReceiver for Recorder {
+    fn on_event(&mut self, event: Event, mark: Marker) {
+        let location = Location {
+            line: mark.line(),
+            column: mark.col() + 1,
+        };
+        match event {
+            Event::Scalar(value, ..) => {
+                if let Some((pointer, location)) = self.node(Some(&value), location) {
+                    self.record(&pointer, location);
+                }
+            }
+            Event::Alias(_) => {
+                if let Some((pointer, location)) = self.node(None, location) {
+                    self.record(&pointer, location);
+                }
+            }
+            Event::MappingStart(..) => {
+                let entry = matches!(self.frames.last(), Some(Frame::Mapping { .. }));
+                let pointer = match self.node(None, location) {
+                    Some((pointer, location)) if entry => {
+                        self.record(&pointer, location);
+                        pointer
+                    }
+                    Some((pointer, _)) => pointer,
+                    // A key which is a mapping.
+                    None => None,
+                };
+                self.frames.push(Frame::Mapping { pointer, key: None, located: entry });
+            }
+            Event::SequenceStart(..) => {
+                let pointer = match self.node(None, location) {
+                    Some((pointer, location)) => {
+                        self.record(&pointer, location);
+                        pointer
+                    }
+                    // A key which is a sequence.
+                    None => None,
+                };
+                self.frames.push(Frame::Sequence { pointer, index: 0 });
+            }
+            Event::MappingEnd | Event::SequenceEnd => {
+                self.frames.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test_source_map() {
+    let yaml = "asyncapi: 2.6.0\ninfo:\n  title: Source\n  version: 1.0.0\nchannels:\n  user/signedup:\n    servers:\n      - production\n      - staging\n";
+    let source_map = SourceMap::parse(yaml);
+    assert_eq!(source_map.get(""), Some(Location { line: 1, column: 1 }));
+    assert_eq!(source_map.get("/info/title"), Some(Location { line: 3, column: 3 }));
+    assert_eq!(source_map.get("/channels/user~1signedup"), Some(Location { line: 6, column: 3 }));
+    assert_eq!(source_map.get("/channels/user~1signedup/servers/1"), Some(Location { line: 9, column: 9 }));
+    assert_eq!(source_map.locate("/channels/user~1signedup/subscribe/message"), Some(Location { line: 6, column: 3 }));
+
+    let json = "{\n\t\"asyncapi\": \"2.6.0\",\n\t\"info\": {\"title\": \"Source\", \"version\": \"1.0.0\"},\n\t\"channels\": {}\n}";
+    let source_map = SourceMap::parse(json);
+    assert_eq!(source_map.get("/info/version"), Some(Location { line: 3, column: 30 }));
+    assert_eq!(source_map.get("/channels"), Some(Location { line: 4, column: 2 }));
+
+    let error = load_with_source_map("asyncapi: 2.6.0\ninfo:\n  title: Source\n").unwrap_err();
+    assert_eq!(error.pointer.as_deref(), Some("/info"));
+    assert_eq!(error.location, Some(Location { line: 2, column: 1 }));
+
+    let error = load_with_source_map("asyncapi: 2.6.0\ninfo: {title: Source, version: 1.0.0}\nchannels:\n  user:\n    subscribe:\n      operationId: 42\n").unwrap_err();
+    assert_eq!(error.to_string(), "6:7: invalid type: integer `42`, expected a string");
+}
@@ -0,0 +1,386 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use serde::Serialize;
+use vek::mat::repr_c::{column_major, row_major};
+use vek::quaternion::repr_c::Quaternion;
+use vek::vec::repr_c::{Extent2, Extent3, Rgb, Rgba, Uv, Uvw, Vec16, Vec2, Vec3, Vec32, Vec4, Vec64, Vec8};
+
+use crate::schema::{AdditionalProperties, ArrayType, BooleanType, IntegerFormat, IntegerType, Items, NumberFormat, NumberType, ObjectType, StringType, Type};
+use crate::{ReferenceOr, Schema, SchemaData, SchemaKind, VariantOrUnknownOrEmpty};
+
+/// A Rust type whose serialization is described by a [`Schema`].
+///
+/// It is implemented for the primitive types, `Option` (as a nullable
+/// schema), the collections of the standard library, tuples, smart pointers
+/// and the vectors, matrices and quaternions of `vek`. With the `derive`
+/// feature, `#[derive(AsyncApiSchema)]` implements it for structs and enums.
+///
+/// ```
+/// use asyncapi::ToSchema;
+///
+/// let schema = Option::<vek::Vec3<f32>>::schema();
+/// assert_eq!(schema.schema_data.nullable, Some(true));
+///
+/// let asyncapi::ReferenceOr::Item(level) = u8::property("Level of the player.", Some(&12)) else { panic!() };
+/// assert_eq!(level.schema_data.example, Some(serde_json::json!(12)));
+/// ```
+pub trait ToSchema {
+    fn schema() -> Schema;
+
+    /// The schema of the type with a description and a typed example, to be
+    /// used as a property of an object schema.
+    fn property(description: &str, example: Option<&Self>) -> ReferenceOr<Box<Schema>>
+    where
+        Self: Serialize,
+    {
+        let mut schema = Self::schema();
+        if !description.is_empty() {
+            schema.schema_data.description = Some(description.to_owned());
+        }
+        if let Some(example) = example {
+            schema.schema_data.example = serde_json::to_value(example).ok();
+        }
+        ReferenceOr::Item(Box::new(schema))
+    }
+}
+
+fn schema(typ: Type) -> Schema {
+    Schema {
+        schema_data: SchemaData::default(),
+        schema_kind: SchemaKind::Type(typ),
+    }
+}
+
+fn integer(format: Option<IntegerFormat>, minimum: Option<i64>, maximum: Option<i64>) -> Schema {
+    schema(Type::Integer(IntegerType {
+        format: format.map_or(VariantOrUnknownOrEmpty::Empty, VariantOrUnknownOrEmpty::Item),
+        minimum,
+        maximum,
+        ..Default::default()
+    }))
+}
+
+fn array(items: Schema, length: Option<usize>, unique_items: bool) -> Schema {
+    schema(Type::Array(ArrayType {
+        items: Some(Items::Schema(ReferenceOr::Item(Box::new(items)))),
+        min_items: length,
+        max_items: length,
+        unique_items,
+        ..Default::default()
+    }))
+}
+
+/// An object whose properties are all required.
+fn object(properties: Vec<(&str, Schema)>) -> Schema {
+    schema(Type::Object(ObjectType {
+        required: properties.iter().map(|(name, _)| (*name).to_owned()).collect(),
+        properties: properties.into_iter().map(|(name, schema)| (name.to_owned(), ReferenceOr::Item(Box::new(schema)))).collect(),
+        ..Default::default()
+    }))
+}
+
+fn map(values: Schema) -> Schema {
+    schema(Type::Object(ObjectType {
+        additional_properties: Some(AdditionalProperties::Schema(Box::new(ReferenceOr::Item(values)))),
+        ..Default::default()
+    }))
+}
+
+/// Tuples serialize as arrays of fixed length, with one schema per position.
+pub(crate) fn tuple(items: Vec<ReferenceOr<Box<Schema>>>) -> Schema {
+    let length = items.len();
+    schema(Type::Array(ArrayType {
+        items: Some(Items::Tuple(items)),
+        additional_items: Some(AdditionalProperties::Any(false)),
+        min_items: Some(length),
+        max_items: Some(length),
+        unique_items: false,
+        ..Default::default()
+    }))
+}
+
+impl ToSchema for bool {
+    fn schema() -> Schema {
+        schema(Type::Boolean(BooleanType::default()))
+    }
+}
+
+macro_rules! impl_integer {
+    ($($typ:ty => $format:expr, $minimum:expr, $maximum:expr;)*) => {$(
+        impl ToSchema for $typ {
+            fn schema() -> Schema {
+                integer($format, $minimum, $maximum)
+            }
+        }
+    )*};
+}
+
+impl_integer! {
+    i8 => Some(IntegerFormat::Int32), Some(i8::MIN.into()), Some(i8::MAX.into());
+    i16 => Some(IntegerFormat::Int32), Some(i16::MIN.into()), Some(i16::MAX.into());
+    i32 => Some(IntegerFormat::Int32), None, None;
+    i64 => Some(IntegerFormat::Int64), None, None;
+    isize => Some(IntegerFormat::Int64), None, None;
+    i128 => None, None, None;
+    u8 => Some(IntegerFormat::UInt32), Some(0), Some(u8::MAX.into());
+    u16 => Some(IntegerFormat::UInt32), Some(0), Some(u16::MAX.into());
+    u32 => Some(IntegerFormat::UInt32), Some(0), None;
+    u64 => Some(IntegerFormat::UInt64), Some(0), None;
+    usize => Some(IntegerFormat::UInt64), Some(0), None;
+    u128 => None, Some(0), None;
+}
+
+impl ToSchema for f32 {
+    fn schema() -> Schema {
+        schema(Type::Number(NumberType {
+            format: VariantOrUnknownOrEmpty::Item(NumberFormat::Float),
+            ..Default::default()
+        }))
+    }
+}
+
+impl ToSchema for f64 {
+    fn schema() -> Schema {
+        schema(Type::Number(NumberType {
+            format: VariantOrUnknownOrEmpty::Item(NumberFormat::Double),
+            ..Default::default()
+        }))
+    }
+}
+
+impl ToSchema for str {
+    fn schema() -> Schema {
+        schema(Type::String(StringType::default()))
+    }
+}
+
+impl ToSchema for String {
+    fn schema() -> Schema {
+        str::schema()
+    }
+}
+
+impl ToSchema for char {
+    fn schema() -> Schema {
+        schema(Type::String(StringType {
+            min_length: Some(1),
+            max_length: Some(1),
+            ..Default::default()
+        }))
+    }
+}
+
+/// The unit type serializes as `null`.
+impl ToSchema for () {
+    fn schema() -> Schema {
+        Schema {
+            schema_data: SchemaData {
+                nullable: Some(true),
+                ..Default::default()
+            },
+            schema_kind: SchemaKind::Any(Default::default()),
+        }
+    }
+}
+
+impl<T: ToSchema> ToSchema for Option<T> {
+    fn schema() -> Schema {
+        let mut schema = T::schema();
+        schema.schema_data.nullable = Some(true);
+        schema
+    }
+}
+
+macro_rules! impl_pointer {
+    ($($pointer:ty),*) => {$(
+        impl<T: ToSchema + ?Sized> ToSchema for $pointer {
+            fn schema() -> Schema {
+                T::schema()
+            }
+        }
+    )*};
+}
+
+impl_pointer!(&T, Box<T>, Rc<T>, Arc<T>);
+
+impl<T: ToSchema + ToOwned + ?Sized> ToSchema for Cow<'_, T> {
+    fn schema() -> Schema {
+        T::schema()
+    }
+}
+
+macro_rules! impl_sequence {
+    ($($sequence:ty => $unique_items:expr),*) => {$(
+        impl<T: ToSchema> ToSchema for $sequence {
+            fn schema() -> Schema {
+                array(T::schema(), None, $unique_items)
+            }
+        }
+    )*};
+}
+
+impl_sequence!([T] => false, Vec<T> => false, VecDeque<T> => false, BTreeSet<T> => true);
+
+impl<T: ToSchema, S> ToSchema for HashSet<T, S> {
+    fn schema() -> Schema {
+        array(T::schema(), None, true)
+    }
+}
+
+impl<T: ToSchema, const N: usize> ToSchema for [T; N] {
+    fn schema() -> Schema {
+        array(T::schema(), Some(N), false)
+    }
+}
+
+impl<T: ToSchema, S> ToSchema for HashMap<String, T, S> {
+    fn schema() -> Schema {
+        map(T::schema())
+    }
+}
+
+impl<T: ToSchema> ToSchema for BTreeMap<String, T> {
+    fn schema() -> Schema {
+        map(T::schema())
+    }
+}
+
+impl<T: ToSchema, S> ToSchema for IndexMap<String, T, S> {
+    fn schema() -> Schema {
+        map(T::schema())
+    }
+}
+
+macro_rules! impl_tuple {
+    ($(($($name:ident),+))*) => {$(
+        impl<$($name: ToSchema),+> ToSchema for ($($name,)+) {
+            fn schema() -> Schema {
+                tuple(vec![$(ReferenceOr::Item(Box::new($name::schema()))),+])
+            }
+        }
+    )*};
+}
+
+impl_tuple! {
+    (A)
+    (A, B)
+    (A, B, C)
+    (A, B, C, D)
+    (A, B, C, D, E)
+    (A, B, C, D, E, F)
+    (A, B, C, D, E, F, G)
+    (A, B, C, D, E, F, G, H)
+    (A, B, C, D, E, F, G, H, I)
+    (A, B, C, D, E, F, G, H, I, J)
+    (A, B, C, D, E, F, G, H, I, J, K)
+    (A, B, C, D, E, F, G, H, I, J, K, L)
+}
+
+/// `vek` types with named fields serialize as objects.
+macro_rules! impl_vek_struct {
+    ($($typ:ident { $($field:ident),+ })*) => {$(
+        impl<T: ToSchema> ToSchema for $typ<T> {
+            fn schema() -> Schema {
+                object(vec![$((stringify!($field), T::schema())),+])
+            }
+        }
+    )*};
+}
+
+impl_vek_struct! {
+    Vec2 { x, y }
+    Vec3 { x, y, z }
+    Vec4 { x, y, z, w }
+    Extent2 { w, h }
+    Extent3 { w, h, d }
+    Rgb { r, g, b }
+    Rgba { r, g, b, a }
+    Uv { u, v }
+    Uvw { u, v, w }
+    Quaternion { x, y, z, w }
+}
+
+/// The larger `vek` vectors are tuple structs, which serialize as arrays.
+macro_rules! impl_vek_tuple {
+    ($($typ:ident => $length:expr),*) => {$(
+        impl<T: ToSchema> ToSchema for $typ<T> {
+            fn schema() -> Schema {
+                array(T::schema(), Some($length), false)
+            }
+        }
+    )*};
+}
+
+impl_vek_tuple!(Vec8 => 8, Vec16 => 16, Vec32 => 32, Vec64 => 64);
+
+/// `vek` matrices serialize as an object with their `cols` or `rows`, each a
+/// vector of vectors.
+macro_rules! impl_vek_matrix {
+    ($($module:ident :: $typ:ident => $lines:ident: $vector:ident),*) => {$(
+        impl<T: ToSchema> ToSchema for $module::$typ<T> {
+            fn schema() -> Schema {
+                object(vec![(stringify!($lines), $vector::<$vector<T>>::schema())])
+            }
+        }
+    )*};
+}
+
+impl_vek_matrix! {
+    column_major::Mat2 => cols: Vec2,
+    column_major::Mat3 => cols: Vec3,
+    column_major::Mat4 => cols: Vec4,
+    row_major::Mat2 => rows: Vec2,
+    row_major::Mat3 => rows: Vec3,
+    row_major::Mat4 => rows: Vec4
+}
+
+#[test]
+fn test_to_schema() {
+    use serde_json::json;
+
+    assert_eq!(
+        serde_json::to_value(u8::schema()).unwrap(),
+        json!({ "type": "integer", "format": "uint32", "minimum": 0, "maximum": 255 })
+    );
+    assert_eq!(serde_json::to_value(Option::<bool>::schema()).unwrap(), json!({ "type": "boolean", "nullable": true }));
+    assert_eq!(serde_json::to_value(<[u16; 3]>::schema()).unwrap()["maxItems"], 3);
+    assert_eq!(
+        serde_json::to_value(HashMap::<String, Box<f32>>::schema()).unwrap(),
+        json!({ "type": "object", "additionalProperties": { "type": "number", "format": "float" } })
+    );
+    assert_eq!(
+        serde_json::to_value(<(String, bool)>::schema()).unwrap(),
+        json!({
+            "type": "array",
+            "items": [{ "type": "string" }, { "type": "boolean" }],
+            "additionalItems": false,
+            "minItems": 2,
+            "maxItems": 2
+        })
+    );
+
+    let color = Rgba::new(255u8, 128, 0, 255);
+    let ReferenceOr::Item(property) = Rgba::<u8>::property("Color of the player.", Some(&color)) else {
+        panic!("expected an inline schema")
+    };
+    assert_eq!(property.schema_data.example, Some(json!({ "r": 255, "g": 128, "b": 0, "a": 255 })));
+    assert_eq!(property.schema_data.description.as_deref(), Some("Color of the player."));
+    assert!(property.validate(property.schema_data.example.as_ref().unwrap()).is_ok());
+    assert!(property.validate(&json!({ "r": 256, "g": 0, "b": 0, "a": 0 })).is_err());
+
+    #[allow(deprecated)]
+    let ReferenceOr::Item(level) = <i32 as crate::schema::XValue>::create_propertie(String::new(), "12".to_owned(), String::new()) else {
+        panic!("expected an inline schema")
+    };
+    assert_eq!(level.schema_data.example, Some(json!(12)));
+
+    let matrix = serde_json::to_value(vek::Mat2::<f64>::schema()).unwrap();
+    assert_eq!(matrix["required"], json!(["cols"]));
+    assert_eq!(matrix["properties"]["cols"]["properties"]["y"]["properties"]["x"]["format"], "double");
+    let position = vek::Vec3::new(1.5f32, 0.0, -2.0);
+    assert!(Vec3::<f32>::schema().validate(&serde_json::to_value(position).unwrap()).is_ok());
+}
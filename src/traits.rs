@@ -0,0 +1,224 @@
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{Component, Components, Message, MessageTrait, Operation, ReferenceOr, ResolveError};
+
+impl Operation {
+    /// Returns the effective operation, with all of its
+    /// [traits][crate::OperationTrait] applied and the `traits` list emptied.
+    ///
+    /// The traits are merged in the order they are listed using the
+    /// [JSON Merge Patch](https://tools.ietf.org/html/rfc7386) algorithm, and
+    /// the operation itself is merged last, so that its own values win over
+    /// the ones of its traits. Objects such as `bindings` and extensions are
+    /// merged key by key, `tags` are merged by tag name.
+    pub fn with_traits_applied(&self, components: &Components) -> Result<Operation, ResolveError> {
+        let mut patches = Vec::with_capacity(self.traits.len() + 1);
+        for operation_trait in &self.traits {
+            let mut operation_trait = components.resolve(operation_trait)?.clone();
+            inline(components, &mut operation_trait.bindings)?;
+            patches.push(to_value(&operation_trait));
+        }
+        let mut operation = Operation { traits: Vec::new(), ..self.clone() };
+        inline(components, &mut operation.bindings)?;
+        patches.push(to_value(&operation));
+        merge(patches)
+    }
+}
+
+impl Message {
+    /// Returns the effective message, with all of its
+    /// [traits][crate::MessageTrait] applied and the `traits` list emptied.
+    ///
+    /// The merge follows the same rules as
+    /// [`Operation::with_traits_applied`]. The `headers`, `correlationId` and
+    /// `bindings` of the message and of its traits are resolved before being
+    /// merged.
+    pub fn with_traits_applied(&self, components: &Components) -> Result<Message, ResolveError> {
+        let mut patches = Vec::with_capacity(self.traits.len() + 1);
+        for message_trait in &self.traits {
+            let mut message_trait: MessageTrait = components.resolve(message_trait)?.clone();
+            inline(components, &mut message_trait.headers)?;
+            inline(components, &mut message_trait.correlation_id)?;
+            inline(components, &mut message_trait.bindings)?;
+            patches.push(to_value(&message_trait));
+        }
+        let mut message = Message { traits: Vec::new(), ..self.clone() };
+        inline(components, &mut message.headers)?;
+        inline(components, &mut message.correlation_id)?;
+        inline(components, &mut message.bindings)?;
+        patches.push(to_value(&message));
+        merge(patches)
+    }
+}
+
+/// Replaces a reference by a copy of the component it points to, so that it
+/// can be merged with the corresponding field of another object.
+fn inline<T: Component + Clone>(components: &Components, item: &mut Option<ReferenceOr<T>>) -> Result<(), ResolveError> {
+    if let Some(ReferenceOr::Reference { reference }) = item {
+        *item = Some(ReferenceOr::Item(components.resolve_reference::<T>(reference)?.clone()));
+    }
+    Ok(())
+}
+
+fn to_value<T: Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).expect("AsyncAPI objects serialize to JSON")
+}
+
+/// Applies each patch in order onto an empty object and deserializes the result.
+fn merge<T: DeserializeOwned>(patches: Vec<Value>) -> Result<T, ResolveError> {
+    let mut target = Value::Object(Map::new());
+    for mut patch in patches {
+        merge_tags(&target, &mut patch);
+        merge_patch(&mut target, patch);
+    }
+    serde_json::from_value(target).map_err(|error| ResolveError::Merge(error.to_string()))
+}
+
+/// Merges `patch` into `target` as described by
+/// [RFC 7386](https://tools.ietf.org/html/rfc7386).
+fn merge_patch(target: &mut Value, patch: Value) {
+    match patch {
+        Value::Object(patch) => {
+            if !target.is_object() {
+                *target = Value::Object(Map::new());
+            }
+            let target = target.as_object_mut().expect("target was made an object");
+            for (key, value) in patch {
+                if value.is_null() {
+                    target.remove(&key);
+                } else {
+                    merge_patch(target.entry(key).or_insert(Value::Null), value);
+                }
+            }
+        }
+        patch => *target = patch,
+    }
+}
+
+/// Merge patch replaces arrays as a whole, but tags are accumulated: the
+/// tags of `patch` are extended with the tags of `target` whose name is not
+/// used by `patch`.
+fn merge_tags(target: &Value, patch: &mut Value) {
+    let (Some(existing), Some(Value::Array(tags))) = (target.get("tags").and_then(Value::as_array), patch.get_mut("tags")) else {
+        return;
+    };
+    let missing: Vec<Value> = existing.iter().filter(|tag| !tags.iter().any(|patch_tag| patch_tag.get("name") == tag.get("name"))).cloned().collect();
+    tags.extend(missing);
+}
+
+#[test]
+fn test_apply_traits() {
+    let example = r##"
+    asyncapi: 2.6.0
+    info:
+      title: Traits
+      version: 1.0.0
+    channels:
+      user/signedup:
+        subscribe:
+          operationId: onUserSignedUp
+          tags:
+            - name: user
+              description: Own description
+          bindings:
+            kafka:
+              clientId:
+                type: string
+          traits:
+            - $ref: "#/components/operationTraits/kafka"
+          message:
+            contentType: application/json
+            traits:
+              - $ref: "#/components/messageTraits/commonHeaders"
+    components:
+      operationTraits:
+        kafka:
+          operationId: overridden
+          summary: From the trait
+          tags:
+            - name: user
+              description: Trait description
+            - name: kafka
+          bindings:
+            kafka:
+              groupId:
+                type: string
+      messageTraits:
+        commonHeaders:
+          contentType: application/avro
+          headers:
+            type: object
+            properties:
+              my-app-header:
+                type: integer
+    "##;
+    let asyncapi: crate::AsyncAPI = serde_yaml::from_str(example).expect("Could not deserialize traits example");
+    let components = asyncapi.components.as_ref().unwrap();
+    let operation = asyncapi.channels["user/signedup"].subscribe.as_ref().unwrap();
+
+    let effective = operation.with_traits_applied(components).unwrap();
+    assert!(effective.traits.is_empty());
+    assert_eq!(effective.operation_id.as_deref(), Some("onUserSignedUp"));
+    assert_eq!(effective.summary.as_deref(), Some("From the trait"));
+    let tags: Vec<_> = effective.tags.iter().map(|tag| (tag.name.as_str(), tag.description.as_deref())).collect();
+    assert_eq!(tags, vec![("user", Some("Own description")), ("kafka", None)]);
+    let bindings = match effective.bindings {
+        Some(ReferenceOr::Item(bindings)) => bindings.kafka.unwrap(),
+        other => panic!("bindings were not merged: {other:?}"),
+    };
+    assert!(bindings.group_id.is_some() && bindings.client_id.is_some());
+
+    let message = match &operation.message {
+        Some(crate::OperationMessageType::Single(ReferenceOr::Item(message))) => message.with_traits_applied(components).unwrap(),
+        other => panic!("unexpected message: {other:?}"),
+    };
+    assert_eq!(message.content_type.as_deref(), Some("application/json"));
+    assert!(message.headers.is_some());
+
+    let components: Components = serde_yaml::from_str(
+        r#"
+        messageTraits:
+          protobuf:
+            schemaFormat: application/vnd.google.protobuf
+        "#,
+    )
+    .unwrap();
+    let message: Message = serde_yaml::from_str(
+        r##"
+        payload:
+          type: object
+        traits:
+          - $ref: "#/components/messageTraits/protobuf"
+        "##,
+    )
+    .unwrap();
+    assert!(matches!(message.with_traits_applied(&components), Err(ResolveError::Merge(_))));
+}
+
+#[test]
+fn test_apply_inherited_schema_format() {
+    let components: Components = serde_yaml::from_str(
+        r#"
+        messageTraits:
+          avro:
+            schemaFormat: application/vnd.apache.avro;version=1.9.0
+        "#,
+    )
+    .unwrap();
+    let message: Message = serde_yaml::from_str(
+        r##"
+        payload:
+          type: record
+          name: User
+          fields:
+            - { name: name, type: string }
+        traits:
+          - $ref: "#/components/messageTraits/avro"
+        "##,
+    )
+    .unwrap();
+    assert!(matches!(message.payload, Some(crate::Payload::Any(_))));
+    let message = message.with_traits_applied(&components).unwrap();
+    assert!(matches!(message.payload, Some(crate::Payload::Avro(_))));
+}
@@ -0,0 +1,214 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::{Channel, Components, Info, Operation, Server};
+use crate::ReferenceOr;
+
+/// This is the root document object for an AsyncAPI 3.0 specification.
+/// It combines resource listing and API declaration together into one document.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AsyncAPI {
+    /// **REQUIRED.** Specifies the AsyncAPI Specification version being used,
+    /// e.g. `3.0.0`.
+    pub asyncapi: String,
+    /// Identifier of the application the AsyncAPI document is defining.
+    /// It must conform to the URI format, according to
+    /// [RFC3986](https://tools.ietf.org/html/rfc3986).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// **REQUIRED.** Provides metadata about the API.
+    /// The metadata can be used by the clients if needed.
+    pub info: Info,
+    /// Provides connection details of servers.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub servers: IndexMap<String, ReferenceOr<Server>>,
+    /// Default content type to use when encoding/decoding a message's payload.
+    /// The value MUST be a specific media type (e.g. `application/json`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_content_type: Option<String>,
+    /// The channels used by this application, keyed by an identifier which
+    /// is unique within the document.
+    ///
+    /// # Examples
+    /// ```yaml
+    /// userSignedUp:
+    ///   address: 'user.signedup'
+    ///   messages:
+    ///     userSignedUp:
+    ///       $ref: '#/components/messages/userSignedUp'
+    /// ```
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub channels: IndexMap<String, ReferenceOr<Channel>>,
+    /// The operations this application MUST implement, keyed by an
+    /// identifier which is unique within the document.
+    ///
+    /// # Examples
+    /// ```yaml
+    /// onUserSignUp:
+    ///   title: User sign up
+    ///   action: send
+    ///   channel:
+    ///     $ref: '#/channels/userSignup'
+    /// ```
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub operations: IndexMap<String, ReferenceOr<Operation>>,
+    /// An element to hold various reusable objects for the specification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Components>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten)]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+#[test]
+fn test_round_trip_v3() {
+    let example = r##"
+    asyncapi: 3.0.0
+    id: urn:example:com:smartylighting:streetlights:server
+    info:
+      title: Streetlights MQTT API
+      version: 1.0.0
+      description: The Smartylighting Streetlights API allows you to remotely manage the city lights.
+      license:
+        name: Apache 2.0
+        url: https://www.apache.org/licenses/LICENSE-2.0
+      tags:
+        - name: lights
+        - $ref: '#/components/tags/city'
+    defaultContentType: application/json
+    servers:
+      production:
+        host: test.mosquitto.org:{port}
+        protocol: mqtt
+        description: Test broker
+        variables:
+          port:
+            description: Secure connection (TLS) is available through port 8883.
+            default: '1883'
+            enum:
+              - '1883'
+              - '8883'
+        security:
+          - $ref: '#/components/securitySchemes/saslScram'
+          - type: oauth2
+            flows:
+              clientCredentials:
+                tokenUrl: https://example.com/api/oauth/token
+                availableScopes:
+                  streetlights:on: Ability to switch lights on
+            scopes:
+              - streetlights:on
+    channels:
+      lightingMeasured:
+        address: smartylighting/streetlights/1/0/event/{streetlightId}/lighting/measured
+        messages:
+          lightMeasured:
+            $ref: '#/components/messages/lightMeasured'
+        description: The topic on which measured values may be produced and consumed.
+        parameters:
+          streetlightId:
+            $ref: '#/components/parameters/streetlightId'
+      lightsDim:
+        address: smartylighting/streetlights/1/0/action/{streetlightId}/dim
+        messages:
+          dimLight:
+            $ref: '#/components/messages/dimLight'
+        parameters:
+          streetlightId:
+            $ref: '#/components/parameters/streetlightId'
+        servers:
+          - $ref: '#/servers/production'
+      replies:
+        address: null
+        messages:
+          ack:
+            payload:
+              type: string
+    operations:
+      receiveLightMeasurement:
+        action: receive
+        channel:
+          $ref: '#/channels/lightingMeasured'
+        summary: Inform about environmental lighting conditions of a particular streetlight.
+        traits:
+          - $ref: '#/components/operationTraits/mqtt'
+        messages:
+          - $ref: '#/channels/lightingMeasured/messages/lightMeasured'
+      dimLight:
+        action: send
+        channel:
+          $ref: '#/channels/lightsDim'
+        messages:
+          - $ref: '#/channels/lightsDim/messages/dimLight'
+        reply:
+          address:
+            description: Consumer inbox
+            location: $message.header#/replyTo
+          channel:
+            $ref: '#/channels/replies'
+          messages:
+            - $ref: '#/channels/replies/messages/ack'
+    components:
+      tags:
+        city:
+          name: city
+      messages:
+        lightMeasured:
+          name: lightMeasured
+          title: Light measured
+          contentType: application/json
+          traits:
+            - $ref: '#/components/messageTraits/commonHeaders'
+          payload:
+            $ref: '#/components/schemas/lightMeasuredPayload'
+        dimLight:
+          name: dimLight
+          payload:
+            schemaFormat: application/vnd.apache.avro;version=1.9.0
+            schema:
+              type: record
+              name: DimLight
+              fields:
+                - name: percentage
+                  type: int
+      schemas:
+        lightMeasuredPayload:
+          type: object
+          properties:
+            lumens:
+              type: integer
+              minimum: 0
+      securitySchemes:
+        saslScram:
+          type: scramSha256
+          description: Provide your username and password for SASL/SCRAM authentication
+      parameters:
+        streetlightId:
+          description: The ID of the streetlight.
+      replyAddresses:
+        inbox:
+          location: $message.header#/replyTo
+      messageTraits:
+        commonHeaders:
+          headers:
+            type: object
+            properties:
+              my-app-header:
+                type: integer
+          examples:
+            - name: header
+              headers:
+                my-app-header: 12
+      operationTraits:
+        mqtt:
+          bindings:
+            mqtt:
+              qos: 1
+    "##;
+    let value: serde_json::Value = serde_yaml::from_str(example).expect("Could not deserialize v3 example as a value");
+    let asyncapi: AsyncAPI = serde_json::from_value(value.clone()).expect("Could not deserialize v3 example");
+    assert!(asyncapi.extensions.is_empty());
+    assert_eq!(serde_json::to_value(&asyncapi).unwrap(), value);
+}
@@ -0,0 +1,81 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::{Message, Parameter, Reference};
+use crate::{ChannelBinding, ExternalDocumentation, ReferenceOr, Tag};
+
+/// Describes a shared communication channel.
+///
+/// # Examples
+/// ```yaml
+/// address: 'users.{userId}'
+/// title: Users channel
+/// description: This channel is used to exchange messages about user events.
+/// messages:
+///   userSignedUp:
+///     $ref: '#/components/messages/userSignedUp'
+///   userCompletedOrder:
+///     $ref: '#/components/messages/userCompletedOrder'
+/// parameters:
+///   userId:
+///     $ref: '#/components/parameters/userId'
+/// servers:
+///   - $ref: '#/servers/rabbitmqInProd'
+///   - $ref: '#/servers/rabbitmqInStaging'
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Channel {
+    /// An optional string representation of this channel's address, which
+    /// MAY contain `{param}` expressions defined in `parameters`.
+    ///
+    /// `None` when the field is omitted and `Some(None)` when it is `null`;
+    /// both mean that the address is unknown or dynamic.
+    #[serde(default, deserialize_with = "deserialize_nullable", skip_serializing_if = "Option::is_none")]
+    pub address: Option<Option<String>>,
+    /// A map of the messages that will be sent to this channel by any
+    /// application at any time.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub messages: IndexMap<String, ReferenceOr<Message>>,
+    /// A human-friendly title for the channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A short summary of the channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// An optional description of this channel.
+    /// [CommonMark syntax](https://spec.commonmark.org/) can be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// References to the `servers` of the document this channel is
+    /// available on. If empty, the channel is available on all servers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub servers: Vec<Reference>,
+    /// A map of the parameters included in the channel address.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub parameters: IndexMap<String, ReferenceOr<Parameter>>,
+    /// A list of tags for logical grouping of channels.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ReferenceOr<Tag>>,
+    /// Additional external documentation for this channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ReferenceOr<ExternalDocumentation>>,
+    /// A map where the keys describe the name of the protocol and the values
+    /// describe protocol-specific definitions for the channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<ReferenceOr<ChannelBinding>>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten)]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+/// Distinguishes a field set to `null` from a missing field, which is
+/// handled by `#[serde(default)]`.
+pub(crate) fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::deserialize(deserializer).map(Some)
+}
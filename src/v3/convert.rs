@@ -0,0 +1,651 @@
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::{
+    AsyncAPI, Channel, Components, Info, Message, MessageTrait, MultiFormatSchema, Operation, OperationAction, OperationTrait, Parameter, Reference, SchemaOrMultiFormat, SecurityScheme, Server,
+};
+use crate::{
+    resolver::{decode_pointer_token, escape_pointer_token},
+    Diagnostic, MessageExample, OperationMessageType, Payload, ReferenceOr, SecurityRequirement, Severity,
+};
+
+/// The schema formats whose payloads are written as AsyncAPI Schema
+/// Objects in both versions, and therefore need no Multi Format Schema Object.
+const DEFAULT_SCHEMA_FORMATS: &[&str] = &["application/vnd.aai.asyncapi", "application/schema+json;version=draft-07", "application/schema+yaml;version=draft-07"];
+
+/// The result of [`convert_v2_to_v3_with_warnings`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Converted {
+    /// The converted document.
+    pub asyncapi: AsyncAPI,
+    /// The constructs of the 2.x document which could not be mapped 1:1,
+    /// located by a JSON Pointer into the 2.x document.
+    pub warnings: Vec<Diagnostic>,
+}
+
+/// Converts an AsyncAPI 2.x document into an AsyncAPI 3.0 document,
+/// discarding the warnings of [`convert_v2_to_v3_with_warnings`].
+pub fn convert_v2_to_v3(asyncapi: &crate::AsyncAPI) -> AsyncAPI {
+    convert_v2_to_v3_with_warnings(asyncapi).asyncapi
+}
+
+/// Converts an AsyncAPI 2.x document into an AsyncAPI 3.0 document,
+/// following the rules of the official
+/// [converter](https://github.com/asyncapi/converter-js):
+///
+/// - channels are keyed by their `x-channelId` extension or their name, which
+///   becomes their `address`,
+/// - the `publish` operation of a channel becomes a `receive` operation and
+///   the `subscribe` operation a `send` operation, keyed by their
+///   `operationId` or by `<channelId>.<publish|subscribe>`,
+/// - the messages of the operations are moved into the `messages` of the
+///   channel, keyed by their `messageId`, their `name` or the name of the
+///   component they reference, and the operations reference them,
+/// - payloads in a schema format other than AsyncAPI or JSON Schema become
+///   [Multi Format Schema Objects][super::MultiFormatSchema],
+/// - the `url` of servers is split into `host` and `pathname`,
+/// - security requirements become references to the security schemes,
+///   schemes requiring scopes are inlined with their `scopes`,
+/// - the schemas of channel parameters are reduced to `enum`, `default` and
+///   `examples`,
+/// - the `tags` and `externalDocs` of the document move into the `info`.
+///
+/// A warning is emitted for every construct which is dropped or changes
+/// meaning in the process.
+pub fn convert_v2_to_v3_with_warnings(asyncapi: &crate::AsyncAPI) -> Converted {
+    let mut converter = Converter {
+        document: asyncapi,
+        warnings: Vec::new(),
+    };
+    let asyncapi = converter.document();
+    Converted {
+        asyncapi,
+        warnings: converter.warnings,
+    }
+}
+
+struct Converter<'a> {
+    document: &'a crate::AsyncAPI,
+    warnings: Vec<Diagnostic>,
+}
+
+fn pointer(parent: &str, token: &str) -> String {
+    format!("{parent}/{}", escape_pointer_token(token))
+}
+
+/// Returns `key`, or `key` suffixed with the first free `_1`, `_2`, ... if
+/// it is already used in the map.
+fn unique_key<T>(map: &IndexMap<String, T>, key: String) -> String {
+    if !map.contains_key(&key) {
+        return key;
+    }
+    (1..).map(|index| format!("{key}_{index}")).find(|candidate| !map.contains_key(candidate)).expect("unbounded range")
+}
+
+fn value_to_string(value: Value) -> String {
+    match value {
+        Value::String(value) => value,
+        value => value.to_string(),
+    }
+}
+
+fn items<T: Clone>(items: &[T]) -> Vec<ReferenceOr<T>> {
+    items.iter().cloned().map(ReferenceOr::Item).collect()
+}
+
+impl<'a> Converter<'a> {
+    /// Converts the references as is, and the items with `f`.
+    fn convert_ref<T, U>(&mut self, path: &str, item: &ReferenceOr<T>, f: impl FnOnce(&mut Self, &str, &T) -> U) -> ReferenceOr<U> {
+        match item {
+            ReferenceOr::Reference { reference } => ReferenceOr::Reference { reference: reference.clone() },
+            ReferenceOr::Item(item) => ReferenceOr::Item(f(self, path, item)),
+        }
+    }
+
+    fn convert_map<T, U>(&mut self, path: &str, items: &IndexMap<String, ReferenceOr<T>>, f: impl Fn(&mut Self, &str, &T) -> U) -> IndexMap<String, ReferenceOr<U>> {
+        items.iter().map(|(name, item)| (name.clone(), self.convert_ref(&pointer(path, name), item, &f))).collect()
+    }
+
+    fn warn(&mut self, path: String, message: String) {
+        self.warnings.push(Diagnostic {
+            path,
+            severity: Severity::Warning,
+            message,
+        });
+    }
+
+    /// Serializes an object to be converted as JSON, or warns that it is
+    /// dropped.
+    fn json<T: Serialize>(&mut self, path: &str, value: &T) -> Option<Value> {
+        match serde_json::to_value(value) {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.warn(path.to_owned(), format!("the object can not be converted and is dropped: {error}"));
+                None
+            }
+        }
+    }
+
+    fn document(&mut self) -> AsyncAPI {
+        let document = self.document;
+        let info = Info {
+            title: document.info.title.clone(),
+            version: document.info.version.clone(),
+            description: document.info.description.clone(),
+            terms_of_service: document.info.terms_of_service.clone(),
+            contact: document.info.contact.clone(),
+            license: document.info.license.clone(),
+            tags: items(&document.tags),
+            external_docs: document.external_docs.clone().map(ReferenceOr::Item),
+            extensions: document.info.extensions.clone(),
+        };
+        let servers = self.convert_map("/servers", &document.servers, Self::server);
+        let mut channels = IndexMap::new();
+        let mut operations = IndexMap::new();
+        for (name, channel) in &document.channels {
+            let id = channel.extensions.get("x-channelId").and_then(Value::as_str).unwrap_or(name).to_owned();
+            let id = unique_key(&channels, id);
+            let reference = format!("#/channels/{}", escape_pointer_token(&id));
+            let channel = self.channel(&pointer("/channels", name), &id, Some(name), &reference, channel, &mut operations);
+            channels.insert(id, ReferenceOr::Item(channel));
+        }
+        let components = document.components.as_ref().map(|components| self.components(components));
+        AsyncAPI {
+            asyncapi: "3.0.0".to_owned(),
+            id: document.id.clone(),
+            info,
+            servers,
+            default_content_type: document.default_content_type.clone(),
+            channels,
+            operations,
+            components,
+            extensions: document.extensions.clone(),
+        }
+    }
+
+    fn server(&mut self, path: &str, server: &crate::Server) -> Server {
+        let (scheme, rest) = match server.url.split_once("://") {
+            Some((scheme, rest)) => (Some(scheme), rest),
+            None => (None, server.url.as_str()),
+        };
+        if let Some(scheme) = scheme.filter(|scheme| !scheme.eq_ignore_ascii_case(&server.protocol)) {
+            self.warn(
+                pointer(path, "url"),
+                format!("the scheme `{scheme}` of the url differs from the protocol `{}` and is dropped", server.protocol),
+            );
+        }
+        let (host, pathname) = match rest.find('/') {
+            Some(index) => (&rest[..index], Some(rest[index..].to_owned())),
+            None => (rest, None),
+        };
+        Server {
+            host: host.to_owned(),
+            protocol: server.protocol.clone(),
+            protocol_version: server.protocol_version.clone(),
+            pathname,
+            description: server.description.clone(),
+            variables: server.variables.iter().map(|(name, variable)| (name.clone(), ReferenceOr::Item(variable.clone()))).collect(),
+            security: self.security(&pointer(path, "security"), &server.security),
+            bindings: server.bindings.clone(),
+            extensions: server.extensions.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Security requirements list alternatives of schemes which must all be
+    /// satisfied, AsyncAPI 3 only lists alternatives of single schemes.
+    fn security(&mut self, path: &str, requirements: &[SecurityRequirement]) -> Vec<ReferenceOr<SecurityScheme>> {
+        let mut security = Vec::new();
+        for (index, requirement) in requirements.iter().enumerate() {
+            let path = pointer(path, &index.to_string());
+            if requirement.values.len() > 1 {
+                self.warn(
+                    path.clone(),
+                    "the security schemes of this requirement must all be satisfied, they are converted into alternatives".to_owned(),
+                );
+            }
+            for (name, scopes) in &requirement.values {
+                let reference = format!("#/components/securitySchemes/{}", escape_pointer_token(name));
+                if scopes.is_empty() {
+                    security.push(ReferenceOr::Reference { reference });
+                    continue;
+                }
+                let scheme = self
+                    .document
+                    .components
+                    .as_ref()
+                    .and_then(|components| components.resolve_reference::<crate::SecurityScheme>(&reference).ok());
+                let Some(scheme) = scheme else {
+                    self.warn(pointer(&path, name), format!("the scopes of the undeclared security scheme `{name}` are dropped"));
+                    security.push(ReferenceOr::Reference { reference });
+                    continue;
+                };
+                match self.security_scheme(reference.trim_start_matches('#'), scheme) {
+                    Some(SecurityScheme::OAuth2 { description, flows, extensions, .. }) => security.push(ReferenceOr::Item(SecurityScheme::OAuth2 {
+                        description,
+                        flows,
+                        scopes: scopes.clone(),
+                        extensions,
+                    })),
+                    Some(SecurityScheme::OpenIdConnect {
+                        description,
+                        open_id_connect_url,
+                        extensions,
+                        ..
+                    }) => security.push(ReferenceOr::Item(SecurityScheme::OpenIdConnect {
+                        description,
+                        open_id_connect_url,
+                        scopes: scopes.clone(),
+                        extensions,
+                    })),
+                    _ => {
+                        self.warn(pointer(&path, name), format!("the security scheme `{name}` does not use scopes, its scopes are dropped"));
+                        security.push(ReferenceOr::Reference { reference });
+                    }
+                }
+            }
+        }
+        security
+    }
+
+    /// Security schemes have the same structure in both versions, except for
+    /// the `scopes` of the OAuth flows which are renamed `availableScopes`.
+    fn security_scheme(&mut self, path: &str, scheme: &crate::SecurityScheme) -> Option<SecurityScheme> {
+        let mut value = self.json(path, scheme)?;
+        if let Some(flows) = value.get_mut("flows").and_then(Value::as_object_mut) {
+            for flow in flows.values_mut().filter_map(Value::as_object_mut) {
+                if let Some(scopes) = flow.remove("scopes") {
+                    flow.insert("availableScopes".to_owned(), scopes);
+                }
+            }
+        }
+        match serde_json::from_value(value) {
+            Ok(scheme) => Some(scheme),
+            Err(error) => {
+                self.warn(path.to_owned(), format!("the security scheme can not be converted and is dropped: {error}"));
+                None
+            }
+        }
+    }
+
+    #[allow(deprecated)]
+    fn channel(&mut self, path: &str, id: &str, address: Option<&str>, reference: &str, channel: &crate::Channel, operations: &mut IndexMap<String, ReferenceOr<Operation>>) -> Channel {
+        if let Some(channel_reference) = &channel.reference {
+            self.warn(pointer(path, "$ref"), format!("the deprecated channel reference `{channel_reference}` is dropped"));
+        }
+        let mut converted = Channel {
+            address: address.map(|address| Some(address.to_owned())),
+            description: channel.description.clone(),
+            servers: channel.servers.iter().map(|server| Reference::new(format!("#/servers/{}", escape_pointer_token(server)))).collect(),
+            parameters: self.convert_map(&pointer(path, "parameters"), &channel.parameters, Self::parameter),
+            bindings: channel.bindings.clone(),
+            extensions: channel.extensions.clone(),
+            ..Default::default()
+        };
+        converted.extensions.shift_remove("x-channelId");
+        for (field, action, operation) in [("publish", OperationAction::Receive, &channel.publish), ("subscribe", OperationAction::Send, &channel.subscribe)] {
+            let Some(operation) = operation else {
+                continue;
+            };
+            let path = pointer(path, field);
+            let operation_id = operation.operation_id.clone().unwrap_or_else(|| format!("{id}.{field}"));
+            let mut messages = Vec::new();
+            for (key, message) in self.operation_messages(&pointer(&path, "message"), &operation_id, operation.message.as_ref()) {
+                let key = match converted.messages.get(&key) {
+                    Some(existing) if *existing == message => key,
+                    _ => unique_key(&converted.messages, key),
+                };
+                messages.push(Reference::new(format!("{reference}/messages/{}", escape_pointer_token(&key))));
+                converted.messages.insert(key, message);
+            }
+            let converted_operation = Operation {
+                action,
+                channel: Reference::new(reference),
+                title: None,
+                summary: operation.summary.clone(),
+                description: operation.description.clone(),
+                security: self.security(&pointer(&path, "security"), &operation.security),
+                tags: items(&operation.tags),
+                external_docs: operation.external_docs.clone().map(ReferenceOr::Item),
+                bindings: operation.bindings.clone(),
+                traits: operation
+                    .traits
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| self.convert_ref(&pointer(&pointer(&path, "traits"), &index.to_string()), item, Self::operation_trait))
+                    .collect(),
+                messages,
+                reply: None,
+                extensions: operation.extensions.clone(),
+            };
+            if operations.contains_key(&operation_id) {
+                self.warn(pointer(&path, "operationId"), format!("operationId `{operation_id}` is already used, the operation is renamed"));
+            }
+            operations.insert(unique_key(operations, operation_id), ReferenceOr::Item(converted_operation));
+        }
+        converted
+    }
+
+    /// Returns the messages of an operation, keyed by the name they get in
+    /// the `messages` of the channel.
+    fn operation_messages(&mut self, path: &str, operation_id: &str, message: Option<&OperationMessageType>) -> Vec<(String, ReferenceOr<Message>)> {
+        match message {
+            None => Vec::new(),
+            Some(OperationMessageType::Map(messages)) => messages.iter().map(|(key, message)| self.operation_message(&pointer(path, key), key, message)).collect(),
+            Some(OperationMessageType::OneOf(messages)) => {
+                let path = pointer(path, "oneOf");
+                messages
+                    .iter()
+                    .enumerate()
+                    .map(|(index, message)| self.operation_message(&pointer(&path, &index.to_string()), &format!("{operation_id}.message.{index}"), message))
+                    .collect()
+            }
+            Some(OperationMessageType::Single(message)) => vec![self.operation_message(path, &format!("{operation_id}.message"), message)],
+        }
+    }
+
+    fn operation_message(&mut self, path: &str, key: &str, message: &ReferenceOr<crate::Message>) -> (String, ReferenceOr<Message>) {
+        match message {
+            ReferenceOr::Reference { reference } => {
+                let name = reference.rsplit('/').next().map(decode_pointer_token).unwrap_or_else(|| key.to_owned());
+                (name, ReferenceOr::Reference { reference: reference.clone() })
+            }
+            ReferenceOr::Item(message) => {
+                let name = message.message_id.clone().or_else(|| message.name.clone()).unwrap_or_else(|| key.to_owned());
+                (name, ReferenceOr::Item(self.message(path, message)))
+            }
+        }
+    }
+
+    fn message(&mut self, path: &str, message: &crate::Message) -> Message {
+        let payload = message.payload.as_ref().and_then(|payload| self.payload(&pointer(path, "payload"), message.schema_format.as_deref(), payload));
+        let mut examples = Vec::with_capacity(message.examples.len());
+        for (index, example) in message.examples.iter().enumerate() {
+            let path = pointer(&pointer(path, "examples"), &index.to_string());
+            if example.description.is_some() {
+                self.warn(pointer(&path, "description"), "message examples have no description in AsyncAPI 3, it is dropped".to_owned());
+            }
+            let headers = match &example.headers {
+                None => IndexMap::new(),
+                Some(Value::Object(headers)) => headers.clone().into_iter().collect(),
+                Some(_) => {
+                    self.warn(pointer(&path, "headers"), "headers which are not an object are dropped".to_owned());
+                    IndexMap::new()
+                }
+            };
+            examples.push(MessageExample {
+                headers,
+                payload: example.payload.clone(),
+                name: example.name.clone(),
+                summary: example.summary.clone(),
+                extensions: example.extensions.clone(),
+            });
+        }
+        Message {
+            headers: message.headers.clone().map(SchemaOrMultiFormat::Schema),
+            payload,
+            correlation_id: message.correlation_id.clone(),
+            content_type: message.content_type.clone(),
+            name: message.name.clone(),
+            title: message.title.clone(),
+            summary: message.summary.clone(),
+            description: message.description.clone(),
+            tags: items(&message.tags),
+            external_docs: message.external_docs.clone().map(ReferenceOr::Item),
+            bindings: message.bindings.clone(),
+            examples,
+            traits: message
+                .traits
+                .iter()
+                .enumerate()
+                .map(|(index, item)| self.convert_ref(&pointer(&pointer(path, "traits"), &index.to_string()), item, Self::message_trait))
+                .collect(),
+            extensions: message.extensions.clone(),
+        }
+    }
+
+    fn payload(&mut self, path: &str, format: Option<&str>, payload: &Payload) -> Option<SchemaOrMultiFormat> {
+        if format.is_none_or(|format| DEFAULT_SCHEMA_FORMATS.iter().any(|prefix| format.starts_with(prefix))) {
+            match payload.to_schema() {
+                Some(schema) => return Some(SchemaOrMultiFormat::Schema(schema)),
+                None => self.warn(path.to_owned(), "the payload is not a schema, it is kept as a Multi Format Schema Object".to_owned()),
+            }
+        }
+        Some(SchemaOrMultiFormat::MultiFormat(MultiFormatSchema {
+            schema_format: format.unwrap_or("application/vnd.aai.asyncapi+json;version=3.0.0").to_owned(),
+            schema: self.json(path, payload)?,
+        }))
+    }
+
+    fn message_trait(&mut self, path: &str, message_trait: &crate::MessageTrait) -> MessageTrait {
+        if let Some(schema_format) = &message_trait.schema_format {
+            self.warn(
+                pointer(path, "schemaFormat"),
+                format!("message traits have no schemaFormat in AsyncAPI 3, `{schema_format}` is dropped"),
+            );
+        }
+        MessageTrait {
+            headers: message_trait.headers.clone().map(SchemaOrMultiFormat::Schema),
+            correlation_id: message_trait.correlation_id.clone(),
+            content_type: message_trait.content_type.clone(),
+            name: message_trait.name.clone(),
+            title: message_trait.title.clone(),
+            summary: message_trait.summary.clone(),
+            description: message_trait.description.clone(),
+            tags: items(&message_trait.tags),
+            external_docs: message_trait.external_docs.clone().map(ReferenceOr::Item),
+            bindings: message_trait.bindings.clone(),
+            examples: message_trait.examples.clone(),
+            extensions: message_trait.extensions.clone(),
+        }
+    }
+
+    fn operation_trait(&mut self, path: &str, operation_trait: &crate::OperationTrait) -> OperationTrait {
+        if let Some(operation_id) = &operation_trait.operation_id {
+            self.warn(pointer(path, "operationId"), format!("operation traits have no operationId in AsyncAPI 3, `{operation_id}` is dropped"));
+        }
+        OperationTrait {
+            summary: operation_trait.summary.clone(),
+            description: operation_trait.description.clone(),
+            tags: items(&operation_trait.tags),
+            external_docs: operation_trait.external_docs.clone().map(ReferenceOr::Item),
+            bindings: operation_trait.bindings.clone(),
+            extensions: operation_trait.extensions.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// AsyncAPI 3 parameters are strings described by `enum`, `default` and
+    /// `examples` instead of a schema.
+    fn parameter(&mut self, path: &str, parameter: &crate::Parameter) -> Parameter {
+        let mut converted = Parameter {
+            description: parameter.description.clone(),
+            location: parameter.location.clone(),
+            extensions: parameter.extensions.clone(),
+            ..Default::default()
+        };
+        match &parameter.schema {
+            None => {}
+            Some(ReferenceOr::Reference { reference }) => {
+                self.warn(pointer(path, "schema"), format!("the referenced schema `{reference}` can not be converted and is dropped"));
+            }
+            Some(ReferenceOr::Item(schema)) => {
+                let Some(Value::Object(mut schema)) = self.json(&pointer(path, "schema"), schema) else {
+                    return converted;
+                };
+                if let Some(Value::Array(values)) = schema.remove("enum") {
+                    converted.enumeration = values.into_iter().map(value_to_string).collect();
+                }
+                converted.default = schema.remove("default").map(value_to_string);
+                if let Some(example) = schema.remove("example") {
+                    converted.examples.push(value_to_string(example));
+                }
+                if let Some(Value::Array(examples)) = schema.remove("examples") {
+                    converted.examples.extend(examples.into_iter().map(value_to_string));
+                }
+                if let Some(Value::String(description)) = schema.remove("description") {
+                    converted.description.get_or_insert(description);
+                }
+                if schema.get("type").and_then(Value::as_str) == Some("string") {
+                    schema.remove("type");
+                }
+                if !schema.is_empty() {
+                    let keywords: Vec<String> = schema.keys().map(|keyword| format!("`{keyword}`")).collect();
+                    self.warn(
+                        pointer(path, "schema"),
+                        format!("parameters are strings without schema in AsyncAPI 3, {} are dropped", keywords.join(", ")),
+                    );
+                }
+            }
+        }
+        converted
+    }
+
+    fn components(&mut self, components: &crate::Components) -> Components {
+        let path = "/components";
+        let mut operations = IndexMap::new();
+        let mut channels = IndexMap::new();
+        for (id, channel) in &components.channels {
+            let reference = format!("#/components/channels/{}", escape_pointer_token(id));
+            let channel = self.channel(&pointer(&pointer(path, "channels"), id), id, None, &reference, channel, &mut operations);
+            channels.insert(id.clone(), ReferenceOr::Item(channel));
+        }
+        Components {
+            schemas: components.schemas.iter().map(|(name, schema)| (name.clone(), SchemaOrMultiFormat::Schema(schema.clone()))).collect(),
+            servers: self.convert_map(&pointer(path, "servers"), &components.servers, Self::server),
+            channels,
+            operations,
+            messages: self.convert_map(&pointer(path, "messages"), &components.messages, Self::message),
+            security_schemes: components
+                .security_schemes
+                .iter()
+                .filter_map(|(name, scheme)| match scheme {
+                    ReferenceOr::Reference { reference } => Some((name.clone(), ReferenceOr::Reference { reference: reference.clone() })),
+                    ReferenceOr::Item(scheme) => Some((name.clone(), ReferenceOr::Item(self.security_scheme(&pointer(&pointer(path, "securitySchemes"), name), scheme)?))),
+                })
+                .collect(),
+            server_variables: components.server_variables.clone(),
+            parameters: self.convert_map(&pointer(path, "parameters"), &components.parameters, Self::parameter),
+            correlation_ids: components.correlation_ids.clone(),
+            operation_traits: self.convert_map(&pointer(path, "operationTraits"), &components.operation_traits, Self::operation_trait),
+            message_traits: self.convert_map(&pointer(path, "messageTraits"), &components.message_traits, Self::message_trait),
+            server_bindings: components.server_bindings.clone(),
+            channel_bindings: components.channel_bindings.clone(),
+            operation_bindings: components.operation_bindings.clone(),
+            message_bindings: components.message_bindings.clone(),
+            extensions: components.extensions.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+#[test]
+fn test_convert_v2_to_v3() {
+    let example = r##"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    tags:
+      - name: accounts
+    servers:
+      production:
+        url: mqtt://broker.example.com:1883/accounts
+        protocol: mqtt
+        security:
+          - oauth: [write:users]
+    channels:
+      user/{userId}/signedup:
+        parameters:
+          userId:
+            schema:
+              type: string
+              enum: [a, b]
+              pattern: "^[a-z]$"
+        publish:
+          operationId: onUserSignUp
+          message:
+            $ref: "#/components/messages/UserSignedUp"
+        subscribe:
+          message:
+            messageId: userSignUpAck
+            schemaFormat: application/vnd.apache.avro;version=1.9.0
+            payload:
+              type: record
+              name: Ack
+              fields: []
+    components:
+      messages:
+        UserSignedUp:
+          payload:
+            type: object
+      securitySchemes:
+        oauth:
+          type: oauth2
+          flows:
+            clientCredentials:
+              tokenUrl: https://example.com/token
+              scopes:
+                write:users: Write users
+    "##;
+    let asyncapi: crate::AsyncAPI = serde_yaml::from_str(example).expect("Could not deserialize conversion example");
+    let Converted { asyncapi, warnings } = convert_v2_to_v3_with_warnings(&asyncapi);
+
+    assert_eq!(asyncapi.asyncapi, "3.0.0");
+    assert_eq!(asyncapi.info.tags.len(), 1);
+    let Some(ReferenceOr::Item(server)) = asyncapi.servers.get("production") else {
+        panic!("server was not converted");
+    };
+    assert_eq!((server.host.as_str(), server.pathname.as_deref()), ("broker.example.com:1883", Some("/accounts")));
+    assert!(matches!(&server.security[..], [ReferenceOr::Item(SecurityScheme::OAuth2 { scopes, .. })] if scopes == &["write:users"]));
+
+    let Some(ReferenceOr::Item(channel)) = asyncapi.channels.get("user/{userId}/signedup") else {
+        panic!("channel was not converted");
+    };
+    assert_eq!(channel.address, Some(Some("user/{userId}/signedup".to_owned())));
+    assert_eq!(channel.messages.keys().collect::<Vec<_>>(), vec!["UserSignedUp", "userSignUpAck"]);
+    assert!(matches!(
+        channel.messages["userSignUpAck"],
+        ReferenceOr::Item(Message {
+            payload: Some(SchemaOrMultiFormat::MultiFormat(_)),
+            ..
+        })
+    ));
+    let ReferenceOr::Item(parameter) = &channel.parameters["userId"] else {
+        panic!("parameter was not converted");
+    };
+    assert_eq!(parameter.enumeration, vec!["a", "b"]);
+
+    let operations: Vec<_> = asyncapi
+        .operations
+        .iter()
+        .map(|(id, operation)| match operation {
+            ReferenceOr::Item(operation) => (id.as_str(), operation.action, operation.messages[0].reference.as_str()),
+            ReferenceOr::Reference { .. } => panic!("operation was not converted"),
+        })
+        .collect();
+    assert_eq!(
+        operations,
+        vec![
+            ("onUserSignUp", OperationAction::Receive, "#/channels/user~1{userId}~1signedup/messages/UserSignedUp"),
+            ("user/{userId}/signedup.subscribe", OperationAction::Send, "#/channels/user~1{userId}~1signedup/messages/userSignUpAck"),
+        ]
+    );
+
+    let warnings: Vec<_> = warnings.iter().map(|warning| warning.path.as_str()).collect();
+    assert_eq!(warnings, vec!["/channels/user~1{userId}~1signedup/parameters/userId/schema"]);
+
+    let mut document = crate::AsyncAPI::default();
+    let mut components = crate::Components::default();
+    let protobuf = crate::Message {
+        payload: Some(Payload::Protobuf("message Ack {}".to_owned())),
+        ..Default::default()
+    };
+    components.messages.insert("Ack".to_owned(), ReferenceOr::Item(protobuf));
+    document.components = Some(components);
+    let Converted { asyncapi, warnings } = convert_v2_to_v3_with_warnings(&document);
+    let message = &asyncapi.components.unwrap().messages["Ack"];
+    assert!(matches!(message, ReferenceOr::Item(Message { payload: Some(SchemaOrMultiFormat::MultiFormat(_)), .. })));
+    assert_eq!(warnings[0].path, "/components/messages/Ack/payload");
+}
@@ -0,0 +1,57 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{Contact, ExternalDocumentation, License, ReferenceOr, Tag};
+
+/// The object provides metadata about the API. The metadata can be used by the clients if needed.
+///
+/// In AsyncAPI 3.0 the `tags` and `externalDocs` of the document moved from
+/// the root object into the Info Object.
+///
+/// # Examples
+/// ```yaml
+/// title: AsyncAPI Sample App
+/// version: 1.0.1
+/// description: This is a sample app.
+/// license:
+///   name: Apache 2.0
+///   url: https://www.apache.org/licenses/LICENSE-2.0.html
+/// tags:
+///   - name: e-commerce
+/// externalDocs:
+///   url: https://www.asyncapi.com/
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Info {
+    /// **REQUIRED.** The title of the application.
+    pub title: String,
+    /// **REQUIRED.** Provides the version of the application API
+    /// (not to be confused with the specification version).
+    pub version: String,
+    /// A short description of the application.
+    /// CommonMark syntax can be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A URL to the Terms of Service for the API.
+    /// MUST be in the format of a URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms_of_service: Option<String>,
+    /// The contact information for the exposed API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact: Option<Contact>,
+    /// The license information for the exposed API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<License>,
+    /// A list of tags for application API documentation control.
+    /// Tags can be used for logical grouping of applications.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ReferenceOr<Tag>>,
+    /// Additional external documentation of the exposed API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ReferenceOr<ExternalDocumentation>>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten)]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
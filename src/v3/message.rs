@@ -0,0 +1,158 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{message_trait::MessageExample, CorrelationId, ExternalDocumentation, MessageBinding, ReferenceOr, Schema, Tag};
+
+/// Describes a message received on a given channel and operation.
+///
+/// Unlike AsyncAPI 2, the message has no `messageId` (the key of the
+/// message in its map identifies it) and no `schemaFormat`: schemas in
+/// another format are given as a [Multi Format Schema Object][MultiFormatSchema].
+///
+/// # Examples
+/// ```yaml
+/// name: UserSignup
+/// title: User signup
+/// contentType: application/json
+/// headers:
+///   type: object
+///   properties:
+///     applicationInstanceId:
+///       type: string
+/// payload:
+///   $ref: '#/components/schemas/userSignup'
+/// traits:
+///   - $ref: '#/components/messageTraits/commonHeaders'
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+    /// Schema definition of the application headers.
+    /// Schema MUST be a map of key-value pairs. It **MUST NOT** define the protocol headers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<SchemaOrMultiFormat>,
+    /// Definition of the message payload.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<SchemaOrMultiFormat>,
+    /// Definition of the correlation ID used for message tracing or matching.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<ReferenceOr<CorrelationId>>,
+    /// The content type to use when encoding/decoding a message's payload.
+    /// When omitted, the value MUST be the one specified on the `defaultContentType` field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// A machine-friendly name for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A human-friendly title for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A short summary of what the message is about.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// A verbose explanation of the message.
+    /// [CommonMark syntax](https://spec.commonmark.org/) can be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A list of tags for logical grouping and categorization of messages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ReferenceOr<Tag>>,
+    /// Additional external documentation for this message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ReferenceOr<ExternalDocumentation>>,
+    /// A map where the keys describe the name of the protocol and the values
+    /// describe protocol-specific definitions for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<ReferenceOr<MessageBinding>>,
+    /// List of examples.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<MessageExample>,
+    /// A list of traits to apply to the message object. Traits MUST be merged
+    /// using the traits merge mechanism, the message object itself winning
+    /// over its traits.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub traits: Vec<ReferenceOr<MessageTrait>>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten)]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+/// Describes a trait that MAY be applied to a [Message Object][Message].
+/// This object MAY contain any property from the Message Object, except
+/// `payload` and `traits`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageTrait {
+    /// Schema definition of the application headers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<SchemaOrMultiFormat>,
+    /// Definition of the correlation ID used for message tracing or matching.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<ReferenceOr<CorrelationId>>,
+    /// The content type to use when encoding/decoding a message's payload.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// A machine-friendly name for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A human-friendly title for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A short summary of what the message is about.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// A verbose explanation of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A list of tags for logical grouping and categorization of messages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ReferenceOr<Tag>>,
+    /// Additional external documentation for this message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ReferenceOr<ExternalDocumentation>>,
+    /// A map where the keys describe the name of the protocol and the values
+    /// describe protocol-specific definitions for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<ReferenceOr<MessageBinding>>,
+    /// List of examples.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<MessageExample>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten)]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+/// A schema of the `headers` or `payload` of a message, or of the
+/// `schemas` of the components.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+#[serde(untagged)]
+pub enum SchemaOrMultiFormat {
+    /// A schema in a format other than the default AsyncAPI Schema.
+    MultiFormat(MultiFormatSchema),
+    /// An AsyncAPI Schema, or a reference to a schema of any format.
+    Schema(ReferenceOr<Schema>),
+}
+
+/// Allows to define schemas in formats other than the default AsyncAPI
+/// Schema, such as Avro or Protobuf.
+///
+/// # Examples
+/// ```yaml
+/// schemaFormat: 'application/vnd.apache.avro;version=1.9.0'
+/// schema:
+///   $ref: 'https://example.com/user.avsc'
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiFormatSchema {
+    /// **REQUIRED.** A string containing the name of the schema format that
+    /// is used to define the information, e.g.
+    /// `application/vnd.aai.asyncapi+json;version=3.0.0`.
+    pub schema_format: String,
+    /// **REQUIRED.** Definition of the message payload, in the format given
+    /// by `schemaFormat`.
+    pub schema: serde_json::Value,
+}
@@ -0,0 +1,60 @@
+//! Data structures of the [AsyncAPI 3.0.0](https://www.asyncapi.com/docs/reference/specification/v3.0.0)
+//! specification.
+//!
+//! AsyncAPI 3.0 decouples operations from channels: channels describe an
+//! `address` and the `messages` sent over it, while the top-level
+//! `operations` describe whether the application `send`s or `receive`s on a
+//! channel and how it replies.
+//!
+//! Objects which did not change between 2.6.0 and 3.0.0, such as the
+//! [Schema Object][crate::Schema] or the bindings, are shared with the
+//! root module.
+//!
+//! ```
+//! let example = r#"
+//! asyncapi: 3.0.0
+//! info:
+//!   title: Account Service
+//!   version: 1.0.0
+//! channels:
+//!   userSignedup:
+//!     address: user/signedup
+//!     messages:
+//!       UserSignedUp:
+//!         payload:
+//!           type: object
+//! operations:
+//!   onUserSignUp:
+//!     action: receive
+//!     channel:
+//!       $ref: '#/channels/userSignedup'
+//! "#;
+//! let asyncapi: asyncapi::v3::AsyncAPI = serde_yaml::from_str(example).unwrap();
+//! let asyncapi::ReferenceOr::Item(operation) = &asyncapi.operations["onUserSignUp"] else { panic!() };
+//! assert_eq!(operation.action, asyncapi::v3::OperationAction::Receive);
+//! assert_eq!(operation.channel.reference, "#/channels/userSignedup");
+//! ```
+
+mod api;
+mod channel;
+mod components;
+mod convert;
+mod info;
+mod message;
+mod operation;
+mod parameter;
+mod reference;
+mod security_scheme;
+mod server;
+
+pub use api::AsyncAPI;
+pub use channel::Channel;
+pub use components::Components;
+pub use convert::{convert_v2_to_v3, convert_v2_to_v3_with_warnings, Converted};
+pub use info::Info;
+pub use message::{Message, MessageTrait, MultiFormatSchema, SchemaOrMultiFormat};
+pub use operation::{Operation, OperationAction, OperationReply, OperationReplyAddress, OperationTrait};
+pub use parameter::Parameter;
+pub use reference::Reference;
+pub use security_scheme::{OAuthFlow, OAuthFlows, SecurityScheme};
+pub use server::Server;
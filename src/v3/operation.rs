@@ -0,0 +1,171 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::{Reference, SecurityScheme};
+use crate::{ExternalDocumentation, OperationBinding, ReferenceOr, Tag};
+
+/// Describes a specific operation of the application.
+///
+/// # Examples
+/// ```yaml
+/// title: User sign up
+/// summary: Action to sign a user up.
+/// action: send
+/// channel:
+///   $ref: '#/channels/userSignup'
+/// messages:
+///   - $ref: '#/channels/userSignup/messages/userSignedUp'
+/// reply:
+///   address:
+///     location: '$message.header#/replyTo'
+///   channel:
+///     $ref: '#/channels/userSignupReply'
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Operation {
+    /// **REQUIRED.** Whether the application will send or receive messages.
+    pub action: OperationAction,
+    /// **REQUIRED.** A reference to the definition of the channel in which
+    /// this operation is performed.
+    pub channel: Reference,
+    /// A human-friendly title for the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A short summary of what the operation is about.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// A verbose explanation of the operation.
+    /// [CommonMark syntax](https://spec.commonmark.org/) can be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The security schemes of which at least one MUST be satisfied to
+    /// perform this operation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub security: Vec<ReferenceOr<SecurityScheme>>,
+    /// A list of tags for logical grouping and categorization of operations.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ReferenceOr<Tag>>,
+    /// Additional external documentation for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ReferenceOr<ExternalDocumentation>>,
+    /// A map where the keys describe the name of the protocol and the values
+    /// describe protocol-specific definitions for the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<ReferenceOr<OperationBinding>>,
+    /// A list of traits to apply to the operation object.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub traits: Vec<ReferenceOr<OperationTrait>>,
+    /// References to the messages of the channel supported by this
+    /// operation. If empty, all the messages of the channel are supported.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<Reference>,
+    /// The definition of the reply in a request-reply operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply: Option<ReferenceOr<OperationReply>>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten)]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+/// Whether the application sends or receives the messages of an
+/// [Operation Object][Operation].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum OperationAction {
+    /// The application will send messages to the channel.
+    Send,
+    /// The application expects to receive messages from the channel.
+    Receive,
+}
+
+/// Describes a trait that MAY be applied to an [Operation Object][Operation].
+/// This object MAY contain any property from the Operation Object, except
+/// `action`, `channel`, `messages` and `traits`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationTrait {
+    /// A human-friendly title for the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A short summary of what the operation is about.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// A verbose explanation of the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The security schemes of which at least one MUST be satisfied to
+    /// perform this operation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub security: Vec<ReferenceOr<SecurityScheme>>,
+    /// A list of tags for logical grouping and categorization of operations.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ReferenceOr<Tag>>,
+    /// Additional external documentation for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ReferenceOr<ExternalDocumentation>>,
+    /// A map where the keys describe the name of the protocol and the values
+    /// describe protocol-specific definitions for the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<ReferenceOr<OperationBinding>>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten)]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+/// Describes the reply part that MAY be applied to an
+/// [Operation Object][Operation]. If an operation implements the
+/// request/reply pattern, the reply object represents the response message.
+///
+/// # Examples
+/// ```yaml
+/// address:
+///   location: '$message.header#/replyTo'
+/// channel:
+///   $ref: '#/channels/userSignupReply'
+/// messages:
+///   - $ref: '#/channels/userSignupReply/messages/userSignedUpReply'
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationReply {
+    /// Definition of the address that implementations MUST use for the reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<ReferenceOr<OperationReplyAddress>>,
+    /// A reference to the definition of the channel in which this operation
+    /// is performed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<Reference>,
+    /// References to the messages of the channel which can be used as reply.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<Reference>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten)]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+/// An object that specifies where an operation has to send the reply.
+///
+/// # Examples
+/// ```yaml
+/// description: Consumer Inbox
+/// location: $message.header#/replyTo
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationReplyAddress {
+    /// An optional description of the address.
+    /// [CommonMark syntax](https://spec.commonmark.org/) can be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// **REQUIRED.** A [runtime expression](https://www.asyncapi.com/docs/reference/specification/v3.0.0#runtimeExpression)
+    /// that specifies the location of the reply address.
+    pub location: String,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten)]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
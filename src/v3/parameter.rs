@@ -0,0 +1,40 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Describes a parameter included in a channel address.
+///
+/// Unlike AsyncAPI 2, parameters are always strings and have no `schema`.
+///
+/// # Examples
+/// ```yaml
+/// description: Id of the user.
+/// enum: ['1', '2']
+/// location: $message.payload#/user/id
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameter {
+    /// An enumeration of string values to be used if the substitution
+    /// options are from a limited set.
+    #[serde(rename = "enum", default, skip_serializing_if = "Vec::is_empty")]
+    pub enumeration: Vec<String>,
+    /// The default value to use for substitution, and to send, if an
+    /// alternate value is not supplied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    /// An optional description for the parameter.
+    /// [CommonMark syntax](https://spec.commonmark.org/) can be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// An array of examples of the parameter value.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<String>,
+    /// A [runtime expression](https://www.asyncapi.com/docs/reference/specification/v3.0.0#runtimeExpression)
+    /// that specifies the location of the parameter value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten)]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// A field which MUST be a reference, such as the `channel` of an
+/// [Operation Object][crate::v3::Operation]. Fields which may hold either a
+/// reference or an inline object use [ReferenceOr][crate::ReferenceOr].
+///
+/// # Examples
+/// ```yaml
+/// $ref: '#/channels/userSignedup'
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
+pub struct Reference {
+    /// **REQUIRED.** The reference string.
+    #[serde(rename = "$ref")]
+    pub reference: String,
+}
+
+impl Reference {
+    pub fn new(reference: impl Into<String>) -> Self {
+        Reference { reference: reference.into() }
+    }
+}
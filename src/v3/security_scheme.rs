@@ -0,0 +1,231 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Defines a security scheme that can be used by the operations.
+///
+/// The schemes are the same as in AsyncAPI 2, except for `oauth2` and
+/// `openIdConnect` which list the `scopes` required by the server or the
+/// operation using them, and for the OAuth flows which list their
+/// `availableScopes`.
+///
+/// # Examples
+/// ```yaml
+/// type: oauth2
+/// flows:
+///   implicit:
+///     authorizationUrl: https://example.com/api/oauth/dialog
+///     availableScopes:
+///       write:pets: modify pets in your account
+///       read:pets: read your pets
+/// scopes:
+///   - 'write:pets'
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+#[serde(tag = "type")]
+pub enum SecurityScheme {
+    #[serde(rename = "userPassword")]
+    UserPassword {
+        /// A short description for security scheme.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten)]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "apiKey")]
+    ApiKey {
+        /// A short description for security scheme.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// **REQUIRED.** The location of the API key.
+        /// Valid values are `"user"` and `"password"`.
+        #[serde(rename = "in")]
+        location: String,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten)]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    X509 {
+        /// A short description for security scheme.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten)]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "symmetricEncryption")]
+    SymmetricEncryption {
+        /// A short description for security scheme.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten)]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "asymmetricEncryption")]
+    AsymmetricEncryption {
+        /// A short description for security scheme.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten)]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "httpApiKey")]
+    HttpApiKey {
+        /// A short description for security scheme.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// **REQUIRED.** The name of the header, query or cookie parameter to be used.
+        name: String,
+        /// **REQUIRED.** The location of the API key.
+        /// Valid values are `"query"`, `"header"` or `"cookie"`.
+        #[serde(rename = "in")]
+        location: String,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten)]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "http", rename_all = "camelCase")]
+    Http {
+        /// A short description for security scheme.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// **REQUIRED.** The name of the HTTP Authorization scheme to be used
+        /// in the Authorization header as defined in
+        /// [RFC7235](https://tools.ietf.org/html/rfc7235#section-5.1).
+        scheme: String,
+        /// A hint to the client to identify how the bearer token is formatted.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        bearer_format: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten)]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "oauth2")]
+    OAuth2 {
+        /// A short description for security scheme.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// **REQUIRED.** An object containing configuration information for
+        /// the flow types supported.
+        flows: OAuthFlows,
+        /// List of the needed scope names.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        scopes: Vec<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten)]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "openIdConnect", rename_all = "camelCase")]
+    OpenIdConnect {
+        /// A short description for security scheme.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// **REQUIRED.** OpenId Connect URL to discover OAuth2 configuration values.
+        open_id_connect_url: String,
+        /// List of the needed scope names.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        scopes: Vec<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten)]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "plain")]
+    Plain {
+        /// A short description for security scheme.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten)]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "scramSha256")]
+    ScramSha256 {
+        /// A short description for security scheme.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten)]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "scramSha512")]
+    ScramSha512 {
+        /// A short description for security scheme.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten)]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "gssapi")]
+    Gssapi {
+        /// A short description for security scheme.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten)]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+}
+
+/// Allows configuration of the supported OAuth Flows.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthFlows {
+    /// Configuration for the OAuth Implicit flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub implicit: Option<OAuthFlow>,
+    /// Configuration for the OAuth Resource Owner Protected Credentials flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<OAuthFlow>,
+    /// Configuration for the OAuth Client Credentials flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_credentials: Option<OAuthFlow>,
+    /// Configuration for the OAuth Authorization Code flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_code: Option<OAuthFlow>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten)]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+/// Configuration details for a supported OAuth Flow. Which URLs are
+/// required depends on the flow.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthFlow {
+    /// The authorization URL to be used for the `implicit` and
+    /// `authorizationCode` flows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_url: Option<String>,
+    /// The token URL to be used for the `password`, `clientCredentials` and
+    /// `authorizationCode` flows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_url: Option<String>,
+    /// The URL to be used for obtaining refresh tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_url: Option<String>,
+    /// **REQUIRED.** The available scopes for the OAuth2 security scheme.
+    /// A map between the scope name and a short description for it.
+    pub available_scopes: IndexMap<String, String>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten)]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
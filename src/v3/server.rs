@@ -0,0 +1,69 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::SecurityScheme;
+use crate::{ExternalDocumentation, ReferenceOr, ServerBinding, ServerVariable, Tag};
+
+/// An object representing a message broker, a server or any other kind of
+/// computer program capable of sending and/or receiving data.
+///
+/// AsyncAPI 3.0 splits the `url` of AsyncAPI 2 into `host` and `pathname`.
+///
+/// # Examples
+/// ```yaml
+/// host: 'rabbitmq.in.mycompany.com:{port}'
+/// pathname: '/production'
+/// protocol: amqp
+/// description: Production RabbitMQ broker (uses the `production` vhost).
+/// variables:
+///   port:
+///     default: '5672'
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Server {
+    /// **REQUIRED.** The server host name. It MAY include the port. This
+    /// field supports [Server Variable](ServerVariable) substitution.
+    pub host: String,
+    /// **REQUIRED.** The protocol this server supports for connection.
+    pub protocol: String,
+    /// The version of the protocol used for connection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol_version: Option<String>,
+    /// The path to a resource in the host. This field supports
+    /// [Server Variable](ServerVariable) substitution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pathname: Option<String>,
+    /// An optional string describing the server.
+    /// [CommonMark syntax](https://spec.commonmark.org/) can be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A human-friendly title for the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A short summary of the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// A map between a variable name and its value.
+    /// The value is used for substitution in the server's `host` and `pathname`.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub variables: IndexMap<String, ReferenceOr<ServerVariable>>,
+    /// The security schemes of which at least one MUST be satisfied to
+    /// connect to this server.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub security: Vec<ReferenceOr<SecurityScheme>>,
+    /// A list of tags for logical grouping and categorization of servers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ReferenceOr<Tag>>,
+    /// Additional external documentation for this server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ReferenceOr<ExternalDocumentation>>,
+    /// A map where the keys describe the name of the protocol and the values
+    /// describe protocol-specific definitions for the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<ReferenceOr<ServerBinding>>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten)]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
@@ -0,0 +1,296 @@
+use std::{collections::HashMap, fmt};
+
+use indexmap::IndexMap;
+
+use crate::{resolver::escape_pointer_token, AsyncAPI, Channel, Message, Operation, OperationMessageType, ReferenceOr, SecurityRequirement, Tag};
+
+/// How serious a [Diagnostic] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The document violates a rule of the specification.
+    Error,
+    /// The document is valid but likely not what was intended.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => f.write_str("error"),
+            Severity::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+/// A problem found by [`AsyncAPI::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// JSON Pointer to the offending node, e.g. `/channels/user~1signedup/servers/0`.
+    pub path: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at `{}`: {}", self.severity, self.path, self.message)
+    }
+}
+
+impl AsyncAPI {
+    /// Checks the rules of the specification which can not be expressed by
+    /// the structure of the data types, and returns every violation found.
+    ///
+    /// The following rules are checked:
+    /// - `operationId`s are unique among all operations,
+    /// - `messageId`s are unique among all messages,
+    /// - tag names are unique within each list of tags,
+    /// - the `servers` of a channel are declared in the `servers` of the document,
+    /// - security requirements name security schemes declared in the components,
+    /// - the `parameters` of a channel match the `{param}` expressions of its name.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validator = Validator {
+            document: self,
+            diagnostics: Vec::new(),
+            operation_ids: HashMap::new(),
+            message_ids: HashMap::new(),
+        };
+        validator.document();
+        validator.diagnostics
+    }
+}
+
+struct Validator<'a> {
+    document: &'a AsyncAPI,
+    diagnostics: Vec<Diagnostic>,
+    /// The path of the first operation using each `operationId`.
+    operation_ids: HashMap<&'a str, String>,
+    /// The path of the first message using each `messageId`.
+    message_ids: HashMap<&'a str, String>,
+}
+
+fn pointer(parent: &str, token: &str) -> String {
+    format!("{parent}/{}", escape_pointer_token(token))
+}
+
+impl<'a> Validator<'a> {
+    fn report(&mut self, path: String, severity: Severity, message: String) {
+        self.diagnostics.push(Diagnostic { path, severity, message });
+    }
+
+    fn document(&mut self) {
+        let document = self.document;
+        self.tags("/tags", &document.tags);
+        for (name, server) in &document.servers {
+            if let ReferenceOr::Item(server) = server {
+                self.security(&pointer(&pointer("/servers", name), "security"), &server.security);
+            }
+        }
+        self.channels("/channels", &document.channels, true);
+        if let Some(components) = &document.components {
+            self.channels("/components/channels", &components.channels, false);
+            for (name, message) in &components.messages {
+                if let ReferenceOr::Item(message) = message {
+                    self.message(&pointer("/components/messages", name), message);
+                }
+            }
+            for (name, message_trait) in &components.message_traits {
+                if let ReferenceOr::Item(message_trait) = message_trait {
+                    self.tags(&pointer(&pointer("/components/messageTraits", name), "tags"), &message_trait.tags);
+                }
+            }
+            for (name, operation_trait) in &components.operation_traits {
+                if let ReferenceOr::Item(operation_trait) = operation_trait {
+                    self.tags(&pointer(&pointer("/components/operationTraits", name), "tags"), &operation_trait.tags);
+                }
+            }
+        }
+    }
+
+    fn channels(&mut self, path: &str, channels: &'a IndexMap<String, Channel>, check_servers: bool) {
+        for (name, channel) in channels {
+            let path = pointer(path, name);
+            if check_servers {
+                for (index, server) in channel.servers.iter().enumerate() {
+                    if !self.document.servers.contains_key(server) {
+                        self.report(
+                            pointer(&pointer(&path, "servers"), &index.to_string()),
+                            Severity::Error,
+                            format!("server `{server}` is not declared in the servers of the document"),
+                        );
+                    }
+                }
+            }
+            self.channel_parameters(&path, name, channel);
+            if let Some(subscribe) = &channel.subscribe {
+                self.operation(&pointer(&path, "subscribe"), subscribe);
+            }
+            if let Some(publish) = &channel.publish {
+                self.operation(&pointer(&path, "publish"), publish);
+            }
+        }
+    }
+
+    fn channel_parameters(&mut self, path: &str, name: &str, channel: &Channel) {
+        let expressions = channel_expressions(name);
+        for expression in &expressions {
+            if !channel.parameters.contains_key(*expression) {
+                self.report(
+                    path.to_owned(),
+                    Severity::Error,
+                    format!("parameter `{expression}` used in the channel name is not defined in `parameters`"),
+                );
+            }
+        }
+        for parameter in channel.parameters.keys() {
+            if !expressions.contains(&parameter.as_str()) {
+                self.report(
+                    pointer(&pointer(path, "parameters"), parameter),
+                    Severity::Warning,
+                    format!("parameter `{parameter}` does not appear in the channel name"),
+                );
+            }
+        }
+    }
+
+    fn operation(&mut self, path: &str, operation: &'a Operation) {
+        if let Some(operation_id) = &operation.operation_id {
+            match self.operation_ids.get(operation_id.as_str()) {
+                Some(first) => {
+                    let message = format!("operationId `{operation_id}` is already used by `{first}`");
+                    self.report(pointer(path, "operationId"), Severity::Error, message);
+                }
+                None => {
+                    self.operation_ids.insert(operation_id, path.to_owned());
+                }
+            }
+        }
+        self.tags(&pointer(path, "tags"), &operation.tags);
+        self.security(&pointer(path, "security"), &operation.security);
+        match &operation.message {
+            Some(OperationMessageType::Single(ReferenceOr::Item(message))) => self.message(&pointer(path, "message"), message),
+            Some(OperationMessageType::Map(messages)) => {
+                for (name, message) in messages {
+                    if let ReferenceOr::Item(message) = message {
+                        self.message(&pointer(&pointer(path, "message"), name), message);
+                    }
+                }
+            }
+            Some(OperationMessageType::OneOf(messages)) => {
+                for (index, message) in messages.iter().enumerate() {
+                    if let ReferenceOr::Item(message) = message {
+                        self.message(&pointer(&pointer(&pointer(path, "message"), "oneOf"), &index.to_string()), message);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn message(&mut self, path: &str, message: &'a Message) {
+        if let Some(message_id) = &message.message_id {
+            match self.message_ids.get(message_id.as_str()) {
+                Some(first) => {
+                    let message = format!("messageId `{message_id}` is already used by `{first}`");
+                    self.report(pointer(path, "messageId"), Severity::Error, message);
+                }
+                None => {
+                    self.message_ids.insert(message_id, path.to_owned());
+                }
+            }
+        }
+        self.tags(&pointer(path, "tags"), &message.tags);
+    }
+
+    fn tags(&mut self, path: &str, tags: &[Tag]) {
+        for (index, tag) in tags.iter().enumerate() {
+            if tags[..index].iter().any(|previous| previous.name == tag.name) {
+                self.report(pointer(path, &index.to_string()), Severity::Error, format!("tag name `{}` is not unique", tag.name));
+            }
+        }
+    }
+
+    fn security(&mut self, path: &str, security: &[SecurityRequirement]) {
+        let schemes = self.document.components.as_ref().map(|components| &components.security_schemes);
+        for (index, requirement) in security.iter().enumerate() {
+            for name in requirement.values.keys() {
+                if !schemes.is_some_and(|schemes| schemes.contains_key(name)) {
+                    self.report(
+                        pointer(&pointer(path, &index.to_string()), name),
+                        Severity::Error,
+                        format!("security scheme `{name}` is not declared in `components/securitySchemes`"),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns the names of the `{param}` expressions of a channel name.
+fn channel_expressions(name: &str) -> Vec<&str> {
+    let mut expressions = Vec::new();
+    let mut rest = name;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        expressions.push(&rest[start + 1..start + end]);
+        rest = &rest[start + end + 1..];
+    }
+    expressions
+}
+
+#[test]
+fn test_validate() {
+    let example = r##"
+    asyncapi: 2.6.0
+    info:
+      title: Validate
+      version: 1.0.0
+    servers:
+      production:
+        url: broker.example.com
+        protocol: kafka
+        security:
+          - undeclared: []
+    channels:
+      user/{userId}/signedup:
+        servers:
+          - staging
+        parameters:
+          unused:
+            description: Not in the channel name.
+        subscribe:
+          operationId: onUser
+          message:
+            messageId: user
+            tags:
+              - name: user
+              - name: user
+      user/signedout:
+        publish:
+          operationId: onUser
+          message:
+            messageId: user
+        subscribe:
+          message:
+            oneOf:
+              - messageId: signedOut
+              - messageId: user
+    "##;
+    let asyncapi: AsyncAPI = serde_yaml::from_str(example).expect("Could not deserialize validate example");
+    let diagnostics: Vec<String> = asyncapi.validate().iter().map(|diagnostic| diagnostic.path.clone()).collect();
+    assert_eq!(
+        diagnostics,
+        vec![
+            "/servers/production/security/0/undeclared",
+            "/channels/user~1{userId}~1signedup/servers/0",
+            "/channels/user~1{userId}~1signedup",
+            "/channels/user~1{userId}~1signedup/parameters/unused",
+            "/channels/user~1{userId}~1signedup/subscribe/message/tags/1",
+            "/channels/user~1signedout/subscribe/message/oneOf/1/messageId",
+            "/channels/user~1signedout/publish/operationId",
+            "/channels/user~1signedout/publish/message/messageId",
+        ]
+    );
+}
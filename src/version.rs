@@ -0,0 +1,226 @@
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+use crate::AsyncAPI;
+
+/// The error returned by [`load_any_version`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum VersionError {
+    /// The text is neither valid JSON nor valid YAML.
+    Parse(String),
+    /// The document has no `asyncapi` field, or it is not a version string.
+    MissingVersion,
+    /// The document uses a version of the specification which is not supported.
+    UnsupportedVersion(String),
+    /// The document does not match the structure of its version.
+    Deserialize { version: String, message: String },
+}
+
+impl fmt::Display for VersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VersionError::Parse(message) => write!(f, "could not parse the document: {message}"),
+            VersionError::MissingVersion => f.write_str("the document has no `asyncapi` version field"),
+            VersionError::UnsupportedVersion(version) if version.starts_with("3.") => {
+                write!(f, "AsyncAPI {version} is not supported by this loader, use the `v3` module instead")
+            }
+            VersionError::UnsupportedVersion(version) => write!(f, "AsyncAPI {version} is not supported, only versions 2.0.0 to 2.6.0 are"),
+            VersionError::Deserialize { version, message } => write!(f, "invalid AsyncAPI {version} document: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for VersionError {}
+
+/// Loads a JSON or YAML document of any AsyncAPI version from 2.0.0 to
+/// 2.6.0 into the 2.6.0 data model.
+///
+/// The `asyncapi` field is read first and documents of other versions are
+/// rejected. Constructs of older versions, or emitted by older tooling, are
+/// then normalized before the document is deserialized:
+///
+/// - `servers` given as a list, as in the release candidates of 2.0.0,
+///   become a map keyed by the `name` of each server or by its index,
+/// - the `protocol` of a server defaults to the scheme of its `url`,
+/// - channel `servers` given as a single name or as references such as
+///   `$ref: '#/servers/production'` become a list of names,
+/// - in documents older than 2.4.0, which have no `messageId`, the
+///   `x-messageId` extension becomes the `messageId`.
+///
+/// The `asyncapi` field keeps the version of the original document.
+///
+/// ```
+/// let asyncapi = asyncapi::load_any_version("
+/// asyncapi: 2.0.0
+/// info:
+///   title: Legacy
+///   version: 1.0.0
+/// servers:
+///   production:
+///     url: mqtt://broker.example.com
+/// channels: {}
+/// ").unwrap();
+/// assert_eq!(asyncapi.servers.len(), 1);
+///
+/// assert!(asyncapi::load_any_version("asyncapi: 1.2.0").is_err());
+/// ```
+pub fn load_any_version(text: &str) -> Result<AsyncAPI, VersionError> {
+    let mut document: Value = serde_yaml::from_str(text).map_err(|error| VersionError::Parse(error.to_string()))?;
+    let version = document.get("asyncapi").and_then(Value::as_str).ok_or(VersionError::MissingVersion)?.to_owned();
+    let minor = supported_minor(&version).ok_or_else(|| VersionError::UnsupportedVersion(version.clone()))?;
+    normalize(&mut document, minor);
+    crate::de::from_value(document).map_err(|error| VersionError::Deserialize { version, message: error.to_string() })
+}
+
+/// Returns the minor version of a supported `2.x.y` version string. The
+/// patch may be suffixed by a hyphen and extra characters, e.g. `2.0.0-rc2`.
+fn supported_minor(version: &str) -> Option<u32> {
+    let mut parts = version.split('.');
+    let (major, minor, patch) = (parts.next()?, parts.next()?, parts.next()?);
+    let patch = patch.split_once('-').map_or(patch, |(patch, _)| patch);
+    if parts.next().is_some() || major != "2" || patch.parse::<u32>().is_err() {
+        return None;
+    }
+    minor.parse().ok().filter(|minor| *minor <= 6)
+}
+
+fn normalize(document: &mut Value, minor: u32) {
+    if let Some(servers) = document.get_mut("servers") {
+        normalize_servers(servers);
+    }
+    if let Some(Value::Object(servers)) = document.pointer_mut("/components/servers") {
+        servers.values_mut().for_each(normalize_server);
+    }
+    for pointer in ["/channels", "/components/channels"] {
+        let Some(Value::Object(channels)) = document.pointer_mut(pointer) else {
+            continue;
+        };
+        for channel in channels.values_mut() {
+            normalize_channel_servers(channel);
+            if minor < 4 {
+                for operation in ["publish", "subscribe"] {
+                    if let Some(message) = channel.pointer_mut(&format!("/{operation}/message")) {
+                        normalize_operation_message_ids(message);
+                    }
+                }
+            }
+        }
+    }
+    if minor < 4 {
+        if let Some(Value::Object(messages)) = document.pointer_mut("/components/messages") {
+            messages.values_mut().for_each(normalize_message_id);
+        }
+    }
+}
+
+fn normalize_servers(servers: &mut Value) {
+    if let Value::Array(list) = servers {
+        let mut map = Map::new();
+        for (index, mut server) in std::mem::take(list).into_iter().enumerate() {
+            let name = match server.as_object_mut().and_then(|server| server.remove("name")) {
+                Some(Value::String(name)) => name,
+                _ => index.to_string(),
+            };
+            map.insert(name, server);
+        }
+        *servers = Value::Object(map);
+    }
+    if let Value::Object(servers) = servers {
+        servers.values_mut().for_each(normalize_server);
+    }
+}
+
+fn normalize_server(server: &mut Value) {
+    let Some(server) = server.as_object_mut() else {
+        return;
+    };
+    if server.contains_key("protocol") {
+        return;
+    }
+    let scheme = server.get("url").and_then(Value::as_str).and_then(|url| url.split_once("://")).map(|(scheme, _)| scheme.to_owned());
+    if let Some(scheme) = scheme {
+        server.insert("protocol".to_owned(), Value::String(scheme));
+    }
+}
+
+fn normalize_channel_servers(channel: &mut Value) {
+    let Some(servers) = channel.get_mut("servers") else {
+        return;
+    };
+    if servers.is_string() {
+        *servers = Value::Array(vec![servers.take()]);
+    }
+    if let Value::Array(servers) = servers {
+        for server in servers {
+            let name = server.get("$ref").and_then(Value::as_str).and_then(|reference| reference.strip_prefix("#/servers/")).map(str::to_owned);
+            if let Some(name) = name {
+                *server = Value::String(name.replace("~1", "/").replace("~0", "~"));
+            }
+        }
+    }
+}
+
+fn normalize_operation_message_ids(message: &mut Value) {
+    match message.get_mut("oneOf") {
+        Some(Value::Array(messages)) => messages.iter_mut().for_each(normalize_message_id),
+        _ => normalize_message_id(message),
+    }
+}
+
+fn normalize_message_id(message: &mut Value) {
+    let Some(message) = message.as_object_mut() else {
+        return;
+    };
+    if message.contains_key("messageId") {
+        return;
+    }
+    if let Some(message_id) = message.remove("x-messageId") {
+        message.insert("messageId".to_owned(), message_id);
+    }
+}
+
+#[test]
+fn test_load_any_version() {
+    let legacy = r##"
+    asyncapi: 2.0.0-rc2
+    info:
+      title: Legacy
+      version: 1.0.0
+    servers:
+      - name: production
+        url: mqtt://broker.example.com:1883
+      - url: ws://broker.example.com/ws
+        protocol: ws
+    channels:
+      user/signedup:
+        servers:
+          - $ref: "#/servers/production"
+        subscribe:
+          message:
+            oneOf:
+              - x-messageId: userSignedUp
+              - $ref: "#/components/messages/userSignedOut"
+    components:
+      messages:
+        userSignedOut:
+          x-messageId: userSignedOut
+    "##;
+    let asyncapi = load_any_version(legacy).unwrap();
+    assert_eq!(asyncapi.asyncapi, "2.0.0-rc2");
+    assert_eq!(asyncapi.servers.keys().collect::<Vec<_>>(), vec!["production", "1"]);
+    match &asyncapi.servers["production"] {
+        crate::ReferenceOr::Item(server) => assert_eq!(server.protocol, "mqtt"),
+        other => panic!("unexpected server: {other:?}"),
+    }
+    assert_eq!(asyncapi.channels["user/signedup"].servers, vec!["production"]);
+    match &asyncapi.components.as_ref().unwrap().messages["userSignedOut"] {
+        crate::ReferenceOr::Item(message) => assert_eq!(message.message_id.as_deref(), Some("userSignedOut")),
+        other => panic!("unexpected message: {other:?}"),
+    }
+
+    assert_eq!(load_any_version("asyncapi: 1.2.0"), Err(VersionError::UnsupportedVersion("1.2.0".to_owned())));
+    assert_eq!(load_any_version("asyncapi: 2.7.0"), Err(VersionError::UnsupportedVersion("2.7.0".to_owned())));
+    assert!(load_any_version("asyncapi: 3.0.0").unwrap_err().to_string().contains("v3"));
+    assert_eq!(load_any_version("info: {}"), Err(VersionError::MissingVersion));
+}
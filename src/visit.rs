@@ -0,0 +1,744 @@
+//! Traversal of the whole tree of an [AsyncAPI] document.
+//!
+//! A [Visit] or [VisitMut] implementation overrides the `visit_*` methods of
+//! the nodes it is interested in. Each method receives the JSON Pointer of
+//! the node, e.g. `/channels/user~1signedup/subscribe/message/payload`, and
+//! by default calls the `walk_*` function of the node, which visits its
+//! children. An overriding method calls the `walk_*` function itself to keep
+//! descending into the children of the node.
+//!
+//! The walk functions visit the servers, channels, operations, messages,
+//! traits, parameters, schemas and bindings of the document and of its
+//! components, and the [Reference Objects][ReferenceOr] found in place of
+//! any of them. References are not followed.
+//!
+//! ```
+//! use asyncapi::{visit::Visit, Schema};
+//!
+//! /// Collects the paths of the schemas without a description.
+//! struct Undocumented(Vec<String>);
+//!
+//! impl<'ast> Visit<'ast> for Undocumented {
+//!     fn visit_schema(&mut self, path: &str, schema: &'ast Schema) {
+//!         if schema.schema_data.description.is_none() {
+//!             self.0.push(path.to_owned());
+//!         }
+//!         asyncapi::visit::walk_schema(self, path, schema);
+//!     }
+//! }
+//!
+//! let asyncapi: asyncapi::AsyncAPI = serde_yaml::from_str(r#"
+//! asyncapi: 2.6.0
+//! info:
+//!   title: Account Service
+//!   version: 1.0.0
+//! channels:
+//!   user/signedup:
+//!     subscribe:
+//!       message:
+//!         name: UserSignedUp
+//!         payload:
+//!           type: object
+//!           description: A user signed up.
+//!           properties:
+//!             displayName:
+//!               type: string
+//! "#).unwrap();
+//! let mut undocumented = Undocumented(Vec::new());
+//! undocumented.visit_asyncapi("", &asyncapi);
+//! assert_eq!(undocumented.0, ["/channels/user~1signedup/subscribe/message/payload/properties/displayName"]);
+//! ```
+
+use indexmap::IndexMap;
+
+use crate::{
+    resolver::escape_pointer_token,
+    schema::{AdditionalProperties, Dependency, Items, Type},
+    AsyncAPI, Channel, ChannelBinding, Components, CorrelationId, Message, MessageBinding, MessageTrait, Operation, OperationBinding, OperationMessageType, OperationTrait, Parameter, Payload,
+    ReferenceOr, Schema, SchemaKind, SecurityScheme, Server, ServerBinding, ServerVariable,
+};
+
+fn pointer(parent: &str, token: &str) -> String {
+    format!("{parent}/{}", escape_pointer_token(token))
+}
+
+/// The path of a variant of a [Payload::OneOf] of the message at `path`.
+fn payload_kind(path: &str, index: usize) -> String {
+    format!("{path}/payload/oneOf/{index}")
+}
+
+/// Visits the nodes of a document by reference.
+pub trait Visit<'ast> {
+    fn visit_asyncapi(&mut self, path: &str, asyncapi: &'ast AsyncAPI) {
+        walk_asyncapi(self, path, asyncapi);
+    }
+
+    fn visit_components(&mut self, path: &str, components: &'ast Components) {
+        walk_components(self, path, components);
+    }
+
+    /// Visits the value of the `$ref` field of a Reference Object.
+    fn visit_reference(&mut self, _path: &str, _reference: &'ast str) {}
+
+    fn visit_server(&mut self, path: &str, server: &'ast Server) {
+        walk_server(self, path, server);
+    }
+
+    fn visit_server_variable(&mut self, _path: &str, _variable: &'ast ServerVariable) {}
+
+    fn visit_security_scheme(&mut self, _path: &str, _scheme: &'ast SecurityScheme) {}
+
+    fn visit_channel(&mut self, path: &str, channel: &'ast Channel) {
+        walk_channel(self, path, channel);
+    }
+
+    fn visit_parameter(&mut self, path: &str, parameter: &'ast Parameter) {
+        walk_parameter(self, path, parameter);
+    }
+
+    fn visit_operation(&mut self, path: &str, operation: &'ast Operation) {
+        walk_operation(self, path, operation);
+    }
+
+    fn visit_operation_trait(&mut self, path: &str, operation_trait: &'ast OperationTrait) {
+        walk_operation_trait(self, path, operation_trait);
+    }
+
+    fn visit_message(&mut self, path: &str, message: &'ast Message) {
+        walk_message(self, path, message);
+    }
+
+    fn visit_message_trait(&mut self, path: &str, message_trait: &'ast MessageTrait) {
+        walk_message_trait(self, path, message_trait);
+    }
+
+    fn visit_correlation_id(&mut self, _path: &str, _correlation_id: &'ast CorrelationId) {}
+
+    fn visit_schema(&mut self, path: &str, schema: &'ast Schema) {
+        walk_schema(self, path, schema);
+    }
+
+    fn visit_server_binding(&mut self, _path: &str, _binding: &'ast ServerBinding) {}
+
+    fn visit_channel_binding(&mut self, path: &str, binding: &'ast ChannelBinding) {
+        walk_channel_binding(self, path, binding);
+    }
+
+    fn visit_operation_binding(&mut self, path: &str, binding: &'ast OperationBinding) {
+        walk_operation_binding(self, path, binding);
+    }
+
+    fn visit_message_binding(&mut self, path: &str, binding: &'ast MessageBinding) {
+        walk_message_binding(self, path, binding);
+    }
+}
+
+/// Visits the nodes of a document by mutable reference, e.g. to rewrite
+/// references or fill in missing fields.
+pub trait VisitMut {
+    fn visit_asyncapi_mut(&mut self, path: &str, asyncapi: &mut AsyncAPI) {
+        walk_asyncapi_mut(self, path, asyncapi);
+    }
+
+    fn visit_components_mut(&mut self, path: &str, components: &mut Components) {
+        walk_components_mut(self, path, components);
+    }
+
+    /// Visits the value of the `$ref` field of a Reference Object.
+    fn visit_reference_mut(&mut self, _path: &str, _reference: &mut String) {}
+
+    fn visit_server_mut(&mut self, path: &str, server: &mut Server) {
+        walk_server_mut(self, path, server);
+    }
+
+    fn visit_server_variable_mut(&mut self, _path: &str, _variable: &mut ServerVariable) {}
+
+    fn visit_security_scheme_mut(&mut self, _path: &str, _scheme: &mut SecurityScheme) {}
+
+    fn visit_channel_mut(&mut self, path: &str, channel: &mut Channel) {
+        walk_channel_mut(self, path, channel);
+    }
+
+    fn visit_parameter_mut(&mut self, path: &str, parameter: &mut Parameter) {
+        walk_parameter_mut(self, path, parameter);
+    }
+
+    fn visit_operation_mut(&mut self, path: &str, operation: &mut Operation) {
+        walk_operation_mut(self, path, operation);
+    }
+
+    fn visit_operation_trait_mut(&mut self, path: &str, operation_trait: &mut OperationTrait) {
+        walk_operation_trait_mut(self, path, operation_trait);
+    }
+
+    fn visit_message_mut(&mut self, path: &str, message: &mut Message) {
+        walk_message_mut(self, path, message);
+    }
+
+    fn visit_message_trait_mut(&mut self, path: &str, message_trait: &mut MessageTrait) {
+        walk_message_trait_mut(self, path, message_trait);
+    }
+
+    fn visit_correlation_id_mut(&mut self, _path: &str, _correlation_id: &mut CorrelationId) {}
+
+    fn visit_schema_mut(&mut self, path: &str, schema: &mut Schema) {
+        walk_schema_mut(self, path, schema);
+    }
+
+    fn visit_server_binding_mut(&mut self, _path: &str, _binding: &mut ServerBinding) {}
+
+    fn visit_channel_binding_mut(&mut self, path: &str, binding: &mut ChannelBinding) {
+        walk_channel_binding_mut(self, path, binding);
+    }
+
+    fn visit_operation_binding_mut(&mut self, path: &str, binding: &mut OperationBinding) {
+        walk_operation_binding_mut(self, path, binding);
+    }
+
+    fn visit_message_binding_mut(&mut self, path: &str, binding: &mut MessageBinding) {
+        walk_message_binding_mut(self, path, binding);
+    }
+}
+
+/// Visits the reference or the item of a [ReferenceOr] with `visit`.
+fn reference_or<'ast, V: Visit<'ast> + ?Sized, T>(visitor: &mut V, path: &str, item: &'ast ReferenceOr<T>, visit: impl FnOnce(&mut V, &str, &'ast T)) {
+    match item {
+        ReferenceOr::Reference { reference } => visitor.visit_reference(path, reference),
+        ReferenceOr::Item(item) => visit(visitor, path, item),
+    }
+}
+
+fn reference_or_mut<V: VisitMut + ?Sized, T>(visitor: &mut V, path: &str, item: &mut ReferenceOr<T>, visit: impl FnOnce(&mut V, &str, &mut T)) {
+    match item {
+        ReferenceOr::Reference { reference } => visitor.visit_reference_mut(path, reference),
+        ReferenceOr::Item(item) => visit(visitor, path, item),
+    }
+}
+
+fn map<'ast, V: Visit<'ast> + ?Sized, T>(visitor: &mut V, path: &str, map: &'ast IndexMap<String, ReferenceOr<T>>, mut visit: impl FnMut(&mut V, &str, &'ast T)) {
+    for (name, item) in map {
+        reference_or(visitor, &pointer(path, name), item, &mut visit);
+    }
+}
+
+fn map_mut<V: VisitMut + ?Sized, T>(visitor: &mut V, path: &str, map: &mut IndexMap<String, ReferenceOr<T>>, mut visit: impl FnMut(&mut V, &str, &mut T)) {
+    for (name, item) in map {
+        reference_or_mut(visitor, &pointer(path, name), item, &mut visit);
+    }
+}
+
+fn list<'ast, V: Visit<'ast> + ?Sized, T>(visitor: &mut V, path: &str, list: &'ast [ReferenceOr<T>], mut visit: impl FnMut(&mut V, &str, &'ast T)) {
+    for (index, item) in list.iter().enumerate() {
+        reference_or(visitor, &pointer(path, &index.to_string()), item, &mut visit);
+    }
+}
+
+fn list_mut<V: VisitMut + ?Sized, T>(visitor: &mut V, path: &str, list: &mut [ReferenceOr<T>], mut visit: impl FnMut(&mut V, &str, &mut T)) {
+    for (index, item) in list.iter_mut().enumerate() {
+        reference_or_mut(visitor, &pointer(path, &index.to_string()), item, &mut visit);
+    }
+}
+
+fn option<'ast, V: Visit<'ast> + ?Sized, T>(visitor: &mut V, path: &str, token: &str, item: &'ast Option<ReferenceOr<T>>, visit: impl FnOnce(&mut V, &str, &'ast T)) {
+    if let Some(item) = item {
+        reference_or(visitor, &pointer(path, token), item, visit);
+    }
+}
+
+fn option_mut<V: VisitMut + ?Sized, T>(visitor: &mut V, path: &str, token: &str, item: &mut Option<ReferenceOr<T>>, visit: impl FnOnce(&mut V, &str, &mut T)) {
+    if let Some(item) = item {
+        reference_or_mut(visitor, &pointer(path, token), item, visit);
+    }
+}
+
+/// Visits the schemas of the fields of a binding.
+fn binding_schemas<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, path: &str, schemas: &[(&str, &'ast Option<Schema>)]) {
+    for (token, schema) in schemas {
+        if let Some(schema) = schema {
+            visitor.visit_schema(&pointer(path, token), schema);
+        }
+    }
+}
+
+fn binding_schemas_mut<V: VisitMut + ?Sized>(visitor: &mut V, path: &str, schemas: &mut [(&str, &mut Option<Schema>)]) {
+    for (token, schema) in schemas {
+        if let Some(schema) = schema {
+            visitor.visit_schema_mut(&pointer(path, token), schema);
+        }
+    }
+}
+
+pub fn walk_asyncapi<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, path: &str, asyncapi: &'ast AsyncAPI) {
+    map(visitor, &pointer(path, "servers"), &asyncapi.servers, V::visit_server);
+    let channels = pointer(path, "channels");
+    for (name, channel) in &asyncapi.channels {
+        visitor.visit_channel(&pointer(&channels, name), channel);
+    }
+    if let Some(components) = &asyncapi.components {
+        visitor.visit_components(&pointer(path, "components"), components);
+    }
+}
+
+pub fn walk_asyncapi_mut<V: VisitMut + ?Sized>(visitor: &mut V, path: &str, asyncapi: &mut AsyncAPI) {
+    map_mut(visitor, &pointer(path, "servers"), &mut asyncapi.servers, V::visit_server_mut);
+    let channels = pointer(path, "channels");
+    for (name, channel) in &mut asyncapi.channels {
+        visitor.visit_channel_mut(&pointer(&channels, name), channel);
+    }
+    if let Some(components) = &mut asyncapi.components {
+        visitor.visit_components_mut(&pointer(path, "components"), components);
+    }
+}
+
+pub fn walk_components<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, path: &str, components: &'ast Components) {
+    map(visitor, &pointer(path, "schemas"), &components.schemas, V::visit_schema);
+    map(visitor, &pointer(path, "messages"), &components.messages, V::visit_message);
+    map(visitor, &pointer(path, "securitySchemes"), &components.security_schemes, V::visit_security_scheme);
+    map(visitor, &pointer(path, "parameters"), &components.parameters, V::visit_parameter);
+    map(visitor, &pointer(path, "correlationIds"), &components.correlation_ids, V::visit_correlation_id);
+    map(visitor, &pointer(path, "operationTraits"), &components.operation_traits, V::visit_operation_trait);
+    map(visitor, &pointer(path, "messageTraits"), &components.message_traits, V::visit_message_trait);
+    map(visitor, &pointer(path, "servers"), &components.servers, V::visit_server);
+    map(visitor, &pointer(path, "serverVariables"), &components.server_variables, V::visit_server_variable);
+    map(visitor, &pointer(path, "serverBindings"), &components.server_bindings, V::visit_server_binding);
+    let channels = pointer(path, "channels");
+    for (name, channel) in &components.channels {
+        visitor.visit_channel(&pointer(&channels, name), channel);
+    }
+    map(visitor, &pointer(path, "channelBindings"), &components.channel_bindings, V::visit_channel_binding);
+    map(visitor, &pointer(path, "operationBindings"), &components.operation_bindings, V::visit_operation_binding);
+    map(visitor, &pointer(path, "messageBindings"), &components.message_bindings, V::visit_message_binding);
+}
+
+pub fn walk_components_mut<V: VisitMut + ?Sized>(visitor: &mut V, path: &str, components: &mut Components) {
+    map_mut(visitor, &pointer(path, "schemas"), &mut components.schemas, V::visit_schema_mut);
+    map_mut(visitor, &pointer(path, "messages"), &mut components.messages, V::visit_message_mut);
+    map_mut(visitor, &pointer(path, "securitySchemes"), &mut components.security_schemes, V::visit_security_scheme_mut);
+    map_mut(visitor, &pointer(path, "parameters"), &mut components.parameters, V::visit_parameter_mut);
+    map_mut(visitor, &pointer(path, "correlationIds"), &mut components.correlation_ids, V::visit_correlation_id_mut);
+    map_mut(visitor, &pointer(path, "operationTraits"), &mut components.operation_traits, V::visit_operation_trait_mut);
+    map_mut(visitor, &pointer(path, "messageTraits"), &mut components.message_traits, V::visit_message_trait_mut);
+    map_mut(visitor, &pointer(path, "servers"), &mut components.servers, V::visit_server_mut);
+    map_mut(visitor, &pointer(path, "serverVariables"), &mut components.server_variables, V::visit_server_variable_mut);
+    map_mut(visitor, &pointer(path, "serverBindings"), &mut components.server_bindings, V::visit_server_binding_mut);
+    let channels = pointer(path, "channels");
+    for (name, channel) in &mut components.channels {
+        visitor.visit_channel_mut(&pointer(&channels, name), channel);
+    }
+    map_mut(visitor, &pointer(path, "channelBindings"), &mut components.channel_bindings, V::visit_channel_binding_mut);
+    map_mut(visitor, &pointer(path, "operationBindings"), &mut components.operation_bindings, V::visit_operation_binding_mut);
+    map_mut(visitor, &pointer(path, "messageBindings"), &mut components.message_bindings, V::visit_message_binding_mut);
+}
+
+pub fn walk_server<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, path: &str, server: &'ast Server) {
+    let variables = pointer(path, "variables");
+    for (name, variable) in &server.variables {
+        visitor.visit_server_variable(&pointer(&variables, name), variable);
+    }
+    option(visitor, path, "bindings", &server.bindings, V::visit_server_binding);
+}
+
+pub fn walk_server_mut<V: VisitMut + ?Sized>(visitor: &mut V, path: &str, server: &mut Server) {
+    let variables = pointer(path, "variables");
+    for (name, variable) in &mut server.variables {
+        visitor.visit_server_variable_mut(&pointer(&variables, name), variable);
+    }
+    option_mut(visitor, path, "bindings", &mut server.bindings, V::visit_server_binding_mut);
+}
+
+pub fn walk_channel<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, path: &str, channel: &'ast Channel) {
+    map(visitor, &pointer(path, "parameters"), &channel.parameters, V::visit_parameter);
+    option(visitor, path, "bindings", &channel.bindings, V::visit_channel_binding);
+    if let Some(subscribe) = &channel.subscribe {
+        visitor.visit_operation(&pointer(path, "subscribe"), subscribe);
+    }
+    if let Some(publish) = &channel.publish {
+        visitor.visit_operation(&pointer(path, "publish"), publish);
+    }
+}
+
+pub fn walk_channel_mut<V: VisitMut + ?Sized>(visitor: &mut V, path: &str, channel: &mut Channel) {
+    map_mut(visitor, &pointer(path, "parameters"), &mut channel.parameters, V::visit_parameter_mut);
+    option_mut(visitor, path, "bindings", &mut channel.bindings, V::visit_channel_binding_mut);
+    if let Some(subscribe) = &mut channel.subscribe {
+        visitor.visit_operation_mut(&pointer(path, "subscribe"), subscribe);
+    }
+    if let Some(publish) = &mut channel.publish {
+        visitor.visit_operation_mut(&pointer(path, "publish"), publish);
+    }
+}
+
+pub fn walk_parameter<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, path: &str, parameter: &'ast Parameter) {
+    option(visitor, path, "schema", &parameter.schema, V::visit_schema);
+}
+
+pub fn walk_parameter_mut<V: VisitMut + ?Sized>(visitor: &mut V, path: &str, parameter: &mut Parameter) {
+    option_mut(visitor, path, "schema", &mut parameter.schema, V::visit_schema_mut);
+}
+
+pub fn walk_operation<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, path: &str, operation: &'ast Operation) {
+    option(visitor, path, "bindings", &operation.bindings, V::visit_operation_binding);
+    list(visitor, &pointer(path, "traits"), &operation.traits, V::visit_operation_trait);
+    match &operation.message {
+        Some(OperationMessageType::Single(message)) => reference_or(visitor, &pointer(path, "message"), message, V::visit_message),
+        Some(OperationMessageType::Map(messages)) => map(visitor, &pointer(path, "message"), messages, V::visit_message),
+        Some(OperationMessageType::OneOf(messages)) => list(visitor, &pointer(&pointer(path, "message"), "oneOf"), messages, V::visit_message),
+        None => {}
+    }
+}
+
+pub fn walk_operation_mut<V: VisitMut + ?Sized>(visitor: &mut V, path: &str, operation: &mut Operation) {
+    option_mut(visitor, path, "bindings", &mut operation.bindings, V::visit_operation_binding_mut);
+    list_mut(visitor, &pointer(path, "traits"), &mut operation.traits, V::visit_operation_trait_mut);
+    match &mut operation.message {
+        Some(OperationMessageType::Single(message)) => reference_or_mut(visitor, &pointer(path, "message"), message, V::visit_message_mut),
+        Some(OperationMessageType::Map(messages)) => map_mut(visitor, &pointer(path, "message"), messages, V::visit_message_mut),
+        Some(OperationMessageType::OneOf(messages)) => list_mut(visitor, &pointer(&pointer(path, "message"), "oneOf"), messages, V::visit_message_mut),
+        None => {}
+    }
+}
+
+pub fn walk_operation_trait<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, path: &str, operation_trait: &'ast OperationTrait) {
+    option(visitor, path, "bindings", &operation_trait.bindings, V::visit_operation_binding);
+}
+
+pub fn walk_operation_trait_mut<V: VisitMut + ?Sized>(visitor: &mut V, path: &str, operation_trait: &mut OperationTrait) {
+    option_mut(visitor, path, "bindings", &mut operation_trait.bindings, V::visit_operation_binding_mut);
+}
+
+pub fn walk_message<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, path: &str, message: &'ast Message) {
+    option(visitor, path, "headers", &message.headers, V::visit_schema);
+    match &message.payload {
+        Some(Payload::Schema(payload)) => reference_or(visitor, &pointer(path, "payload"), payload, V::visit_schema),
+        Some(Payload::OneOf { one_of, .. }) => {
+            for (index, kind) in one_of.iter().enumerate() {
+                visitor.visit_schema(&pointer(&payload_kind(path, index), "payload"), &kind.payload);
+            }
+        }
+        _ => {}
+    }
+    option(visitor, path, "correlationId", &message.correlation_id, V::visit_correlation_id);
+    option(visitor, path, "bindings", &message.bindings, V::visit_message_binding);
+    list(visitor, &pointer(path, "traits"), &message.traits, V::visit_message_trait);
+}
+
+pub fn walk_message_mut<V: VisitMut + ?Sized>(visitor: &mut V, path: &str, message: &mut Message) {
+    option_mut(visitor, path, "headers", &mut message.headers, V::visit_schema_mut);
+    match &mut message.payload {
+        Some(Payload::Schema(payload)) => reference_or_mut(visitor, &pointer(path, "payload"), payload, V::visit_schema_mut),
+        Some(Payload::OneOf { one_of, .. }) => {
+            for (index, kind) in one_of.iter_mut().enumerate() {
+                visitor.visit_schema_mut(&pointer(&payload_kind(path, index), "payload"), &mut kind.payload);
+            }
+        }
+        _ => {}
+    }
+    option_mut(visitor, path, "correlationId", &mut message.correlation_id, V::visit_correlation_id_mut);
+    option_mut(visitor, path, "bindings", &mut message.bindings, V::visit_message_binding_mut);
+    list_mut(visitor, &pointer(path, "traits"), &mut message.traits, V::visit_message_trait_mut);
+}
+
+pub fn walk_message_trait<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, path: &str, message_trait: &'ast MessageTrait) {
+    option(visitor, path, "headers", &message_trait.headers, V::visit_schema);
+    option(visitor, path, "correlationId", &message_trait.correlation_id, V::visit_correlation_id);
+    option(visitor, path, "bindings", &message_trait.bindings, V::visit_message_binding);
+}
+
+pub fn walk_message_trait_mut<V: VisitMut + ?Sized>(visitor: &mut V, path: &str, message_trait: &mut MessageTrait) {
+    option_mut(visitor, path, "headers", &mut message_trait.headers, V::visit_schema_mut);
+    option_mut(visitor, path, "correlationId", &mut message_trait.correlation_id, V::visit_correlation_id_mut);
+    option_mut(visitor, path, "bindings", &mut message_trait.bindings, V::visit_message_binding_mut);
+}
+
+pub fn walk_schema<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, path: &str, schema: &'ast Schema) {
+    let data = &schema.schema_data;
+    map(visitor, &pointer(path, "definitions"), &data.definitions, |visitor, path, schema| visitor.visit_schema(path, schema));
+    for (token, subschema) in [("not", &data.not), ("if", &data.if_schema), ("then", &data.then_schema), ("else", &data.else_schema)] {
+        option(visitor, path, token, subschema, |visitor, path, schema| visitor.visit_schema(path, schema));
+    }
+    for (token, schemas) in [("allOf", &data.all_of), ("anyOf", &data.any_of), ("oneOf", &data.one_of)] {
+        list(visitor, &pointer(path, token), schemas, V::visit_schema);
+    }
+    let (object, array) = match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => (
+            Some((
+                &object.properties,
+                &object.pattern_properties,
+                &object.additional_properties,
+                &object.property_names,
+                &object.dependencies,
+            )),
+            None,
+        ),
+        SchemaKind::Type(Type::Array(array)) => (None, Some((&array.items, &array.additional_items, &array.contains))),
+        SchemaKind::Type(_) | SchemaKind::Bool(_) => (None, None),
+        SchemaKind::OneOf { one_of } => {
+            list(visitor, &pointer(path, "oneOf"), one_of, V::visit_schema);
+            (None, None)
+        }
+        SchemaKind::AllOf { all_of } => {
+            list(visitor, &pointer(path, "allOf"), all_of, V::visit_schema);
+            (None, None)
+        }
+        SchemaKind::AnyOf { any_of } => {
+            list(visitor, &pointer(path, "anyOf"), any_of, V::visit_schema);
+            (None, None)
+        }
+        SchemaKind::Any(any) => (
+            Some((&any.properties, &any.pattern_properties, &any.additional_properties, &any.property_names, &any.dependencies)),
+            Some((&any.items, &any.additional_items, &any.contains)),
+        ),
+    };
+    if let Some((properties, pattern_properties, additional_properties, property_names, dependencies)) = object {
+        map(visitor, &pointer(path, "properties"), properties, |visitor, path, schema| visitor.visit_schema(path, schema));
+        map(visitor, &pointer(path, "patternProperties"), pattern_properties, |visitor, path, schema| {
+            visitor.visit_schema(path, schema)
+        });
+        if let Some(AdditionalProperties::Schema(schema)) = additional_properties {
+            reference_or(visitor, &pointer(path, "additionalProperties"), schema, V::visit_schema);
+        }
+        option(visitor, path, "propertyNames", property_names, |visitor, path, schema| visitor.visit_schema(path, schema));
+        for (name, dependency) in dependencies {
+            if let Dependency::Schema(schema) = dependency {
+                reference_or(visitor, &pointer(&pointer(path, "dependencies"), name), schema, |visitor, path, schema| {
+                    visitor.visit_schema(path, schema)
+                });
+            }
+        }
+    }
+    if let Some((items, additional_items, contains)) = array {
+        match items {
+            Some(Items::Schema(schema)) => reference_or(visitor, &pointer(path, "items"), schema, |visitor, path, schema| visitor.visit_schema(path, schema)),
+            Some(Items::Tuple(schemas)) => list(visitor, &pointer(path, "items"), schemas, |visitor, path, schema| visitor.visit_schema(path, schema)),
+            None => {}
+        }
+        if let Some(AdditionalProperties::Schema(schema)) = additional_items {
+            reference_or(visitor, &pointer(path, "additionalItems"), schema, V::visit_schema);
+        }
+        option(visitor, path, "contains", contains, |visitor, path, schema| visitor.visit_schema(path, schema));
+    }
+}
+
+pub fn walk_schema_mut<V: VisitMut + ?Sized>(visitor: &mut V, path: &str, schema: &mut Schema) {
+    let data = &mut schema.schema_data;
+    map_mut(visitor, &pointer(path, "definitions"), &mut data.definitions, |visitor, path, schema| {
+        visitor.visit_schema_mut(path, schema)
+    });
+    for (token, subschema) in [("not", &mut data.not), ("if", &mut data.if_schema), ("then", &mut data.then_schema), ("else", &mut data.else_schema)] {
+        option_mut(visitor, path, token, subschema, |visitor, path, schema| visitor.visit_schema_mut(path, schema));
+    }
+    for (token, schemas) in [("allOf", &mut data.all_of), ("anyOf", &mut data.any_of), ("oneOf", &mut data.one_of)] {
+        list_mut(visitor, &pointer(path, token), schemas, V::visit_schema_mut);
+    }
+    let (object, array) = match &mut schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => (
+            Some((
+                &mut object.properties,
+                &mut object.pattern_properties,
+                &mut object.additional_properties,
+                &mut object.property_names,
+                &mut object.dependencies,
+            )),
+            None,
+        ),
+        SchemaKind::Type(Type::Array(array)) => (None, Some((&mut array.items, &mut array.additional_items, &mut array.contains))),
+        SchemaKind::Type(_) | SchemaKind::Bool(_) => (None, None),
+        SchemaKind::OneOf { one_of } => {
+            list_mut(visitor, &pointer(path, "oneOf"), one_of, V::visit_schema_mut);
+            (None, None)
+        }
+        SchemaKind::AllOf { all_of } => {
+            list_mut(visitor, &pointer(path, "allOf"), all_of, V::visit_schema_mut);
+            (None, None)
+        }
+        SchemaKind::AnyOf { any_of } => {
+            list_mut(visitor, &pointer(path, "anyOf"), any_of, V::visit_schema_mut);
+            (None, None)
+        }
+        SchemaKind::Any(any) => (
+            Some((
+                &mut any.properties,
+                &mut any.pattern_properties,
+                &mut any.additional_properties,
+                &mut any.property_names,
+                &mut any.dependencies,
+            )),
+            Some((&mut any.items, &mut any.additional_items, &mut any.contains)),
+        ),
+    };
+    if let Some((properties, pattern_properties, additional_properties, property_names, dependencies)) = object {
+        map_mut(visitor, &pointer(path, "properties"), properties, |visitor, path, schema| visitor.visit_schema_mut(path, schema));
+        map_mut(visitor, &pointer(path, "patternProperties"), pattern_properties, |visitor, path, schema| {
+            visitor.visit_schema_mut(path, schema)
+        });
+        if let Some(AdditionalProperties::Schema(schema)) = additional_properties {
+            reference_or_mut(visitor, &pointer(path, "additionalProperties"), schema, V::visit_schema_mut);
+        }
+        option_mut(visitor, path, "propertyNames", property_names, |visitor, path, schema| visitor.visit_schema_mut(path, schema));
+        for (name, dependency) in dependencies {
+            if let Dependency::Schema(schema) = dependency {
+                reference_or_mut(visitor, &pointer(&pointer(path, "dependencies"), name), schema, |visitor, path, schema| {
+                    visitor.visit_schema_mut(path, schema)
+                });
+            }
+        }
+    }
+    if let Some((items, additional_items, contains)) = array {
+        match items {
+            Some(Items::Schema(schema)) => reference_or_mut(visitor, &pointer(path, "items"), schema, |visitor, path, schema| visitor.visit_schema_mut(path, schema)),
+            Some(Items::Tuple(schemas)) => list_mut(visitor, &pointer(path, "items"), schemas, |visitor, path, schema| visitor.visit_schema_mut(path, schema)),
+            None => {}
+        }
+        if let Some(AdditionalProperties::Schema(schema)) = additional_items {
+            reference_or_mut(visitor, &pointer(path, "additionalItems"), schema, V::visit_schema_mut);
+        }
+        option_mut(visitor, path, "contains", contains, |visitor, path, schema| visitor.visit_schema_mut(path, schema));
+    }
+}
+
+pub fn walk_channel_binding<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, path: &str, binding: &'ast ChannelBinding) {
+    if let Some(ws) = &binding.ws {
+        binding_schemas(visitor, &pointer(path, "ws"), &[("query", &ws.query), ("headers", &ws.headers)]);
+    }
+}
+
+pub fn walk_channel_binding_mut<V: VisitMut + ?Sized>(visitor: &mut V, path: &str, binding: &mut ChannelBinding) {
+    if let Some(ws) = &mut binding.ws {
+        binding_schemas_mut(visitor, &pointer(path, "ws"), &mut [("query", &mut ws.query), ("headers", &mut ws.headers)]);
+    }
+}
+
+pub fn walk_operation_binding<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, path: &str, binding: &'ast OperationBinding) {
+    if let Some(http) = &binding.http {
+        binding_schemas(visitor, &pointer(path, "http"), &[("query", &http.query)]);
+    }
+    if let Some(kafka) = &binding.kafka {
+        binding_schemas(visitor, &pointer(path, "kafka"), &[("groupId", &kafka.group_id), ("clientId", &kafka.client_id)]);
+    }
+}
+
+pub fn walk_operation_binding_mut<V: VisitMut + ?Sized>(visitor: &mut V, path: &str, binding: &mut OperationBinding) {
+    if let Some(http) = &mut binding.http {
+        binding_schemas_mut(visitor, &pointer(path, "http"), &mut [("query", &mut http.query)]);
+    }
+    if let Some(kafka) = &mut binding.kafka {
+        binding_schemas_mut(visitor, &pointer(path, "kafka"), &mut [("groupId", &mut kafka.group_id), ("clientId", &mut kafka.client_id)]);
+    }
+}
+
+pub fn walk_message_binding<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, path: &str, binding: &'ast MessageBinding) {
+    if let Some(http) = &binding.http {
+        binding_schemas(visitor, &pointer(path, "http"), &[("headers", &http.headers)]);
+    }
+    if let Some(ws) = &binding.ws {
+        binding_schemas(visitor, &pointer(path, "ws"), &[("query", &ws.query), ("headers", &ws.headers)]);
+    }
+    if let Some(kafka) = &binding.kafka {
+        binding_schemas(visitor, &pointer(path, "kafka"), &[("key", &kafka.key)]);
+    }
+    if let Some(anypointmq) = &binding.anypointmq {
+        binding_schemas(visitor, &pointer(path, "anypointmq"), &[("headers", &anypointmq.headers)]);
+    }
+}
+
+pub fn walk_message_binding_mut<V: VisitMut + ?Sized>(visitor: &mut V, path: &str, binding: &mut MessageBinding) {
+    if let Some(http) = &mut binding.http {
+        binding_schemas_mut(visitor, &pointer(path, "http"), &mut [("headers", &mut http.headers)]);
+    }
+    if let Some(ws) = &mut binding.ws {
+        binding_schemas_mut(visitor, &pointer(path, "ws"), &mut [("query", &mut ws.query), ("headers", &mut ws.headers)]);
+    }
+    if let Some(kafka) = &mut binding.kafka {
+        binding_schemas_mut(visitor, &pointer(path, "kafka"), &mut [("key", &mut kafka.key)]);
+    }
+    if let Some(anypointmq) = &mut binding.anypointmq {
+        binding_schemas_mut(visitor, &pointer(path, "anypointmq"), &mut [("headers", &mut anypointmq.headers)]);
+    }
+}
+
+#[test]
+fn test_visit() {
+    let example = r##"
+    asyncapi: 2.6.0
+    info:
+      title: Visit
+      version: 1.0.0
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            $ref: "#/components/messages/userSignedUp"
+    components:
+      messages:
+        userSignedUp:
+          payload:
+            $ref: "#/components/schemas/user"
+          traits:
+            - headers:
+                type: object
+          bindings:
+            kafka:
+              key:
+                type: string
+      schemas:
+        user:
+          type: object
+          properties:
+            friends:
+              type: array
+              items:
+                $ref: "#/components/schemas/user"
+      channels:
+        user/deleted:
+          publish:
+            message:
+              oneOf:
+                - name: userDeleted
+                  payload:
+                    type: string
+    "##;
+    let mut asyncapi: AsyncAPI = serde_yaml::from_str(example).expect("Could not deserialize visit example");
+
+    #[derive(Default)]
+    struct Paths(Vec<String>);
+    impl<'ast> Visit<'ast> for Paths {
+        fn visit_reference(&mut self, path: &str, reference: &'ast str) {
+            self.0.push(format!("{path} -> {reference}"));
+        }
+        fn visit_schema(&mut self, path: &str, schema: &'ast Schema) {
+            self.0.push(path.to_owned());
+            walk_schema(self, path, schema);
+        }
+    }
+    let mut paths = Paths::default();
+    paths.visit_asyncapi("", &asyncapi);
+    assert_eq!(
+        paths.0,
+        [
+            "/channels/user~1signedup/subscribe/message -> #/components/messages/userSignedUp",
+            "/components/schemas/user",
+            "/components/schemas/user/properties/friends",
+            "/components/schemas/user/properties/friends/items -> #/components/schemas/user",
+            "/components/messages/userSignedUp/payload -> #/components/schemas/user",
+            "/components/messages/userSignedUp/bindings/kafka/key",
+            "/components/messages/userSignedUp/traits/0/headers",
+            "/components/channels/user~1deleted/publish/message/oneOf/0/payload",
+        ]
+    );
+
+    struct Rename;
+    impl VisitMut for Rename {
+        fn visit_reference_mut(&mut self, _path: &str, reference: &mut String) {
+            *reference = reference.replace("/user", "/User");
+        }
+    }
+    Rename.visit_asyncapi_mut("", &mut asyncapi);
+    let schema = match &asyncapi.components.as_ref().unwrap().messages["userSignedUp"] {
+        ReferenceOr::Item(Message {
+            payload: Some(Payload::Schema(schema)),
+            ..
+        }) => schema,
+        other => panic!("unexpected message {other:?}"),
+    };
+    assert_eq!(schema, &ReferenceOr::ref_("#/components/schemas/User"));
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "codegen")]
+//! The code generated for `codegen/document.yaml` is checked in next to it
+//! and compiled as modules of this test, so that generators emitting code
+//! which does not compile fail here. Run the test with `UPDATE_GENERATED`
+//! set to write the generated code again.
+
+use std::{fs, path::Path};
+
+use asyncapi::{codegen, AsyncAPI};
+
+#[allow(dead_code, clippy::all)]
+#[path = "codegen/types.rs"]
+mod types;
+
+#[allow(dead_code, clippy::all)]
+#[path = "codegen/channels.rs"]
+mod channels;
+
+fn document() -> AsyncAPI {
+    serde_yaml::from_str(include_str!("codegen/document.yaml")).expect("Could not deserialize the codegen document")
+}
+
+fn check(file: &str, source: String) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("codegen").join(file);
+    if std::env::var_os("UPDATE_GENERATED").is_some() {
+        fs::write(&path, &source).unwrap();
+    }
+    assert_eq!(fs::read_to_string(&path).unwrap(), source, "`{file}` is out of date, run the test with `UPDATE_GENERATED=1`");
+}
+
+#[test]
+fn test_generated_types() {
+    check("types.rs", codegen::format_source(codegen::generate_types(&document())).unwrap());
+}
+
+#[test]
+fn test_generated_channels() {
+    check("channels.rs", codegen::format_source(codegen::generate_channels(&document())).unwrap());
+}
@@ -0,0 +1,189 @@
+/// A string in the `email` format.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Email(pub String);
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct User {
+    pub id: i64,
+    pub user_name: String,
+    #[serde(rename = "userName")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_name_2: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Kind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<Email>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_properties: Option<bool>,
+    #[serde(flatten)]
+    pub additional_properties_6: std::collections::HashMap<String, String>,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Kind {
+    #[serde(rename = "admin")]
+    Admin,
+    #[serde(rename = "guest")]
+    Guest,
+    #[serde(rename = "Guest")]
+    Guest2,
+}
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind")]
+pub enum Event {
+    #[serde(rename = "Created")]
+    Created(Created),
+    #[serde(rename = "Deleted")]
+    Deleted(Deleted),
+}
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Created {
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Deleted {
+    pub kind: String,
+}
+/// A string in the `date-time` format.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct DateTime(pub String);
+/// audit
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AuditedAudit {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at: Option<DateTime>,
+}
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Audited {
+    #[serde(flatten)]
+    pub user: User,
+    #[serde(flatten)]
+    pub audit: AuditedAudit,
+}
+pub type UserRejectedPayload = String;
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum UserUserIdSignedupSubscribeMessage {
+    UserSignedUp(User),
+    UserRejected(UserRejectedPayload),
+}
+pub type UserDeletedPublishMessagePayload = String;
+pub type UserDeletedSubscribeMessagePayload = String;
+pub type UserDeletedPublishMessagePayload2 = String;
+pub type UserDeletedSubscribeMessagePayload2 = String;
+/// Sends the payloads of messages to the address of a channel.
+pub trait Transport {
+    type Error;
+    fn send<P: serde::Serialize + ?Sized>(
+        &self,
+        address: &str,
+        payload: &P,
+    ) -> Result<(), Self::Error>;
+}
+/// Matches an address against the parts of a channel name around its
+/// parameters, and returns the values of the parameters.
+fn match_address<'a>(address: &'a str, parts: &[&str]) -> Option<Vec<&'a str>> {
+    let mut rest = address.strip_prefix(parts[0])?;
+    let mut values = Vec::new();
+    for part in &parts[1..] {
+        let (value, next) = if part.is_empty() {
+            (rest, "")
+        } else {
+            rest.split_once(part)?
+        };
+        values.push(value);
+        rest = next;
+    }
+    rest.is_empty().then_some(values)
+}
+/// Handles the messages published to `user/{userId}/signedup`.
+pub trait UserUserIdSignedupHandler {
+    fn user_signed_up(&mut self, user_id: i64, payload: User);
+    /// Calls [`Self::user_signed_up`] when `address` matches `user/{userId}/signedup`, with the payload deserialized from JSON.
+    /// Returns `None` when the address does not match the channel.
+    fn dispatch_user_user_id_signedup(
+        &mut self,
+        address: &str,
+        payload: &[u8],
+    ) -> Option<Result<(), serde_json::Error>> {
+        let values = match_address(address, &["user/", "/signedup"])?;
+        let user_id = values[0].parse().ok()?;
+        let payload = match serde_json::from_slice(payload) {
+            Ok(payload) => payload,
+            Err(error) => return Some(Err(error)),
+        };
+        self.user_signed_up(user_id, payload);
+        Some(Ok(()))
+    }
+}
+/// Sends the messages of `user/{userId}/signedup`.
+pub trait UserUserIdSignedupSender: Transport {
+    fn send_user_user_id_signedup(
+        &self,
+        user_id: i64,
+        payload: &UserUserIdSignedupSubscribeMessage,
+    ) -> Result<(), Self::Error> {
+        self.send(&format!("user/{}/signedup", user_id), payload)
+    }
+}
+impl<T: Transport + ?Sized> UserUserIdSignedupSender for T {}
+/// Handles the messages published to `user.deleted`.
+pub trait UserDeletedHandler {
+    fn on_user_deleted(&mut self, payload: UserDeletedPublishMessagePayload);
+    /// Calls [`Self::on_user_deleted`] when `address` matches `user.deleted`, with the payload deserialized from JSON.
+    /// Returns `None` when the address does not match the channel.
+    fn dispatch_user_deleted(
+        &mut self,
+        address: &str,
+        payload: &[u8],
+    ) -> Option<Result<(), serde_json::Error>> {
+        let _ = match_address(address, &["user.deleted"])?;
+        let payload = match serde_json::from_slice(payload) {
+            Ok(payload) => payload,
+            Err(error) => return Some(Err(error)),
+        };
+        self.on_user_deleted(payload);
+        Some(Ok(()))
+    }
+}
+/// Sends the messages of `user.deleted`.
+pub trait UserDeletedSender: Transport {
+    fn send_user_deleted(
+        &self,
+        payload: &UserDeletedSubscribeMessagePayload,
+    ) -> Result<(), Self::Error> {
+        self.send(&format!("user.deleted"), payload)
+    }
+}
+impl<T: Transport + ?Sized> UserDeletedSender for T {}
+/// Handles the messages published to `user/deleted`.
+pub trait UserDeletedHandler2 {
+    fn on_user_deleted_2(&mut self, payload: UserDeletedPublishMessagePayload2);
+    /// Calls [`Self::on_user_deleted_2`] when `address` matches `user/deleted`, with the payload deserialized from JSON.
+    /// Returns `None` when the address does not match the channel.
+    fn dispatch_user_deleted_2(
+        &mut self,
+        address: &str,
+        payload: &[u8],
+    ) -> Option<Result<(), serde_json::Error>> {
+        let _ = match_address(address, &["user/deleted"])?;
+        let payload = match serde_json::from_slice(payload) {
+            Ok(payload) => payload,
+            Err(error) => return Some(Err(error)),
+        };
+        self.on_user_deleted_2(payload);
+        Some(Ok(()))
+    }
+}
+/// Sends the messages of `user/deleted`.
+pub trait UserDeletedSender2: Transport {
+    fn send_user_deleted_2(
+        &self,
+        payload: &UserDeletedSubscribeMessagePayload2,
+    ) -> Result<(), Self::Error> {
+        self.send(&format!("user/deleted"), payload)
+    }
+}
+impl<T: Transport + ?Sized> UserDeletedSender2 for T {}
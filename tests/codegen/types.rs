@@ -0,0 +1,75 @@
+/// A string in the `email` format.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Email(pub String);
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct User {
+    pub id: i64,
+    pub user_name: String,
+    #[serde(rename = "userName")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_name_2: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Kind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<Email>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_properties: Option<bool>,
+    #[serde(flatten)]
+    pub additional_properties_6: std::collections::HashMap<String, String>,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Kind {
+    #[serde(rename = "admin")]
+    Admin,
+    #[serde(rename = "guest")]
+    Guest,
+    #[serde(rename = "Guest")]
+    Guest2,
+}
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind")]
+pub enum Event {
+    #[serde(rename = "Created")]
+    Created(Created),
+    #[serde(rename = "Deleted")]
+    Deleted(Deleted),
+}
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Created {
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Deleted {
+    pub kind: String,
+}
+/// A string in the `date-time` format.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct DateTime(pub String);
+/// audit
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AuditedAudit {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at: Option<DateTime>,
+}
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Audited {
+    #[serde(flatten)]
+    pub user: User,
+    #[serde(flatten)]
+    pub audit: AuditedAudit,
+}
+pub type UserRejectedPayload = String;
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum UserUserIdSignedupSubscribeMessage {
+    UserSignedUp(User),
+    UserRejected(UserRejectedPayload),
+}
+pub type UserDeletedPublishMessagePayload = String;
+pub type UserDeletedSubscribeMessagePayload = String;
+pub type UserDeletedPublishMessagePayload2 = String;
+pub type UserDeletedSubscribeMessagePayload2 = String;